@@ -6,7 +6,7 @@ use crate::{
 use abstract_sdk::cw_helpers::AbstractAttributes;
 use abstract_std::{
     account::{
-        state::{AccountInfo, SuspensionStatus, INFO, SUSPENSION_STATUS},
+        state::{AccountInfo, SuspensionStatus, INFO, SUSPENSION_STATUS, TRUSTED_AUDITORS},
         InternalConfigAction,
     },
     objects::{
@@ -89,6 +89,18 @@ pub fn update_internal_config(
 
             Ok(AccountResponse::action("update_whitelist"))
         }
+        InternalConfigAction::UpdateTrustedAuditors { to_add, to_remove } => {
+            let mut auditors = TRUSTED_AUDITORS.may_load(deps.storage)?.unwrap_or_default();
+            auditors.retain(|auditor| !to_remove.contains(auditor));
+            for auditor in to_add {
+                if !auditors.contains(&auditor) {
+                    auditors.push(auditor);
+                }
+            }
+            TRUSTED_AUDITORS.save(deps.storage, &auditors)?;
+
+            Ok(AccountResponse::action("update_trusted_auditors"))
+        }
         _ => Err(AccountError::InvalidConfigAction {
             error: StdError::generic_err("Unknown config action"),
         }),
@@ -674,6 +686,41 @@ mod tests {
 
             Ok(())
         }
+
+        #[coverage_helper::test]
+        fn trusted_auditors() -> anyhow::Result<()> {
+            use abstract_std::objects::{account::AccountTrace, AccountId};
+
+            let mut deps = mock_dependencies();
+            deps.querier = abstract_mock_querier(deps.api);
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let owner = abstr.owner;
+
+            mock_init(&mut deps)?;
+
+            let first = AccountId::new(1, AccountTrace::Local)?;
+            let second = AccountId::new(2, AccountTrace::Local)?;
+            let msg =
+                ExecuteMsg::UpdateInternalConfig(InternalConfigAction::UpdateTrustedAuditors {
+                    to_add: vec![first.clone(), second.clone(), first.clone()],
+                    to_remove: vec![],
+                });
+            execute_as(&mut deps, &owner, msg)?;
+            assert_eq!(
+                TRUSTED_AUDITORS.load(&deps.storage)?,
+                vec![first.clone(), second.clone()]
+            );
+
+            let msg =
+                ExecuteMsg::UpdateInternalConfig(InternalConfigAction::UpdateTrustedAuditors {
+                    to_add: vec![],
+                    to_remove: vec![first],
+                });
+            execute_as(&mut deps, &owner, msg)?;
+            assert_eq!(TRUSTED_AUDITORS.load(&deps.storage)?, vec![second]);
+
+            Ok(())
+        }
     }
 
     mod update_ownership {
@@ -43,7 +43,7 @@ use crate::{
     queries::{
         handle_account_info_query, handle_config_query, handle_module_address_query,
         handle_module_info_query, handle_module_versions_query, handle_sub_accounts_query,
-        handle_top_level_owner_query,
+        handle_top_level_owner_query, handle_trusted_auditors_query,
     },
     reply::{admin_action_reply, forward_response_reply, register_dependencies},
     sub_account::{
@@ -391,6 +391,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             handle_sub_accounts_query(deps, start_after, limit)
         }
         QueryMsg::TopLevelOwner {} => handle_top_level_owner_query(deps, env),
+        QueryMsg::TrustedAuditors {} => handle_trusted_auditors_query(deps),
         QueryMsg::Ownership {} => {
             cosmwasm_std::to_json_binary(&ownership::get_ownership(deps.storage)?)
         }
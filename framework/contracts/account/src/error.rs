@@ -51,6 +51,9 @@ pub enum AccountError {
     #[error("The provided module {0} was not found")]
     ModuleNotFound(String),
 
+    #[error("Module {0} is not attested by any of the account's trusted auditors")]
+    ModuleNotAttested(ModuleInfo),
+
     #[error("Cannot migrate {} twice", module_id)]
     DuplicateModuleMigration { module_id: String },
 
@@ -1,6 +1,9 @@
 use abstract_std::{
     account::{
-        state::{WhitelistedModules, ACCOUNT_ID, ACCOUNT_MODULES, DEPENDENTS, WHITELISTED_MODULES},
+        state::{
+            WhitelistedModules, ACCOUNT_ID, ACCOUNT_MODULES, DEPENDENTS, TRUSTED_AUDITORS,
+            WHITELISTED_MODULES,
+        },
         ModuleInstallConfig,
     },
     adapter::{AdapterBaseMsg, BaseExecuteMsg, ExecuteMsg as AdapterExecMsg},
@@ -10,10 +13,11 @@ use abstract_std::{
         module::{Module, ModuleInfo, ModuleVersion},
         module_factory::ModuleFactoryContract,
         module_reference::ModuleReference,
+        namespace::ABSTRACT_NAMESPACE,
         ownership,
         registry::RegistryContract,
         salt::generate_instantiate_salt,
-        storage_namespaces,
+        storage_namespaces, AccountId,
     },
     registry::ModuleResponse,
 };
@@ -62,6 +66,27 @@ pub fn install_modules(
     Ok(response)
 }
 
+/// Checks that the module is attested by one of the trusted auditors of the Account.
+/// No-op when the Account has no trusted auditors or for Abstract-owned modules.
+pub(crate) fn assert_module_attested(
+    deps: Deps,
+    registry: &RegistryContract,
+    module_info: &ModuleInfo,
+    trusted_auditors: &[AccountId],
+) -> AccountResult<()> {
+    if trusted_auditors.is_empty() || module_info.namespace.as_str() == ABSTRACT_NAMESPACE {
+        return Ok(());
+    }
+    let attestations = registry.query_module_attestations_raw(module_info, &deps.querier)?;
+    ensure!(
+        attestations
+            .iter()
+            .any(|attestation| trusted_auditors.contains(&attestation.auditor)),
+        AccountError::ModuleNotAttested(module_info.clone())
+    );
+    Ok(())
+}
+
 /// Generate message and attribute for installing module
 /// Adds the modules to the internal store for reference and adds them to the account allowlist if applicable.
 pub fn _install_modules(
@@ -91,12 +116,15 @@ pub fn _install_modules(
     let mut add_to_whitelist: Vec<Addr> = Vec::with_capacity(modules.len());
     let mut add_to_account: Vec<(String, Addr)> = Vec::with_capacity(modules.len());
 
+    let trusted_auditors = TRUSTED_AUDITORS.may_load(deps.storage)?.unwrap_or_default();
+
     let salt: Binary = generate_instantiate_salt(&account_id);
     for (ModuleResponse { module, .. }, init_msg) in modules.into_iter().zip(init_msgs) {
         // Check if module is already enabled.
         if ACCOUNT_MODULES.has(deps.storage, &module.info.id()) {
             return Err(AccountError::ModuleAlreadyInstalled(module.info.id()));
         }
+        assert_module_attested(deps.as_ref(), &registry, &module.info, &trusted_auditors)?;
         installed_modules.push(module.info.id_with_version());
 
         let init_msg_salt = match module.reference {
@@ -160,7 +188,7 @@ pub fn _install_modules(
 
     Ok((
         messages,
-        Attribute::new("installed_modules", format!("{installed_modules:?}")),
+        Attribute::new("installed_modules", installed_modules.join(",")),
     ))
 }
 
@@ -622,4 +650,332 @@ mod tests {
             Ok(())
         }
     }
+
+    mod state_invariants {
+        use std::collections::HashSet;
+
+        use abstract_std::{
+            account::{
+                state::{CALLING_TO_AS_ADMIN, SUB_ACCOUNTS},
+                UpdateSubAccountAction,
+            },
+            adapter::AuthorizedAddressesResponse,
+            objects::module_version::{ModuleData, MODULE},
+            registry::{
+                self,
+                state::{ACCOUNT_ADDRESSES, REGISTERED_MODULES},
+                Account, ModuleConfiguration, ModulesResponse,
+            },
+        };
+        use abstract_testing::{
+            mock_env_validated,
+            property_tester::{CwPropertyTester, TestRng},
+        };
+        use cosmwasm_std::{Reply, ReplyOn, SubMsgResponse, SubMsgResult};
+
+        use super::*;
+
+        const DEPENDENCY: &str = "tester:dependency";
+        /// Depends on [`DEPENDENCY`]
+        const DEPENDENT: &str = "tester:dependent";
+        const MODULES: [&str; 2] = [DEPENDENCY, DEPENDENT];
+        const UPGRADED_VERSION: &str = "99.0.0";
+        const VERSIONS: [&str; 2] = [TEST_VERSION, UPGRADED_VERSION];
+        /// Sub-account ids known by the registry
+        const SUB_ACCOUNT_IDS: [u32; 2] = [2, 3];
+        /// Sub-account id that is not registered
+        const UNKNOWN_SUB_ACCOUNT_ID: u32 = 4;
+
+        #[derive(Debug, Clone)]
+        enum Action {
+            Install(&'static str, &'static str),
+            Upgrade(&'static str, &'static str),
+            Uninstall(&'static str),
+            /// Callback of a sub-account with the id of the first field, sent by the sub-account of the second field
+            RegisterSubAccount(u32, u32),
+            UnregisterSubAccount(u32, u32),
+        }
+
+        /// Every module version is an adapter with its own address
+        fn module_addr(api: MockApi, id: &str, version: &str) -> Addr {
+            api.addr_make(&format!("{id}@{version}"))
+        }
+
+        fn sub_account_addr(api: MockApi, id: u32) -> Addr {
+            api.addr_make(&format!("sub-account-{id}"))
+        }
+
+        fn setup() -> MockDeps {
+            let mut deps = mock_dependencies();
+            let api = deps.api;
+            let abstr = AbstractMockAddrs::new(api);
+            let mut querier = abstract_mock_querier_builder(api).with_smart_handler(
+                &abstr.registry,
+                move |msg| match from_json(msg).unwrap() {
+                    registry::QueryMsg::Modules { infos } => {
+                        let modules = infos
+                            .into_iter()
+                            .map(|info| ModuleResponse {
+                                module: Module {
+                                    reference: ModuleReference::Adapter(module_addr(
+                                        api,
+                                        &info.id(),
+                                        &info.version.to_string(),
+                                    )),
+                                    info,
+                                },
+                                config: ModuleConfiguration::default(),
+                            })
+                            .collect();
+                        Ok(to_json_binary(&ModulesResponse { modules }).unwrap())
+                    }
+                    _ => Err("unexpected message".to_string()),
+                },
+            );
+            for id in MODULES {
+                for version in VERSIONS {
+                    let dependencies = if id == DEPENDENT {
+                        vec![Dependency {
+                            id: DEPENDENCY.to_string(),
+                            version_req: vec![],
+                        }]
+                    } else {
+                        vec![]
+                    };
+                    let addr = module_addr(api, id, version);
+                    querier = querier
+                        .with_contract_version(&addr, id, version)
+                        .with_contract_item(
+                            &addr,
+                            MODULE,
+                            &ModuleData {
+                                module: id.to_string(),
+                                version: version.to_string(),
+                                dependencies,
+                                metadata: None,
+                            },
+                        )
+                        .with_contract_map_entry(
+                            &abstr.registry,
+                            REGISTERED_MODULES,
+                            (
+                                &ModuleInfo::from_id(id, version.into()).unwrap(),
+                                ModuleReference::Adapter(addr.clone()),
+                            ),
+                        )
+                        .with_smart_handler(&addr, |_| {
+                            Ok(
+                                to_json_binary(&AuthorizedAddressesResponse { addresses: vec![] })
+                                    .unwrap(),
+                            )
+                        });
+                }
+            }
+            for id in SUB_ACCOUNT_IDS {
+                querier = querier.with_contract_map_entry(
+                    &abstr.registry,
+                    ACCOUNT_ADDRESSES,
+                    (
+                        &AccountId::local(id),
+                        Account::new(sub_account_addr(api, id)),
+                    ),
+                );
+            }
+            deps.querier = querier.build();
+            mock_init(&mut deps).unwrap();
+            deps
+        }
+
+        fn generate(rng: &mut TestRng) -> Action {
+            let id = *rng.choose(&MODULES);
+            let sub_account_ids = [
+                SUB_ACCOUNT_IDS[0],
+                SUB_ACCOUNT_IDS[1],
+                UNKNOWN_SUB_ACCOUNT_ID,
+            ];
+            let sub_account = *rng.choose(&sub_account_ids);
+            let sender = *rng.choose(&sub_account_ids);
+            match rng.gen_range(5) {
+                0 => Action::Install(id, *rng.choose(&VERSIONS)),
+                1 => Action::Upgrade(id, *rng.choose(&VERSIONS)),
+                2 => Action::Uninstall(id),
+                3 => Action::RegisterSubAccount(sub_account, sender),
+                _ => Action::UnregisterSubAccount(sub_account, sender),
+            }
+        }
+
+        /// Executes the message and settles its sub-messages as if they succeeded, like a transaction would.
+        fn execute_and_reply(deps: &mut MockDeps, sender: &Addr, msg: ExecuteMsg) -> AccountResult {
+            let response = execute_as(deps, sender, msg)?;
+            for sub_msg in &response.messages {
+                if sub_msg.reply_on == ReplyOn::Never {
+                    continue;
+                }
+                let reply = Reply {
+                    id: sub_msg.id,
+                    #[allow(deprecated)]
+                    result: SubMsgResult::Ok(SubMsgResponse {
+                        events: vec![],
+                        data: None,
+                        msg_responses: vec![],
+                    }),
+                    payload: sub_msg.payload.clone(),
+                    gas_used: 0,
+                };
+                let env = mock_env_validated(deps.api);
+                crate::contract::reply(deps.as_mut(), env, reply)?;
+            }
+            Ok(response)
+        }
+
+        fn execute(deps: &mut MockDeps, action: Action) -> AccountResult {
+            let owner = AbstractMockAddrs::new(deps.api).owner;
+            match action {
+                Action::Install(id, version) => execute_and_reply(
+                    deps,
+                    &owner,
+                    ExecuteMsg::InstallModules {
+                        modules: vec![ModuleInstallConfig::new(
+                            ModuleInfo::from_id(id, version.into())?,
+                            None,
+                        )],
+                    },
+                ),
+                Action::Upgrade(id, version) => execute_and_reply(
+                    deps,
+                    &owner,
+                    ExecuteMsg::Upgrade {
+                        modules: vec![(ModuleInfo::from_id(id, version.into())?, None)],
+                    },
+                ),
+                Action::Uninstall(id) => execute_and_reply(
+                    deps,
+                    &owner,
+                    ExecuteMsg::UninstallModule {
+                        module_id: id.to_string(),
+                    },
+                ),
+                Action::RegisterSubAccount(id, sender) => {
+                    let sender = sub_account_addr(deps.api, sender);
+                    execute_and_reply(
+                        deps,
+                        &sender,
+                        ExecuteMsg::UpdateSubAccount(UpdateSubAccountAction::RegisterSubAccount {
+                            id,
+                        }),
+                    )
+                }
+                Action::UnregisterSubAccount(id, sender) => {
+                    let sender = sub_account_addr(deps.api, sender);
+                    execute_and_reply(
+                        deps,
+                        &sender,
+                        ExecuteMsg::UpdateSubAccount(
+                            UpdateSubAccountAction::UnregisterSubAccount { id },
+                        ),
+                    )
+                }
+            }
+        }
+
+        fn whitelist_matches_installed(deps: &MockDeps) -> Result<(), String> {
+            let whitelisted: HashSet<Addr> = WHITELISTED_MODULES
+                .load(&deps.storage)
+                .map_err(|e| e.to_string())?
+                .0
+                .into_iter()
+                .collect();
+            let installed: HashSet<Addr> = load_account_modules(&deps.storage)
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(|(_, addr)| addr)
+                .collect();
+            if whitelisted != installed {
+                return Err(format!(
+                    "whitelisted {whitelisted:?}, installed {installed:?}"
+                ));
+            }
+            Ok(())
+        }
+
+        fn installed_modules_are_registered(deps: &MockDeps) -> Result<(), String> {
+            for (id, addr) in load_account_modules(&deps.storage).map_err(|e| e.to_string())? {
+                if !VERSIONS
+                    .iter()
+                    .any(|version| module_addr(deps.api, &id, version) == addr)
+                {
+                    return Err(format!("{id} is installed at unregistered address {addr}"));
+                }
+            }
+            Ok(())
+        }
+
+        fn dependents_consistent(deps: &MockDeps) -> Result<(), String> {
+            for entry in DEPENDENTS.range(&deps.storage, None, None, Order::Ascending) {
+                let (module, dependents) = entry.map_err(|e| e.to_string())?;
+                if !ACCOUNT_MODULES.has(&deps.storage, &module) {
+                    return Err(format!("{module} has dependents but is not installed"));
+                }
+                if let Some(dependent) = dependents
+                    .iter()
+                    .find(|dependent| !ACCOUNT_MODULES.has(&deps.storage, dependent))
+                {
+                    return Err(format!(
+                        "{dependent} depends on {module} but is not installed"
+                    ));
+                }
+            }
+            if ACCOUNT_MODULES.has(&deps.storage, DEPENDENT) {
+                let dependents = DEPENDENTS
+                    .may_load(&deps.storage, DEPENDENCY)
+                    .map_err(|e| e.to_string())?
+                    .unwrap_or_default();
+                if !dependents.contains(DEPENDENT) {
+                    return Err(format!("{DEPENDENT} is not registered as dependent"));
+                }
+            }
+            Ok(())
+        }
+
+        fn no_pending_upgrade(deps: &MockDeps) -> Result<(), String> {
+            let migrate_context = MIGRATE_CONTEXT
+                .load(&deps.storage)
+                .map_err(|e| e.to_string())?;
+            if !migrate_context.is_empty() {
+                return Err(format!("migrate context left behind: {migrate_context:?}"));
+            }
+            if CALLING_TO_AS_ADMIN.exists(&deps.storage) {
+                return Err("admin call left behind".to_string());
+            }
+            Ok(())
+        }
+
+        fn sub_accounts_are_registered(deps: &MockDeps) -> Result<(), String> {
+            for id in SUB_ACCOUNTS.keys(&deps.storage, None, None, Order::Ascending) {
+                let id = id.map_err(|e| e.to_string())?;
+                if !SUB_ACCOUNT_IDS.contains(&id) {
+                    return Err(format!("sub-account {id} is not registered"));
+                }
+            }
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn module_and_sub_account_actions_keep_state_consistent() -> anyhow::Result<()> {
+            CwPropertyTester::new(setup, generate, execute)
+                .with_invariant(
+                    "whitelist matches installed modules",
+                    whitelist_matches_installed,
+                )
+                .with_invariant(
+                    "installed modules are registered",
+                    installed_modules_are_registered,
+                )
+                .with_invariant("dependents are consistent", dependents_consistent)
+                .with_invariant("no pending upgrade", no_pending_upgrade)
+                .with_invariant("sub-accounts are registered", sub_accounts_are_registered)
+                .run();
+            Ok(())
+        }
+    }
 }
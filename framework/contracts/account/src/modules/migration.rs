@@ -1,5 +1,5 @@
 use abstract_std::{
-    account::state::{CALLING_TO_AS_ADMIN, CALLING_TO_AS_ADMIN_WILD_CARD},
+    account::state::{CALLING_TO_AS_ADMIN, CALLING_TO_AS_ADMIN_WILD_CARD, TRUSTED_AUDITORS},
     adapter::{
         AdapterBaseMsg, AuthorizedAddressesResponse, BaseQueryMsg, QueryMsg as AdapterQuery,
     },
@@ -15,15 +15,15 @@ use abstract_std::{
     ACCOUNT,
 };
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, Binary, CosmosMsg, DepsMut, Empty, Env, MessageInfo, Response,
-    StdResult, Storage, SubMsg, WasmMsg,
+    ensure, to_json_binary, to_json_string, Addr, Binary, CosmosMsg, DepsMut, Empty, Env,
+    MessageInfo, Response, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::get_contract_version;
 use cw_storage_plus::Item;
 
 use super::{
-    _update_whitelisted_modules, configure_adapter, load_module_addr, query_module,
-    update_module_addresses,
+    _update_whitelisted_modules, assert_module_attested, configure_adapter, load_module_addr,
+    query_module, update_module_addresses,
 };
 use crate::{
     contract::{AccountResponse, AccountResult, ASSERT_MODULE_DEPENDENCIES_REQUIREMENTS_REPLY_ID},
@@ -53,6 +53,8 @@ pub fn upgrade_modules(
 
     let mut upgraded_module_ids = Vec::new();
 
+    let mut deprecation_warnings: Vec<String> = vec![];
+
     CALLING_TO_AS_ADMIN.save(
         deps.storage,
         &Addr::unchecked(CALLING_TO_AS_ADMIN_WILD_CARD),
@@ -72,13 +74,14 @@ pub fn upgrade_modules(
         if module_id == ACCOUNT {
             account_migrate_info = Some((module_info, migrate_msg));
         } else {
-            set_migrate_msgs_and_context(
+            let deprecation_warning = set_migrate_msgs_and_context(
                 deps.branch(),
                 &env,
                 module_info,
                 migrate_msg,
                 &mut upgrade_msgs,
             )?;
+            deprecation_warnings.extend(deprecation_warning);
         }
     }
 
@@ -96,21 +99,25 @@ pub fn upgrade_modules(
         .pop()
         .map(|msg| SubMsg::reply_on_success(msg, ASSERT_MODULE_DEPENDENCIES_REQUIREMENTS_REPLY_ID));
 
-    Ok(AccountResponse::new(
-        "upgrade_modules",
-        vec![("upgraded_modules", upgraded_module_ids.join(","))],
-    )
-    .add_messages(upgrade_msgs)
-    .add_submessages(assert_dependency_msg))
+    let mut attributes = vec![("upgraded_modules", upgraded_module_ids.join(","))];
+    // Suggest the replacement of deprecated versions
+    if !deprecation_warnings.is_empty() {
+        attributes.push(("deprecated_modules", to_json_string(&deprecation_warnings)?));
+    }
+
+    Ok(AccountResponse::new("upgrade_modules", attributes)
+        .add_messages(upgrade_msgs)
+        .add_submessages(assert_dependency_msg))
 }
 
+/// Returns a warning if the requested module version is deprecated.
 pub fn set_migrate_msgs_and_context(
     deps: DepsMut,
     env: &Env,
     module_info: ModuleInfo,
     migrate_msg: Option<Binary>,
     msgs: &mut Vec<CosmosMsg>,
-) -> Result<(), AccountError> {
+) -> Result<Option<String>, AccountError> {
     let abstract_code_id =
         native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
     let registry = RegistryContract::new(deps.as_ref(), abstract_code_id)?;
@@ -123,6 +130,17 @@ pub fn set_migrate_msgs_and_context(
         module_info.clone(),
         Some(old_module_cw2),
     )?;
+    let trusted_auditors = TRUSTED_AUDITORS.may_load(deps.storage)?.unwrap_or_default();
+    assert_module_attested(
+        deps.as_ref(),
+        &registry,
+        &requested_module.module.info,
+        &trusted_auditors,
+    )?;
+    let deprecation_warning = requested_module
+        .config
+        .deprecation
+        .map(|deprecation| deprecation.warning(&requested_module.module.info));
 
     let migrate_msgs = match requested_module.module.reference {
         // upgrading an adapter is done by moving the authorized addresses to the new contract address and updating the permissions on the account.
@@ -151,7 +169,7 @@ pub fn set_migrate_msgs_and_context(
         _ => return Err(AccountError::NotUpgradeable(module_info)),
     };
     msgs.extend(migrate_msgs);
-    Ok(())
+    Ok(deprecation_warning)
 }
 
 /// Handle Adapter module migration and return the migration messages
@@ -5,10 +5,11 @@ use abstract_std::{
     account::{
         state::{
             AccountInfo, ACCOUNT_ID, ACCOUNT_MODULES, INFO, SUB_ACCOUNTS, SUSPENSION_STATUS,
-            WHITELISTED_MODULES,
+            TRUSTED_AUDITORS, WHITELISTED_MODULES,
         },
         AccountModuleInfo, ConfigResponse, InfoResponse, ModuleAddressesResponse,
         ModuleInfosResponse, ModuleVersionsResponse, SubAccountIdsResponse,
+        TrustedAuditorsResponse,
     },
     native_addrs,
     objects::{
@@ -42,6 +43,11 @@ pub fn handle_account_info_query(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&InfoResponse { info })
 }
 
+pub fn handle_trusted_auditors_query(deps: Deps) -> StdResult<Binary> {
+    let auditors = TRUSTED_AUDITORS.may_load(deps.storage)?.unwrap_or_default();
+    to_json_binary(&TrustedAuditorsResponse { auditors })
+}
+
 pub fn handle_config_query(deps: Deps, env: &Env) -> StdResult<Binary> {
     let account_id = ACCOUNT_ID.load(deps.storage)?;
     let abstract_code_id =
@@ -0,0 +1,27 @@
+use abstract_integration_tests::{gas::*, AResult};
+use abstract_interface::Abstract;
+use cw_orch::prelude::*;
+
+/// Records the gas report of the Account scenarios.
+/// Set `GAS_REPORT_DIR` to write the report, see `just gas-report`.
+#[test]
+fn account_gas_report() -> AResult {
+    let chain = MockBech32::new("mock");
+    Abstract::deploy_on(chain.clone(), ())?;
+    let benchmark = account_benchmark(chain)?;
+
+    for scenario in [
+        INSTALL_APP,
+        INSTALL_ADAPTER,
+        EXECUTE_ON_APP,
+        EXECUTE_ON_ADAPTER,
+        UNINSTALL_MODULE,
+    ] {
+        let cost = benchmark.cost(scenario).unwrap();
+        assert!(cost.contract_calls > 0, "{scenario} called no contracts");
+    }
+    if let Ok(dir) = std::env::var("GAS_REPORT_DIR") {
+        benchmark.write(dir)?;
+    }
+    Ok(())
+}
@@ -17,11 +17,11 @@ use abstract_std::{
         namespace::Namespace,
         AccountId,
     },
-    registry::UpdateModule,
+    registry::{self, UpdateModule},
     AbstractError, IBC_CLIENT,
 };
 use abstract_testing::prelude::*;
-use cosmwasm_std::coin;
+use cosmwasm_std::{coin, wasm_execute};
 use cw2::ContractVersion;
 use cw_orch::prelude::*;
 
@@ -259,6 +259,79 @@ fn upgrade_app() -> AResult {
     Ok(())
 }
 
+#[test]
+fn install_and_upgrade_require_attestation() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender_addr();
+    let abstr = Abstract::deploy_on(chain.clone(), ())?;
+    let account = create_default_account(&sender, &abstr)?;
+
+    abstr
+        .registry
+        .claim_namespace(TEST_ACCOUNT_ID, TEST_NAMESPACE.to_string())?;
+    deploy_modules(&chain);
+
+    let auditor = create_default_account(&sender, &abstr)?;
+    abstr
+        .registry
+        .update_auditors(vec![auditor.id()?], vec![])?;
+    account.update_trusted_auditors(vec![auditor.id()?], vec![])?;
+    let attest = |version: &str| -> AResult {
+        let module = ModuleInfo::from_id(
+            adapter_1::MOCK_ADAPTER_ID,
+            ModuleVersion::Version(version.to_owned()),
+        )?;
+        auditor.execute_msgs(
+            vec![wasm_execute(
+                abstr.registry.addr_str()?,
+                &registry::ExecuteMsg::AttestModule {
+                    module,
+                    report: "ipfs://report".to_owned(),
+                },
+                vec![],
+            )?
+            .into()],
+            &[],
+        )?;
+        Ok(())
+    };
+    let not_attested = |version: &str| -> anyhow::Result<String> {
+        Ok(AccountError::ModuleNotAttested(ModuleInfo::from_id(
+            adapter_1::MOCK_ADAPTER_ID,
+            ModuleVersion::Version(version.to_owned()),
+        )?)
+        .to_string())
+    };
+
+    let res = install_module_version(&account, adapter_1::MOCK_ADAPTER_ID, V1);
+    assert!(res
+        .unwrap_err()
+        .root_cause()
+        .to_string()
+        .contains(&not_attested(V1)?));
+    attest(V1)?;
+    install_module_version(&account, adapter_1::MOCK_ADAPTER_ID, V1)?;
+
+    let upgrade = || {
+        account.upgrade_module(
+            adapter_1::MOCK_ADAPTER_ID,
+            &app::MigrateMsg {
+                base: app::BaseMigrateMsg {},
+                module: Empty {},
+            },
+        )
+    };
+    let res = upgrade();
+    assert!(res
+        .unwrap_err()
+        .root()
+        .to_string()
+        .contains(&not_attested(V2)?));
+    attest(V2)?;
+    upgrade()?;
+    Ok(())
+}
+
 #[test]
 fn uninstall_modules() -> AResult {
     let chain = MockBech32::new("mock");
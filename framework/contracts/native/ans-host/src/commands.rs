@@ -1,16 +1,22 @@
 use abstract_sdk::execute_update_ownership;
 use abstract_std::{
-    ans_host::{state::*, AssetPair, ExecuteMsg},
+    account::state::ACCOUNT_ID,
+    ans_host::{state::*, AssetMetadata, AssetPair, ExecuteMsg, PriceSource},
+    native_addrs,
     objects::{
+        namespace::Namespace,
         pool_id::{PoolAddress, UncheckedPoolAddress},
         pool_metadata::PoolMetadata,
         pool_reference::PoolReference,
+        registry::{RegistryContract, RegistryError},
         AssetEntry, DexAssetPairing, DexName, UncheckedChannelEntry, UncheckedContractEntry,
         UniquePoolId,
     },
+    AbstractResult,
 };
-use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, StdError, StdResult, Storage};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, StdError, StdResult, Storage};
 use cw_asset::AssetInfoUnchecked;
+use cw_ownable::OwnershipError;
 
 use crate::{
     contract::{AnsHostResponse, AnsHostResult},
@@ -29,19 +35,25 @@ pub fn handle_message(
 ) -> AnsHostResult {
     match message {
         ExecuteMsg::UpdateContractAddresses { to_add, to_remove } => {
-            update_contract_addresses(deps, info, to_add, to_remove)
+            update_contract_addresses(deps, env, info, to_add, to_remove)
         }
         ExecuteMsg::UpdateAssetAddresses { to_add, to_remove } => {
-            update_asset_addresses(deps, info, to_add, to_remove)
+            update_asset_addresses(deps, env, info, to_add, to_remove)
         }
         ExecuteMsg::UpdateChannels { to_add, to_remove } => {
             update_channels(deps, info, to_add, to_remove)
         }
         ExecuteMsg::UpdateDexes { to_add, to_remove } => {
-            update_dex_registry(deps, info, to_add, to_remove)
+            update_dex_registry(deps, env, info, to_add, to_remove)
         }
         ExecuteMsg::UpdatePools { to_add, to_remove } => {
-            update_pools(deps, info, to_add, to_remove)
+            update_pools(deps, env, info, to_add, to_remove)
+        }
+        ExecuteMsg::UpdateAssetMetadata { to_add, to_remove } => {
+            update_asset_metadata(deps, env, info, to_add, to_remove)
+        }
+        ExecuteMsg::UpdateProtocolNamespaces { to_add, to_remove } => {
+            update_protocol_namespaces(deps, info, to_add, to_remove)
         }
         ExecuteMsg::UpdateOwnership(action) => {
             execute_update_ownership!(AnsHostResponse, deps, env, info, action)
@@ -53,18 +65,105 @@ pub fn handle_message(
 //  GOVERNANCE CONTROLLED SETTERS
 //----------------------------------------------------------------------------------------
 
+/// Sender allowed to update ANS entries
+#[derive(Debug, PartialEq)]
+pub enum Curator {
+    /// The contract owner, can update and remove any entry
+    Admin,
+    /// Account that owns a namespace in the registry, can only update entries under that namespace
+    Namespace(Namespace),
+}
+
+impl Curator {
+    /// Load the curation rights of the sender.
+    /// Errors if the sender is neither the owner nor an Account that owns a namespace.
+    pub fn load(deps: Deps, env: &Env, sender: &Addr) -> AnsHostResult<Self> {
+        if cw_ownable::is_owner(deps.storage, sender)? {
+            return Ok(Curator::Admin);
+        }
+        match namespace_of_account(deps, env, sender)? {
+            Some(namespace) => Ok(Curator::Namespace(namespace)),
+            None => Err(AnsHostError::Ownership(OwnershipError::NotOwner)),
+        }
+    }
+
+    /// Assert that the curator can update an entry that belongs to `entry_namespace`
+    pub fn assert_can_curate(&self, entry_namespace: Option<&str>) -> AnsHostResult<()> {
+        match self {
+            Curator::Admin => Ok(()),
+            Curator::Namespace(namespace) if entry_namespace == Some(namespace.as_str()) => Ok(()),
+            Curator::Namespace(namespace) => Err(AnsHostError::NotNamespaceCurator {
+                namespace: namespace.to_string(),
+                entry_namespace: entry_namespace.unwrap_or_default().to_owned(),
+            }),
+        }
+    }
+
+    /// Assert that the curator can update the contract entries of `protocol`.
+    /// Namespace owners can only curate the protocols the admin bound to their namespace.
+    pub fn assert_can_curate_protocol(
+        &self,
+        storage: &dyn Storage,
+        protocol: &str,
+    ) -> AnsHostResult<()> {
+        let Curator::Namespace(namespace) = self else {
+            return Ok(());
+        };
+        match PROTOCOL_NAMESPACES.may_load(storage, protocol)? {
+            Some(bound) if &bound == namespace => Ok(()),
+            _ => Err(AnsHostError::ProtocolNotBound {
+                protocol: protocol.to_owned(),
+                namespace: namespace.to_string(),
+            }),
+        }
+    }
+}
+
+/// Namespace claimed in the registry by the Account at `sender`.
+/// Returns `None` if the sender is not an Account or if its Account didn't claim a namespace.
+fn namespace_of_account(deps: Deps, env: &Env, sender: &Addr) -> AbstractResult<Option<Namespace>> {
+    // Only contracts can be Accounts
+    if deps.querier.query_wasm_contract_info(sender).is_err() {
+        return Ok(None);
+    }
+    let Some(account_id) = ACCOUNT_ID.may_query(&deps.querier, sender.clone())? else {
+        return Ok(None);
+    };
+
+    let abstract_code_id =
+        native_addrs::abstract_code_id(&deps.querier, env.contract.address.clone())?;
+    let registry = RegistryContract::new(deps, abstract_code_id)?;
+    // The Account id is self-reported, it has to be registered to the sender
+    match registry.account(&account_id, &deps.querier) {
+        Ok(account) if account.addr() == sender => {}
+        Ok(_) | Err(RegistryError::UnknownAccountId { .. }) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    }
+    Ok(registry.query_account_namespace_raw(&account_id, &deps.querier)?)
+}
+
+/// Namespace of an asset entry, e.g. `mynamespace` for `mynamespace/token`
+fn asset_namespace(entry: &AssetEntry) -> Option<&str> {
+    entry
+        .as_str()
+        .split_once('/')
+        .map(|(namespace, _)| namespace)
+}
+
 /// Adds, updates or removes provided addresses.
+/// Contract entries can be curated by the owner of the namespace their protocol is bound to.
 pub fn update_contract_addresses(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<(UncheckedContractEntry, String)>,
     to_remove: Vec<UncheckedContractEntry>,
 ) -> AnsHostResult {
-    // Only Admin can call this method
-    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    let curator = Curator::load(deps.as_ref(), &env, &msg_info.sender)?;
 
     for (key, new_address) in to_add.into_iter() {
         let key = key.check();
+        curator.assert_can_curate_protocol(deps.storage, &key.protocol)?;
         // validate addr
         let addr = deps.as_ref().api.addr_validate(&new_address)?;
 
@@ -75,27 +174,62 @@ pub fn update_contract_addresses(
 
     for key in to_remove {
         let key = key.check();
+        curator.assert_can_curate_protocol(deps.storage, &key.protocol)?;
         CONTRACT_ADDRESSES.remove(deps.storage, &key);
     }
 
     Ok(AnsHostResponse::action("update_contract_addresses"))
 }
 
+/// Binds protocols to the namespace whose owner can curate their contract entries
+pub fn update_protocol_namespaces(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    to_add: Vec<(String, String)>,
+    to_remove: Vec<String>,
+) -> AnsHostResult {
+    // Only Admin can call this method
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    for (protocol, namespace) in to_add.into_iter() {
+        let namespace = Namespace::new(&namespace)?;
+        PROTOCOL_NAMESPACES.save(deps.storage, &protocol, &namespace)?;
+    }
+
+    for protocol in to_remove {
+        PROTOCOL_NAMESPACES.remove(deps.storage, &protocol);
+    }
+
+    Ok(AnsHostResponse::action("update_protocol_namespaces"))
+}
+
 /// Adds, updates or removes provided addresses.
+/// Asset entries prefixed with a namespace (`namespace/asset`) can be curated by the owner of that namespace.
 pub fn update_asset_addresses(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<(String, AssetInfoUnchecked)>,
     to_remove: Vec<String>,
 ) -> AnsHostResult {
-    // Only Admin can call this method
-    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    let curator = Curator::load(deps.as_ref(), &env, &msg_info.sender)?;
 
     for (name, new_asset) in to_add.into_iter() {
         // validate asset
         let asset = new_asset.check(deps.as_ref().api, None)?;
 
         let entry = AssetEntry::from(name);
+        curator.assert_can_curate(asset_namespace(&entry))?;
+        // Only the admin can take over the reverse entry of an asset registered under another namespace
+        if let Some(registered) = REV_ASSET_ADDRESSES.may_load(deps.storage, &asset)? {
+            if curator != Curator::Admin && asset_namespace(&registered) != asset_namespace(&entry)
+            {
+                return Err(AnsHostError::AssetAlreadyRegistered {
+                    asset: asset.to_string(),
+                    entry: registered.to_string(),
+                });
+            }
+        }
 
         ASSET_ADDRESSES.save(deps.storage, &entry, &asset)?;
         REV_ASSET_ADDRESSES.save(deps.storage, &asset, &entry)?;
@@ -103,16 +237,77 @@ pub fn update_asset_addresses(
 
     for name in to_remove {
         let entry = AssetEntry::from(name);
+        curator.assert_can_curate(asset_namespace(&entry))?;
         let maybe_asset = ASSET_ADDRESSES.may_load(deps.storage, &entry)?;
         if let Some(asset) = maybe_asset {
             ASSET_ADDRESSES.remove(deps.storage, &entry);
-            REV_ASSET_ADDRESSES.remove(deps.storage, &asset);
+            // The reverse entry can point to another entry of the same asset
+            if REV_ASSET_ADDRESSES.may_load(deps.storage, &asset)? == Some(entry.clone()) {
+                REV_ASSET_ADDRESSES.remove(deps.storage, &asset);
+            }
+            ASSET_METADATA.remove(deps.storage, &entry);
         }
     }
 
     Ok(AnsHostResponse::action("update_asset_addresses"))
 }
 
+/// Adds, updates or removes the metadata of registered assets.
+/// Follows the same curation rules as the assets themselves.
+pub fn update_asset_metadata(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    to_add: Vec<(String, AssetMetadata)>,
+    to_remove: Vec<String>,
+) -> AnsHostResult {
+    let curator = Curator::load(deps.as_ref(), &env, &msg_info.sender)?;
+
+    for (name, metadata) in to_add.into_iter() {
+        let entry = AssetEntry::from(name);
+        curator.assert_can_curate(asset_namespace(&entry))?;
+        if !ASSET_ADDRESSES.has(deps.storage, &entry) {
+            return Err(AnsHostError::UnregisteredAsset {
+                asset: entry.to_string(),
+            });
+        }
+        validate_price_source(deps.storage, metadata.price_source.as_ref())?;
+
+        ASSET_METADATA.save(deps.storage, &entry, &metadata)?;
+    }
+
+    for name in to_remove {
+        let entry = AssetEntry::from(name);
+        curator.assert_can_curate(asset_namespace(&entry))?;
+        ASSET_METADATA.remove(deps.storage, &entry);
+    }
+
+    Ok(AnsHostResponse::action("update_asset_metadata"))
+}
+
+/// Assert that the price source references existing ANS entries
+fn validate_price_source(
+    storage: &dyn Storage,
+    price_source: Option<&PriceSource>,
+) -> AnsHostResult<()> {
+    match price_source {
+        Some(PriceSource::Pool(pool_id)) if !POOL_METADATA.has(storage, *pool_id) => {
+            Err(AnsHostError::UnregisteredPool { pool_id: *pool_id })
+        }
+        Some(PriceSource::Oracle(contract)) if !CONTRACT_ADDRESSES.has(storage, contract) => {
+            Err(AnsHostError::UnregisteredContract {
+                contract: contract.to_string(),
+            })
+        }
+        Some(PriceSource::Peg { asset, .. }) if !ASSET_ADDRESSES.has(storage, asset) => {
+            Err(AnsHostError::UnregisteredAsset {
+                asset: asset.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Adds, updates or removes provided addresses.
 pub fn update_channels(
     deps: DepsMut,
@@ -139,14 +334,18 @@ pub fn update_channels(
 }
 
 /// Updates the dex registry with additions and removals
+/// A dex can be curated by the owner of the namespace with the same name.
 fn update_dex_registry(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<String>,
     to_remove: Vec<String>,
 ) -> AnsHostResult {
-    // Only Admin can call this method
-    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    let curator = Curator::load(deps.as_ref(), &env, &msg_info.sender)?;
+    for dex in to_add.iter().chain(to_remove.iter()) {
+        curator.assert_can_curate(Some(&dex.to_ascii_lowercase()))?;
+    }
 
     if !to_add.is_empty() {
         let register_dex = |mut dexes: Vec<String>| -> StdResult<Vec<String>> {
@@ -174,14 +373,15 @@ fn update_dex_registry(
     Ok(AnsHostResponse::action("update_dexes"))
 }
 
+/// Pools can be curated by the owner of their dex namespace.
 fn update_pools(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     to_add: Vec<(UncheckedPoolAddress, PoolMetadata)>,
     to_remove: Vec<UniquePoolId>,
 ) -> AnsHostResult {
-    // Only Admin can call this method
-    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    let curator = Curator::load(deps.as_ref(), &env, &msg_info.sender)?;
 
     let original_unique_pool_id = CONFIG.load(deps.storage)?.next_unique_pool_id;
     let mut next_unique_pool_id = original_unique_pool_id;
@@ -200,6 +400,7 @@ fn update_pools(
         validate_pool_assets(deps.storage, assets)?;
 
         let dex = pool_metadata.dex.to_ascii_lowercase();
+        curator.assert_can_curate(Some(&dex))?;
         if !registered_dexes.contains(&dex) {
             return Err(AnsHostError::UnregisteredDex { dex });
         }
@@ -222,6 +423,7 @@ fn update_pools(
             // THere is no existing metadata at that id, so we can skip it
             None => continue,
         };
+        curator.assert_can_curate(Some(&pool_metadata.dex))?;
 
         remove_pool_pairings(
             deps.storage,
@@ -903,6 +1105,136 @@ mod test {
         }
     }
 
+    mod update_asset_metadata {
+        use super::*;
+
+        use abstract_std::ans_host::AssetMetadatasResponse;
+        use cosmwasm_std::{from_json, Decimal};
+
+        fn juno_metadata(price_source: Option<PriceSource>) -> AssetMetadata {
+            AssetMetadata {
+                decimals: 6,
+                display_name: "JUNO".to_owned(),
+                coingecko_id: Some("juno-network".to_owned()),
+                price_source,
+            }
+        }
+
+        fn update_metadata(
+            deps: &mut MockDeps,
+            to_add: Vec<(String, AssetMetadata)>,
+            to_remove: Vec<String>,
+            owner: &Addr,
+        ) -> AnsHostTestResult {
+            execute_helper(
+                deps,
+                ExecuteMsg::UpdateAssetMetadata { to_add, to_remove },
+                owner,
+            )
+        }
+
+        #[coverage_helper::test]
+        fn add_and_query_metadata() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let abstr = AbstractMockAddrs::new(deps.api);
+            register_assets_helper(&mut deps, vec!["juno".into(), "usdc".into()], &abstr.owner)?;
+
+            let metadata = juno_metadata(Some(PriceSource::Peg {
+                asset: "usdc".into(),
+                rate: Decimal::percent(50),
+            }));
+            update_metadata(
+                &mut deps,
+                vec![("juno".to_owned(), metadata.clone())],
+                vec![],
+                &abstr.owner,
+            )?;
+
+            let res: AssetMetadatasResponse = from_json(crate::queries::query_asset_metadatas(
+                deps.as_ref(),
+                vec!["juno".to_owned()],
+            )?)?;
+            assert_eq!(res.metadatas, vec![("juno".into(), metadata)]);
+
+            // Removing the asset removes its metadata
+            execute_helper(
+                &mut deps,
+                ExecuteMsg::UpdateAssetAddresses {
+                    to_add: vec![],
+                    to_remove: vec!["juno".to_owned()],
+                },
+                &abstr.owner,
+            )?;
+            assert!(!ASSET_METADATA.has(&deps.storage, &"juno".into()));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn validates_entries() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(&mut deps)?;
+            let abstr = AbstractMockAddrs::new(deps.api);
+
+            let res = update_metadata(
+                &mut deps,
+                vec![("juno".to_owned(), juno_metadata(None))],
+                vec![],
+                &abstr.owner,
+            );
+            assert_eq!(
+                res,
+                Err(AnsHostError::UnregisteredAsset {
+                    asset: "juno".to_owned()
+                })
+            );
+
+            register_assets_helper(&mut deps, vec!["juno".into()], &abstr.owner)?;
+            let res = update_metadata(
+                &mut deps,
+                vec![(
+                    "juno".to_owned(),
+                    juno_metadata(Some(PriceSource::Pool(UniquePoolId::new(1)))),
+                )],
+                vec![],
+                &abstr.owner,
+            );
+            assert_eq!(
+                res,
+                Err(AnsHostError::UnregisteredPool {
+                    pool_id: UniquePoolId::new(1)
+                })
+            );
+
+            let oracle = UncheckedContractEntry::new("oracle", "prices").check();
+            let res = update_metadata(
+                &mut deps,
+                vec![(
+                    "juno".to_owned(),
+                    juno_metadata(Some(PriceSource::Oracle(oracle.clone()))),
+                )],
+                vec![],
+                &abstr.owner,
+            );
+            assert_eq!(
+                res,
+                Err(AnsHostError::UnregisteredContract {
+                    contract: oracle.to_string()
+                })
+            );
+
+            let not_owner = deps.api.addr_make("not_owner");
+            let res = update_metadata(
+                &mut deps,
+                vec![("juno".to_owned(), juno_metadata(None))],
+                vec![],
+                &not_owner,
+            );
+            assert_eq!(res, Err(AnsHostError::Ownership(OwnershipError::NotOwner)));
+            Ok(())
+        }
+    }
+
     mod update_channels {
         use abstract_std::objects::ChannelEntry;
         use abstract_testing::map_tester::CwMapTesterBuilder;
@@ -1404,6 +1736,209 @@ mod test {
         }
     }
 
+    mod namespace_curation {
+        use super::*;
+
+        use abstract_std::{
+            objects::{namespace::ABSTRACT_NAMESPACE, PoolType, ABSTRACT_ACCOUNT_ID},
+            registry::state::REV_NAMESPACES,
+        };
+        use abstract_testing::abstract_mock_querier_builder;
+        use cw_asset::AssetInfo;
+
+        /// The mock abstract Account owns the `abstract` namespace
+        fn curation_mock_deps() -> MockDeps {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .with_contract_map_entry(
+                    &abstr.registry,
+                    REV_NAMESPACES,
+                    (
+                        &ABSTRACT_ACCOUNT_ID,
+                        Namespace::new(ABSTRACT_NAMESPACE).unwrap(),
+                    ),
+                )
+                .build();
+            mock_init(&mut deps).unwrap();
+            deps
+        }
+
+        fn not_bound(protocol: &str) -> AnsHostError {
+            AnsHostError::ProtocolNotBound {
+                protocol: protocol.to_owned(),
+                namespace: ABSTRACT_NAMESPACE.to_owned(),
+            }
+        }
+
+        fn not_curator(entry_namespace: &str) -> AnsHostError {
+            AnsHostError::NotNamespaceCurator {
+                namespace: ABSTRACT_NAMESPACE.to_owned(),
+                entry_namespace: entry_namespace.to_owned(),
+            }
+        }
+
+        #[coverage_helper::test]
+        fn namespace_owner_curates_own_entries() -> AnsHostTestResult {
+            let mut deps = curation_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let account = abstr.account.addr().clone();
+            let api = deps.api;
+
+            let contract_msg = |protocol: &str| ExecuteMsg::UpdateContractAddresses {
+                to_add: vec![(
+                    UncheckedContractEntry::new(protocol, "staking"),
+                    api.addr_make("staking").to_string(),
+                )],
+                to_remove: vec![],
+            };
+            // Protocols have to be bound to the namespace by the admin
+            let res = execute_helper(&mut deps, contract_msg("wyndex"), &account);
+            assert_eq!(res, Err(not_bound("wyndex")));
+            let bind_msg = ExecuteMsg::UpdateProtocolNamespaces {
+                to_add: vec![("wyndex".to_owned(), ABSTRACT_NAMESPACE.to_owned())],
+                to_remove: vec![],
+            };
+            let res = execute_helper(&mut deps, bind_msg.clone(), &account);
+            assert_eq!(res, Err(AnsHostError::Ownership(OwnershipError::NotOwner)));
+            execute_helper(&mut deps, bind_msg, &abstr.owner)?;
+            execute_helper(&mut deps, contract_msg("wyndex"), &account)?;
+            let res = execute_helper(&mut deps, contract_msg(ABSTRACT_NAMESPACE), &account);
+            assert_eq!(res, Err(not_bound(ABSTRACT_NAMESPACE)));
+
+            register_assets_helper(&mut deps, vec!["abstract/token".into()], &account)?;
+            let res = register_assets_helper(&mut deps, vec!["juno".into()], &account);
+            assert_eq!(res, Err(not_curator("")));
+
+            // The dex named after the namespace and its pools can be curated
+            execute_helper(
+                &mut deps,
+                ExecuteMsg::UpdateDexes {
+                    to_add: vec![ABSTRACT_NAMESPACE.to_owned()],
+                    to_remove: vec![],
+                },
+                &account,
+            )?;
+            register_assets_helper(&mut deps, vec!["juno".into(), "osmo".into()], &abstr.owner)?;
+            let pool_msg = |dex: &str| ExecuteMsg::UpdatePools {
+                to_add: vec![(
+                    UncheckedPoolAddress::contract(api.addr_make("pool")),
+                    PoolMetadata::new(dex, PoolType::Stable, vec!["juno", "osmo"]),
+                )],
+                to_remove: vec![],
+            };
+            execute_helper(&mut deps, pool_msg(ABSTRACT_NAMESPACE), &account)?;
+            let res = execute_helper(&mut deps, pool_msg("junoswap"), &account);
+            assert_eq!(res, Err(not_curator("junoswap")));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn admin_overrides_namespace_entries() -> AnsHostTestResult {
+            let mut deps = curation_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+
+            register_assets_helper(
+                &mut deps,
+                vec!["abstract/token".into()],
+                &abstr.account.addr().clone(),
+            )?;
+            execute_helper(
+                &mut deps,
+                ExecuteMsg::UpdateAssetAddresses {
+                    to_add: vec![],
+                    to_remove: vec!["abstract/token".to_owned()],
+                },
+                &abstr.owner,
+            )?;
+            assert!(!ASSET_ADDRESSES.has(&deps.storage, &"abstract/token".into()));
+
+            // Channels stay admin-only
+            let res = execute_helper(
+                &mut deps,
+                ExecuteMsg::UpdateChannels {
+                    to_add: vec![(
+                        UncheckedChannelEntry::new("juno", "ics20"),
+                        "channel-1".to_owned(),
+                    )],
+                    to_remove: vec![],
+                },
+                &abstr.account.addr().clone(),
+            );
+            assert_eq!(res, Err(AnsHostError::Ownership(OwnershipError::NotOwner)));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn namespace_owner_cant_take_over_assets() -> AnsHostTestResult {
+            let mut deps = curation_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let account = abstr.account.addr().clone();
+            let usdc = AssetInfoUnchecked::native("uusdc");
+            let asset_msg = |name: &str| ExecuteMsg::UpdateAssetAddresses {
+                to_add: vec![(name.to_owned(), usdc.clone())],
+                to_remove: vec![],
+            };
+
+            execute_helper(&mut deps, asset_msg("usdc"), &abstr.owner)?;
+            let res = execute_helper(&mut deps, asset_msg("abstract/usdc"), &account);
+            assert_eq!(
+                res,
+                Err(AnsHostError::AssetAlreadyRegistered {
+                    asset: "native:uusdc".to_owned(),
+                    entry: "usdc".to_owned(),
+                })
+            );
+
+            // The admin can register a second entry, the reverse entry stays untouched on removal
+            let uusdc = AssetInfo::native("uusdc");
+            execute_helper(&mut deps, asset_msg("abstract/usdc"), &abstr.owner)?;
+            execute_helper(&mut deps, asset_msg("usdc"), &abstr.owner)?;
+            execute_helper(
+                &mut deps,
+                ExecuteMsg::UpdateAssetAddresses {
+                    to_add: vec![],
+                    to_remove: vec!["abstract/usdc".to_owned()],
+                },
+                &account,
+            )?;
+            assert_eq!(
+                REV_ASSET_ADDRESSES.load(&deps.storage, &uusdc)?,
+                AssetEntry::from("usdc")
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn account_without_namespace_is_not_curator() -> AnsHostTestResult {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .with_contract_map_key(&abstr.registry, REV_NAMESPACES, &ABSTRACT_ACCOUNT_ID)
+                .build();
+            mock_init(&mut deps)?;
+
+            let res = register_assets_helper(
+                &mut deps,
+                vec!["abstract/token".into()],
+                &abstr.account.addr().clone(),
+            );
+            assert_eq!(res, Err(AnsHostError::Ownership(OwnershipError::NotOwner)));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn curator_query_errors_are_returned() -> AnsHostTestResult {
+            let mut deps = curation_mock_deps();
+            // The mock querier fails raw queries on unknown contracts
+            let unknown = deps.api.addr_make("unknown");
+
+            let res = register_assets_helper(&mut deps, vec!["abstract/token".into()], &unknown);
+            assert!(matches!(res, Err(AnsHostError::Abstract(_))));
+            Ok(())
+        }
+    }
+
     mod validate_pool_assets {
         use super::*;
 
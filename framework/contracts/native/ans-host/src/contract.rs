@@ -94,6 +94,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => queries::list_pool_metadata_entries(deps, filter, start_after, limit),
+        QueryMsg::BatchEntries { entries } => queries::query_batch_entries(deps, entries),
+        QueryMsg::AssetMetadatas { names } => queries::query_asset_metadatas(deps, names),
+        QueryMsg::AssetMetadataList { start_after, limit } => {
+            queries::query_asset_metadata_list(deps, start_after, limit)
+        }
         QueryMsg::Ownership {} => query_ownership!(deps),
     }
 }
@@ -1,4 +1,4 @@
-use abstract_std::AbstractError;
+use abstract_std::{objects::UniquePoolId, AbstractError};
 use cosmwasm_std::StdError;
 use cw_asset::AssetError;
 use thiserror::Error;
@@ -29,4 +29,18 @@ pub enum AnsHostError {
 
     #[error("Asset {} is not registered", asset)]
     UnregisteredAsset { asset: String },
+
+    #[error(
+        "Owner of namespace {namespace} can't update entries of namespace \"{entry_namespace}\""
+    )]
+    NotNamespaceCurator {
+        namespace: String,
+        entry_namespace: String,
+    },
+
+    #[error("Protocol {protocol} is not bound to namespace {namespace}")]
+    ProtocolNotBound { protocol: String, namespace: String },
+
+    #[error("Asset {asset} is already registered as {entry}")]
+    AssetAlreadyRegistered { asset: String, entry: String },
 }
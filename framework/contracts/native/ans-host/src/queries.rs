@@ -1,15 +1,16 @@
 use abstract_std::{
     ans_host::{
         state::{
-            Config, ASSET_ADDRESSES, ASSET_PAIRINGS, CHANNELS, CONFIG, CONTRACT_ADDRESSES,
-            POOL_METADATA, REGISTERED_DEXES, REV_ASSET_ADDRESSES,
+            Config, ASSET_ADDRESSES, ASSET_METADATA, ASSET_PAIRINGS, CHANNELS, CONFIG,
+            CONTRACT_ADDRESSES, POOL_METADATA, REGISTERED_DEXES, REV_ASSET_ADDRESSES,
         },
-        AssetInfoListResponse, AssetInfoMapEntry, AssetInfosResponse, AssetListResponse,
-        AssetMapEntry, AssetPairingFilter, AssetPairingMapEntry, AssetsResponse,
-        ChannelListResponse, ChannelMapEntry, ChannelsResponse, ConfigResponse,
-        ContractListResponse, ContractMapEntry, ContractsResponse, PoolAddressListResponse,
-        PoolMetadataFilter, PoolMetadataListResponse, PoolMetadataMapEntry, PoolMetadatasResponse,
-        PoolsResponse, RegisteredDexesResponse,
+        AnsEntries, AssetInfoListResponse, AssetInfoMapEntry, AssetInfosResponse,
+        AssetListResponse, AssetMapEntry, AssetMetadataListResponse, AssetMetadataMapEntry,
+        AssetMetadatasResponse, AssetPairingFilter, AssetPairingMapEntry, AssetsResponse,
+        BatchEntriesResponse, ChannelListResponse, ChannelMapEntry, ChannelsResponse,
+        ConfigResponse, ContractListResponse, ContractMapEntry, ContractsResponse,
+        PoolAddressListResponse, PoolMetadataFilter, PoolMetadataListResponse,
+        PoolMetadataMapEntry, PoolMetadatasResponse, PoolsResponse, RegisteredDexesResponse,
     },
     objects::{
         AssetEntry, ChannelEntry, ContractEntry, DexAssetPairing, DexName, PoolMetadata,
@@ -65,6 +66,93 @@ pub fn query_asset_list(
     to_json_binary(&AssetListResponse { assets: res? })
 }
 
+pub fn query_asset_metadatas(deps: Deps, keys: Vec<String>) -> StdResult<Binary> {
+    let metadatas = keys
+        .into_iter()
+        .map(|name| {
+            let key = AssetEntry::new(&name);
+            let value = ASSET_METADATA.load(deps.storage, &key)?;
+            Ok((key, value))
+        })
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&AssetMetadatasResponse { metadatas })
+}
+
+pub fn query_batch_entries(deps: Deps, entries: AnsEntries) -> StdResult<Binary> {
+    let AnsEntries {
+        assets,
+        contracts,
+        channels,
+        pairings,
+        pool_ids,
+    } = entries;
+
+    // Unregistered entries are skipped so a single missing entry doesn't fail the whole batch.
+    let assets = assets
+        .into_iter()
+        .map(|key| {
+            Ok(ASSET_ADDRESSES
+                .may_load(deps.storage, &key)?
+                .map(|v| (key, v)))
+        })
+        .filter_map(StdResult::transpose)
+        .collect::<StdResult<_>>()?;
+    let contracts = contracts
+        .into_iter()
+        .map(|key| {
+            Ok(CONTRACT_ADDRESSES
+                .may_load(deps.storage, &key)?
+                .map(|v| (key, v)))
+        })
+        .filter_map(StdResult::transpose)
+        .collect::<StdResult<_>>()?;
+    let channels = channels
+        .into_iter()
+        .map(|key| Ok(CHANNELS.may_load(deps.storage, &key)?.map(|v| (key, v))))
+        .filter_map(StdResult::transpose)
+        .collect::<StdResult<_>>()?;
+    let pairings = pairings
+        .into_iter()
+        .map(|key| {
+            Ok(ASSET_PAIRINGS
+                .may_load(deps.storage, &key)?
+                .map(|v| (key, v)))
+        })
+        .filter_map(StdResult::transpose)
+        .collect::<StdResult<_>>()?;
+    let pool_metadatas = pool_ids
+        .into_iter()
+        .map(|key| Ok(POOL_METADATA.may_load(deps.storage, key)?.map(|v| (key, v))))
+        .filter_map(StdResult::transpose)
+        .collect::<StdResult<_>>()?;
+
+    to_json_binary(&BatchEntriesResponse {
+        assets,
+        contracts,
+        channels,
+        pairings,
+        pool_metadatas,
+    })
+}
+
+pub fn query_asset_metadata_list(
+    deps: Deps,
+    last_asset_name: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let entry = last_asset_name.map(AssetEntry::from);
+    let start_bound = entry.as_ref().map(Bound::exclusive);
+
+    let res: Result<Vec<AssetMetadataMapEntry>, _> = ASSET_METADATA
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect();
+
+    to_json_binary(&AssetMetadataListResponse { metadatas: res? })
+}
+
 pub fn query_asset_infos(
     deps: Deps,
     _env: Env,
@@ -685,6 +773,47 @@ mod test {
         Ok(())
     }
 
+    #[coverage_helper::test]
+    fn test_query_batch_entries() -> AnsHostTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(&mut deps).unwrap();
+        let api = deps.api;
+
+        let test_assets = create_test_assets(vec![("foo", &api.addr_make("foo"))], api);
+        update_asset_addresses(deps.as_mut(), test_assets.clone())?;
+        let contracts =
+            create_contract_entry_and_string(vec![("foo", "foo", &api.addr_make("foo"))]);
+        update_contract_addresses(deps.as_mut(), contracts)?;
+        let channels = create_channel_entry_and_string(vec![("foo", "foo", "foo")]);
+        update_channels(deps.as_mut(), channels.clone())?;
+
+        // unregistered entries are omitted
+        let msg = QueryMsg::BatchEntries {
+            entries: AnsEntries {
+                assets: vec!["foo".into(), "bar".into()],
+                contracts: create_contract_entry(vec![("foo", "foo"), ("bar", "bar")]),
+                channels: create_channel_entry(vec![("foo", "foo")]),
+                pairings: vec![create_dex_asset_pairing("foo", "bar", "dex")],
+                pool_ids: vec![UniquePoolId::new(1)],
+            },
+        };
+        let res: BatchEntriesResponse = from_json(query_helper(&deps, msg)?)?;
+
+        let expected = BatchEntriesResponse {
+            assets: create_asset_response(test_assets).assets,
+            contracts: create_contract_entry(vec![("foo", "foo")])
+                .into_iter()
+                .map(|entry| (entry, api.addr_make("foo")))
+                .collect(),
+            channels,
+            pairings: vec![],
+            pool_metadatas: vec![],
+        };
+        assert_eq!(res, expected);
+
+        Ok(())
+    }
+
     #[coverage_helper::test]
     fn test_query_asset_list() -> AnsHostTestResult {
         // arrange mocks
@@ -1154,8 +1154,10 @@ mod tests {
             let res = execute_as(&mut deps, &note_addr, msg)?;
 
             assert_eq!(
-                IbcClientResponse::action("register_remote_proxy")
-                    .add_attribute("chain", chain_name.to_string()),
+                IbcClientResponse::new(
+                    "register_remote_proxy",
+                    vec![("chain", chain_name.to_string())]
+                ),
                 res
             );
 
@@ -1306,9 +1308,13 @@ mod tests {
             let res = execute_as(&mut deps, &note_addr, msg)?;
 
             assert_eq!(
-                IbcClientResponse::action("acknowledge_remote_account_registration")
-                    .add_attribute("account_id", TEST_ACCOUNT_ID.to_string())
-                    .add_attribute("chain", chain_name.to_string()),
+                IbcClientResponse::new(
+                    "acknowledge_remote_account_registration",
+                    vec![
+                        ("account_id", TEST_ACCOUNT_ID.to_string()),
+                        ("chain", chain_name.to_string()),
+                    ]
+                ),
                 res
             );
 
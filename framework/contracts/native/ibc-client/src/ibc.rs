@@ -54,8 +54,10 @@ pub fn receive_action_callback(
             } else {
                 return Err(IbcClientError::IbcFailed(polytone_callback));
             }
-            Ok(IbcClientResponse::action("register_remote_proxy")
-                .add_attribute("chain", host_chain.to_string()))
+            Ok(IbcClientResponse::new(
+                "register_remote_proxy",
+                vec![("chain", host_chain.to_string())],
+            ))
         }
         IbcClientCallback::CreateAccount { account_id } => {
             // We need to get the address of the remote proxy from the account creation response
@@ -84,11 +86,13 @@ pub fn receive_action_callback(
             } else {
                 return Err(IbcClientError::IbcFailed(polytone_callback));
             }
-            Ok(
-                IbcClientResponse::action("acknowledge_remote_account_registration")
-                    .add_attribute("account_id", account_id.to_string())
-                    .add_attribute("chain", host_chain.to_string()),
-            )
+            Ok(IbcClientResponse::new(
+                "acknowledge_remote_account_registration",
+                vec![
+                    ("account_id", account_id.to_string()),
+                    ("chain", host_chain.to_string()),
+                ],
+            ))
         }
         IbcClientCallback::ModuleRemoteAction {
             callback,
@@ -99,9 +103,11 @@ pub fn receive_action_callback(
                 callback,
                 result: IbcResult::from_execute(polytone_callback.result, initiator_msg)?,
             };
-            Ok(IbcClientResponse::action("module_action_ibc_callback")
-                .add_message(resp_msg.into_cosmos_msg(sender_address)?)
-                .add_attribute("chain", host_chain.to_string()))
+            Ok(IbcClientResponse::new(
+                "module_action_ibc_callback",
+                vec![("chain", host_chain.to_string())],
+            )
+            .add_message(resp_msg.into_cosmos_msg(sender_address)?))
         }
         IbcClientCallback::ModuleRemoteQuery {
             sender_address,
@@ -112,9 +118,11 @@ pub fn receive_action_callback(
                 callback,
                 result: IbcResult::from_query(polytone_callback.result, queries)?,
             };
-            Ok(IbcClientResponse::action("module_query_ibc_callback")
-                .add_message(reps_msg.into_cosmos_msg(sender_address)?)
-                .add_attribute("chain", host_chain.to_string()))
+            Ok(IbcClientResponse::new(
+                "module_query_ibc_callback",
+                vec![("chain", host_chain.to_string())],
+            )
+            .add_message(reps_msg.into_cosmos_msg(sender_address)?))
         }
     }
 }
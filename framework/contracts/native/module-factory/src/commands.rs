@@ -8,10 +8,10 @@ use abstract_sdk::{
     },
     *,
 };
-use abstract_std::{native_addrs, objects::module};
+use abstract_std::{native_addrs, objects::module, registry::ExecuteMsg as RegistryExecuteMsg};
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, BankMsg, Binary, CanonicalAddr, Coin, Coins, CosmosMsg, Deps,
-    DepsMut, Env, MessageInfo, WasmMsg,
+    from_json, to_json_binary, to_json_string, wasm_execute, Addr, BankMsg, Binary, CanonicalAddr,
+    Coin, Coins, CosmosMsg, Deps, DepsMut, Env, MessageInfo, WasmMsg,
 };
 use serde_cw_value::Value;
 
@@ -58,6 +58,7 @@ pub fn execute_create_modules(
 
     // Attributes logging
     let mut module_ids: Vec<String> = Vec::with_capacity(modules_responses.len());
+    let mut deprecation_warnings: Vec<String> = vec![];
 
     let mut at_least_one_standalone = false;
 
@@ -69,6 +70,9 @@ pub fn execute_create_modules(
         let new_module_monetization = module_response.config.monetization;
         let new_module_init_funds = module_response.config.instantiation_funds;
         module_ids.push(new_module.info.id_with_version());
+        if let Some(deprecation) = module_response.config.deprecation {
+            deprecation_warnings.push(deprecation.warning(&new_module.info));
+        }
 
         // We validate the fee if it was required by the registry to install this module
         match new_module_monetization {
@@ -86,7 +90,26 @@ pub fn execute_create_modules(
                     amount: vec![fee],
                 }));
             }
-            abstract_std::objects::module::Monetization::None => {}
+            module::Monetization::Subscription { fee, .. } => {
+                let fee = fee.fee();
+                sum_of_monetization.add(fee.clone())?;
+                // The first period is paid through the registry, which forwards it to the namespace owner
+                let account_id = registry.unchecked_account_id(account.addr(), &deps.querier)?;
+                fee_msgs.push(
+                    wasm_execute(
+                        registry.address.clone(),
+                        &RegistryExecuteMsg::PayModuleSubscription {
+                            account_id,
+                            module: new_module.info.clone(),
+                            periods: 1,
+                        },
+                        vec![fee],
+                    )?
+                    .into(),
+                );
+            }
+            // Usage fees are charged by the module itself
+            module::Monetization::UsageFee(_) | module::Monetization::None => {}
             // The monetization must be known to the factory for a module to be installed
             _ => return Err(ModuleFactoryError::ModuleNotInstallable {}),
         };
@@ -173,15 +196,18 @@ pub fn execute_create_modules(
 
     let new_modules = new_module_addrs(&modules_to_register)?;
 
-    let response = ModuleFactoryResponse::new(
-        "create_modules",
-        [
-            ("module_ids", format!("{module_ids:?}")),
-            ("new_modules", new_modules),
-        ],
-    )
-    .add_messages(fee_msgs)
-    .add_messages(module_instantiate_messages);
+    let mut attributes = vec![
+        ("module_ids", format!("{module_ids:?}")),
+        ("new_modules", new_modules),
+    ];
+    // Deprecated modules can be installed, but the installer is warned
+    if !deprecation_warnings.is_empty() {
+        attributes.push(("deprecated_modules", to_json_string(&deprecation_warnings)?));
+    }
+
+    let response = ModuleFactoryResponse::new("create_modules", attributes)
+        .add_messages(fee_msgs)
+        .add_messages(module_instantiate_messages);
 
     Ok(response)
 }
@@ -98,9 +98,13 @@ pub fn query_simulate_install_modules(
     let mut install_funds = vec![];
     let mut init_funds = vec![];
     for module in module_responses {
-        if let Monetization::InstallFee(fee) = module.config.monetization {
-            coins.add(fee.fee())?;
-            install_funds.push((module.module.info.id(), fee.fee()))
+        match module.config.monetization {
+            // The first subscription period is paid on install
+            Monetization::InstallFee(fee) | Monetization::Subscription { fee, .. } => {
+                coins.add(fee.fee())?;
+                install_funds.push((module.module.info.id(), fee.fee()))
+            }
+            _ => {}
         }
         if !module.config.instantiation_funds.is_empty() {
             init_funds.push((
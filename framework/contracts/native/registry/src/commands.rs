@@ -7,7 +7,7 @@ use abstract_sdk::{
             namespace::Namespace,
             AccountId,
         },
-        registry::{state::*, Account, Config},
+        registry::{state::*, Account, Config, ModuleAttestation},
     },
 };
 use abstract_std::{
@@ -23,13 +23,14 @@ use abstract_std::{
     ACCOUNT, IBC_HOST,
 };
 use cosmwasm_std::{
-    ensure, ensure_eq, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, MessageInfo,
-    Order, QuerierWrapper, StdResult, Storage,
+    ensure, ensure_eq, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Order, QuerierWrapper, StdResult, Storage, Timestamp,
 };
 
 use crate::{
     contract::{VCResult, VcResponse, ABSTRACT_NAMESPACE},
     error::RegistryError,
+    queries::handle_modules_query,
 };
 
 /// Add new Account to registry contract
@@ -134,14 +135,14 @@ pub fn add_account(
         None
     };
 
-    let mut response = VcResponse::new(
-        "add_account",
-        vec![
-            ("account_id", account_id.to_string().as_str()),
-            ("account_address", account.addr().as_str()),
-            ("namespace", &format!("{namespace:?}")),
-        ],
-    );
+    let mut attributes = vec![
+        ("account_id", account_id.to_string()),
+        ("account_address", account.addr().to_string()),
+    ];
+    if let Some(namespace) = namespace {
+        attributes.push(("namespace", namespace));
+    }
+    let mut response = VcResponse::new("add_account", attributes);
 
     if let Some(msg) = fee_msg {
         response = response.add_message(msg);
@@ -336,6 +337,136 @@ pub fn yank_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) ->
     ))
 }
 
+/// Deprecate a module version without breaking existing installs, optionally pointing to its replacement.
+pub fn deprecate_module(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    module: ModuleInfo,
+    replacement: Option<ModuleInfo>,
+    reason: String,
+) -> VCResult {
+    // validate the caller is the owner of the namespace
+    validate_account_owner(deps.as_ref(), &module.namespace, &msg_info.sender)?;
+
+    // Only specific versions may be deprecated
+    module.assert_version_variant()?;
+    if !REGISTERED_MODULES.has(deps.storage, &module) {
+        return Err(RegistryError::ModuleNotFound(module));
+    }
+
+    if let Some(replacement) = &replacement {
+        replacement.assert_version_variant()?;
+        ensure!(
+            replacement != &module,
+            RegistryError::InvalidModuleReplacement(module)
+        );
+        if !REGISTERED_MODULES.has(deps.storage, replacement) {
+            return Err(RegistryError::ModuleNotFound(replacement.clone()));
+        }
+    }
+
+    let deprecation = module::ModuleDeprecation {
+        replacement,
+        reason,
+    };
+    let mut config = MODULE_CONFIG
+        .may_load(deps.storage, &module)?
+        .unwrap_or_default();
+    let warning = deprecation.warning(&module);
+    config.deprecation = Some(deprecation);
+    MODULE_CONFIG.save(deps.storage, &module, &config)?;
+
+    Ok(VcResponse::new(
+        "deprecate_module",
+        vec![("module", module.to_string()), ("warning", warning)],
+    ))
+}
+
+/// Approve or revoke the Accounts that can attest module audits.
+/// Only the admin can update the auditors.
+pub fn update_auditors(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    to_add: Vec<AccountId>,
+    to_remove: Vec<AccountId>,
+) -> VCResult {
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    for account_id in to_add.iter() {
+        if !ACCOUNT_ADDRESSES.has(deps.storage, account_id) {
+            return Err(RegistryError::UnknownAccountId {
+                id: account_id.clone(),
+            });
+        }
+        AUDITORS.save(deps.storage, account_id, &cosmwasm_std::Empty {})?;
+    }
+    for account_id in to_remove.iter() {
+        AUDITORS.remove(deps.storage, account_id);
+    }
+
+    let join_ids = |ids: &[AccountId]| {
+        ids.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    Ok(VcResponse::new(
+        "update_auditors",
+        vec![
+            ("added", join_ids(&to_add)),
+            ("removed", join_ids(&to_remove)),
+        ],
+    ))
+}
+
+/// Attest that a module version was audited by the calling auditor Account.
+pub fn attest_module(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    module: ModuleInfo,
+    report: String,
+) -> VCResult {
+    let not_auditor = || RegistryError::NotAuditor(msg_info.sender.clone());
+    let auditor = ACCOUNT_ID
+        .query(&deps.querier, msg_info.sender.clone())
+        .map_err(|_| not_auditor())?;
+    // The sender must be the registered Account of an approved auditor
+    let auditor_account = ACCOUNT_ADDRESSES
+        .may_load(deps.storage, &auditor)?
+        .ok_or_else(not_auditor)?;
+    ensure!(
+        auditor_account.addr() == msg_info.sender && AUDITORS.has(deps.storage, &auditor),
+        not_auditor()
+    );
+
+    // Only specific, registered versions may be attested
+    module.assert_version_variant()?;
+    if !REGISTERED_MODULES.has(deps.storage, &module) {
+        return Err(RegistryError::ModuleNotFound(module));
+    }
+    ensure!(!report.is_empty(), RegistryError::EmptyAuditReport {});
+
+    let mut attestations = MODULE_ATTESTATIONS
+        .may_load(deps.storage, &module)?
+        .unwrap_or_default();
+    attestations.retain(|attestation| attestation.auditor != auditor);
+    attestations.push(ModuleAttestation {
+        auditor: auditor.clone(),
+        report,
+        date: env.block.time,
+    });
+    MODULE_ATTESTATIONS.save(deps.storage, &module, &attestations)?;
+
+    Ok(VcResponse::new(
+        "attest_module",
+        vec![
+            ("module", module.to_string()),
+            ("auditor", auditor.to_string()),
+        ],
+    ))
+}
+
 /// Updates module configuration
 pub fn update_module_config(
     deps: DepsMut,
@@ -405,6 +536,9 @@ pub fn update_module_config(
 
             // Update monetization
             if let Some(monetization) = monetization {
+                if let module::Monetization::Subscription { period, .. } = &monetization {
+                    ensure!(*period > 0, RegistryError::ZeroSubscriptionPeriod {});
+                }
                 current_cfg.monetization = monetization;
             }
 
@@ -562,6 +696,7 @@ pub fn forgo_namespace(deps: DepsMut, msg_info: MessageInfo, namespaces: Vec<Str
         logs.push(format!("({namespace}, {owner})"));
         NAMESPACES.remove(deps.storage, &namespace);
         REV_NAMESPACES.remove(deps.storage, &owner);
+        PENDING_NAMESPACE_TRANSFERS.remove(deps.storage, &namespace);
     }
 
     Ok(VcResponse::new(
@@ -570,6 +705,77 @@ pub fn forgo_namespace(deps: DepsMut, msg_info: MessageInfo, namespaces: Vec<Str
     ))
 }
 
+/// Offer a namespace to another Account
+/// Only admin or the namespace owner can call this
+pub fn transfer_namespace(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    namespace: String,
+    to_account: AccountId,
+) -> VCResult {
+    let namespace = Namespace::try_from(namespace)?;
+    if !cw_ownable::is_owner(deps.storage, &msg_info.sender)? {
+        validate_account_owner(deps.as_ref(), &namespace, &msg_info.sender)?;
+    }
+    let from_account = NAMESPACES.load(deps.storage, &namespace)?;
+    ensure!(
+        ACCOUNT_ADDRESSES.has(deps.storage, &to_account),
+        RegistryError::UnknownAccountId { id: to_account }
+    );
+    ensure!(
+        to_account != from_account,
+        RegistryError::NamespaceOccupied {
+            namespace: namespace.to_string(),
+            id: from_account,
+        }
+    );
+
+    PENDING_NAMESPACE_TRANSFERS.save(deps.storage, &namespace, &to_account)?;
+
+    Ok(VcResponse::new(
+        "transfer_namespace",
+        vec![
+            ("namespace", namespace.to_string()),
+            ("from_account", from_account.to_string()),
+            ("to_account", to_account.to_string()),
+        ],
+    ))
+}
+
+/// Accept a pending namespace transfer.
+/// Registered modules stay under the namespace and the registration fee is not charged again.
+pub fn accept_namespace(deps: DepsMut, msg_info: MessageInfo, namespace: String) -> VCResult {
+    let namespace = Namespace::try_from(namespace)?;
+    let to_account = PENDING_NAMESPACE_TRANSFERS
+        .may_load(deps.storage, &namespace)?
+        .ok_or_else(|| RegistryError::NoPendingNamespaceTransfer {
+            namespace: namespace.clone(),
+        })?;
+    validate_account_or_owner(deps.as_ref(), &to_account, &msg_info.sender)?;
+
+    if REV_NAMESPACES.has(deps.storage, &to_account) {
+        return Err(RegistryError::ExceedsNamespaceLimit {
+            limit: 1,
+            current: 1,
+        });
+    }
+
+    let from_account = NAMESPACES.load(deps.storage, &namespace)?;
+    PENDING_NAMESPACE_TRANSFERS.remove(deps.storage, &namespace);
+    REV_NAMESPACES.remove(deps.storage, &from_account);
+    REV_NAMESPACES.save(deps.storage, &to_account, &namespace)?;
+    NAMESPACES.save(deps.storage, &namespace, &to_account)?;
+
+    Ok(VcResponse::new(
+        "accept_namespace",
+        vec![
+            ("namespace", namespace.to_string()),
+            ("from_account", from_account.to_string()),
+            ("to_account", to_account.to_string()),
+        ],
+    ))
+}
+
 pub fn update_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -617,6 +823,83 @@ pub fn update_config(
     Ok(VcResponse::new("update_config", attributes))
 }
 
+/// Pay for a number of periods of a module subscription.
+/// The paid time is added after the current paid period, or after now if the subscription lapsed.
+pub fn pay_module_subscription(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    account_id: AccountId,
+    module: ModuleInfo,
+    periods: u64,
+) -> VCResult {
+    ensure!(periods > 0, RegistryError::ZeroSubscriptionPeriods {});
+    if !ACCOUNT_ADDRESSES.has(deps.storage, &account_id) {
+        return Err(RegistryError::UnknownAccountId { id: account_id });
+    }
+
+    // Resolves the version if latest is requested
+    let module = handle_modules_query(deps.as_ref(), vec![module])?
+        .modules
+        .swap_remove(0);
+    let (fee, period, lapse_policy) = match module.config.monetization {
+        module::Monetization::Subscription {
+            fee,
+            period,
+            lapse_policy,
+        } => (fee, period, lapse_policy),
+        _ => return Err(RegistryError::NoModuleSubscription(module.module.info)),
+    };
+    let info = module.module.info;
+    let fee = fee.quantity(periods.into()).assert_payment(&msg_info)?;
+
+    // Subscription is paid to the owner of the namespace
+    let namespace_account_id = NAMESPACES.load(deps.storage, &info.namespace)?;
+    let namespace_account = ACCOUNT_ADDRESSES.load(deps.storage, &namespace_account_id)?;
+
+    let module_id = info.id();
+    let key = (&account_id, module_id.as_str());
+    let paid_from = MODULE_SUBSCRIPTIONS
+        .may_load(deps.storage, key)?
+        .map(|s| s.paid_until)
+        .filter(|paid_until| *paid_until > env.block.time)
+        .unwrap_or(env.block.time);
+    let paid_until = period
+        .checked_mul(periods)
+        .and_then(|seconds| seconds.checked_mul(1_000_000_000))
+        .and_then(|nanos| paid_from.nanos().checked_add(nanos))
+        .map(Timestamp::from_nanos)
+        .ok_or(RegistryError::SubscriptionOverflow {
+            account_id: account_id.clone(),
+            period,
+            periods,
+        })?;
+    MODULE_SUBSCRIPTIONS.save(
+        deps.storage,
+        key,
+        &module::ModuleSubscription {
+            paid_until,
+            lapse_policy,
+        },
+    )?;
+
+    let mut response = VcResponse::new(
+        "pay_module_subscription",
+        vec![
+            ("account_id", account_id.to_string()),
+            ("module", info.id_with_version()),
+            ("paid_until", paid_until.to_string()),
+        ],
+    );
+    if !fee.amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: namespace_account.addr().to_string(),
+            amount: vec![fee],
+        });
+    }
+    Ok(response)
+}
+
 pub fn query_account_owner(
     querier: &QuerierWrapper,
     account_addr: Addr,
@@ -636,17 +919,26 @@ pub fn validate_account_owner(
     namespace: &Namespace,
     sender: &Addr,
 ) -> Result<(), RegistryError> {
-    let sender = sender.clone();
     let account_id = NAMESPACES
         .may_load(deps.storage, &namespace.clone())?
         .ok_or_else(|| RegistryError::UnknownNamespace {
             namespace: namespace.to_owned(),
         })?;
-    let account = ACCOUNT_ADDRESSES.load(deps.storage, &account_id)?;
+    validate_account_or_owner(deps, &account_id, sender)
+}
+
+/// Validate that the sender is the Account itself or its owner
+fn validate_account_or_owner(
+    deps: Deps,
+    account_id: &AccountId,
+    sender: &Addr,
+) -> Result<(), RegistryError> {
+    let sender = sender.clone();
+    let account = ACCOUNT_ADDRESSES.load(deps.storage, account_id)?;
     let account = account.addr();
     // Check account first, account can call this function to unregister a namespace when renouncing its ownership.
     if sender != account {
-        let account_owner = query_account_owner(&deps.querier, account.clone(), &account_id)?;
+        let account_owner = query_account_owner(&deps.querier, account.clone(), account_id)?;
         if sender != account_owner {
             return Err(RegistryError::AccountOwnerMismatch {
                 sender,
@@ -1245,6 +1537,137 @@ mod tests {
         }
     }
 
+    mod transfer_namespace {
+        use super::*;
+
+        #[coverage_helper::test]
+        fn transfer_and_accept() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            claim_test_namespace_as_owner(&mut deps, &abstr.owner)?;
+            create_second_account(&mut deps);
+
+            let module = ModuleInfo::from_id(TEST_MODULE_ID, TEST_VERSION.into())?;
+            let msg = ExecuteMsg::ProposeModules {
+                modules: vec![(module.clone(), ModuleReference::App(0))],
+            };
+            execute_as(&mut deps, &abstr.owner, msg)?;
+
+            let first_account = deps.api.addr_make(FIRST_ACCOUNT);
+            let second_account = deps.api.addr_make(SECOND_ACCOUNT);
+            let namespace = Namespace::new(TEST_NAMESPACE)?;
+
+            // Only the namespace owner can offer it
+            let msg = ExecuteMsg::TransferNamespace {
+                namespace: TEST_NAMESPACE.to_owned(),
+                to_account: SECOND_TEST_ACCOUNT_ID,
+            };
+            let res = execute_as(&mut deps, &second_account, msg.clone());
+            assert!(matches!(
+                res,
+                Err(RegistryError::AccountOwnerMismatch { .. })
+            ));
+            execute_as(&mut deps, &first_account, msg)?;
+            assert_eq!(
+                PENDING_NAMESPACE_TRANSFERS.load(&deps.storage, &namespace)?,
+                SECOND_TEST_ACCOUNT_ID
+            );
+
+            // Only the receiving account can accept it
+            let msg = ExecuteMsg::AcceptNamespace {
+                namespace: TEST_NAMESPACE.to_owned(),
+            };
+            let res = execute_as(&mut deps, &first_account, msg.clone());
+            assert!(matches!(
+                res,
+                Err(RegistryError::AccountOwnerMismatch { .. })
+            ));
+            execute_as(&mut deps, &second_account, msg.clone())?;
+
+            assert_eq!(
+                NAMESPACES.load(&deps.storage, &namespace)?,
+                SECOND_TEST_ACCOUNT_ID
+            );
+            assert_eq!(
+                REV_NAMESPACES.load(&deps.storage, &SECOND_TEST_ACCOUNT_ID)?,
+                namespace
+            );
+            assert!(!REV_NAMESPACES.has(&deps.storage, &FIRST_TEST_ACCOUNT_ID));
+            assert!(!PENDING_NAMESPACE_TRANSFERS.has(&deps.storage, &namespace));
+            // Modules stay registered
+            assert!(REGISTERED_MODULES.has(&deps.storage, &module));
+
+            // Transfer can't be accepted twice
+            let res = execute_as(&mut deps, &second_account, msg);
+            assert_eq!(
+                res,
+                Err(RegistryError::NoPendingNamespaceTransfer { namespace })
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn receiver_already_has_namespace() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            claim_test_namespace_as_owner(&mut deps, &abstr.owner)?;
+            create_second_account(&mut deps);
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::ClaimNamespace {
+                    account_id: SECOND_TEST_ACCOUNT_ID,
+                    namespace: "other".to_owned(),
+                },
+            )?;
+
+            let msg = ExecuteMsg::TransferNamespace {
+                namespace: TEST_NAMESPACE.to_owned(),
+                to_account: SECOND_TEST_ACCOUNT_ID,
+            };
+            execute_as(&mut deps, &abstr.owner, msg)?;
+
+            let msg = ExecuteMsg::AcceptNamespace {
+                namespace: TEST_NAMESPACE.to_owned(),
+            };
+            let second_account = deps.api.addr_make(SECOND_ACCOUNT);
+            let res = execute_as(&mut deps, &second_account, msg);
+            assert_eq!(
+                res,
+                Err(RegistryError::ExceedsNamespaceLimit {
+                    limit: 1,
+                    current: 1
+                })
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn forgo_clears_pending_transfer() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            claim_test_namespace_as_owner(&mut deps, &abstr.owner)?;
+            create_second_account(&mut deps);
+
+            let msg = ExecuteMsg::TransferNamespace {
+                namespace: TEST_NAMESPACE.to_owned(),
+                to_account: SECOND_TEST_ACCOUNT_ID,
+            };
+            execute_as(&mut deps, &abstr.owner, msg)?;
+            let msg = ExecuteMsg::ForgoNamespace {
+                namespaces: vec![TEST_NAMESPACE.to_owned()],
+            };
+            execute_as(&mut deps, &abstr.owner, msg)?;
+
+            let namespace = Namespace::new(TEST_NAMESPACE)?;
+            assert!(!PENDING_NAMESPACE_TRANSFERS.has(&deps.storage, &namespace));
+            Ok(())
+        }
+    }
+
     mod forgo_namespace {
         use super::*;
 
@@ -2263,6 +2686,231 @@ mod tests {
         }
     }
 
+    mod deprecate_module {
+        use super::*;
+
+        fn propose_test_versions(deps: &mut MockDeps, owner: &Addr) -> RegistryTestResult {
+            claim_test_namespace_as_owner(deps, owner)?;
+            let msg = ExecuteMsg::ProposeModules {
+                modules: vec![
+                    (
+                        ModuleInfo::from_id(TEST_MODULE_ID, "1.0.0".into())?,
+                        ModuleReference::App(0),
+                    ),
+                    (
+                        ModuleInfo::from_id(TEST_MODULE_ID, "2.0.0".into())?,
+                        ModuleReference::App(1),
+                    ),
+                ],
+            };
+            execute_as(deps, owner, msg)?;
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn only_namespace_owner() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            propose_test_versions(&mut deps, &abstr.owner)?;
+
+            let msg = ExecuteMsg::DeprecateModule {
+                module: ModuleInfo::from_id(TEST_MODULE_ID, "1.0.0".into())?,
+                replacement: None,
+                reason: "old".to_owned(),
+            };
+            let other = deps.api.addr_make(TEST_OTHER);
+            let res = execute_as(&mut deps, &other, msg);
+            assert_eq!(
+                res,
+                Err(RegistryError::AccountOwnerMismatch {
+                    sender: other,
+                    owner: abstr.owner,
+                })
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn deprecates_with_replacement() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            propose_test_versions(&mut deps, &abstr.owner)?;
+
+            let old = ModuleInfo::from_id(TEST_MODULE_ID, "1.0.0".into())?;
+            let new = ModuleInfo::from_id(TEST_MODULE_ID, "2.0.0".into())?;
+            let msg = ExecuteMsg::DeprecateModule {
+                module: old.clone(),
+                replacement: Some(new.clone()),
+                reason: "security fix".to_owned(),
+            };
+            execute_as(&mut deps, &abstr.owner, msg)?;
+
+            // Module stays installable and exposes its deprecation
+            assert!(REGISTERED_MODULES.has(&deps.storage, &old));
+            let res: ModulesResponse = from_json(contract::query(
+                deps.as_ref(),
+                mock_env_validated(deps.api),
+                QueryMsg::Modules {
+                    infos: vec![old.clone()],
+                },
+            )?)?;
+            assert_eq!(
+                res.modules[0].config.deprecation,
+                Some(module::ModuleDeprecation {
+                    replacement: Some(new),
+                    reason: "security fix".to_owned(),
+                })
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn replacement_must_be_registered() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            propose_test_versions(&mut deps, &abstr.owner)?;
+
+            let old = ModuleInfo::from_id(TEST_MODULE_ID, "1.0.0".into())?;
+            let unknown = ModuleInfo::from_id(TEST_MODULE_ID, "3.0.0".into())?;
+            let msg = ExecuteMsg::DeprecateModule {
+                module: old.clone(),
+                replacement: Some(unknown.clone()),
+                reason: "old".to_owned(),
+            };
+            let res = execute_as(&mut deps, &abstr.owner, msg);
+            assert_eq!(res, Err(RegistryError::ModuleNotFound(unknown)));
+
+            let msg = ExecuteMsg::DeprecateModule {
+                module: old.clone(),
+                replacement: Some(old.clone()),
+                reason: "old".to_owned(),
+            };
+            let res = execute_as(&mut deps, &abstr.owner, msg);
+            assert_eq!(res, Err(RegistryError::InvalidModuleReplacement(old)));
+            Ok(())
+        }
+    }
+
+    mod attest_module {
+        use super::*;
+        use crate::contract::query;
+
+        fn registered_test_module(
+            deps: &mut MockDeps,
+            owner: &Addr,
+        ) -> Result<ModuleInfo, RegistryError> {
+            claim_test_namespace_as_owner(deps, owner)?;
+            let module = ModuleInfo::from_id(TEST_MODULE_ID, TEST_VERSION.into())?;
+            let msg = ExecuteMsg::ProposeModules {
+                modules: vec![(module.clone(), ModuleReference::App(0))],
+            };
+            execute_as(deps, owner, msg)?;
+            Ok(module)
+        }
+
+        #[coverage_helper::test]
+        fn only_admin_updates_auditors() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+
+            let msg = ExecuteMsg::UpdateAuditors {
+                to_add: vec![FIRST_TEST_ACCOUNT_ID],
+                to_remove: vec![],
+            };
+            test_only_admin(msg, &mut deps)
+        }
+
+        #[coverage_helper::test]
+        fn only_approved_auditor() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            let module = registered_test_module(&mut deps, &abstr.owner)?;
+
+            let auditor = deps.api.addr_make(FIRST_ACCOUNT);
+            let msg = ExecuteMsg::AttestModule {
+                module,
+                report: "ipfs://report".to_owned(),
+            };
+            let res = execute_as(&mut deps, &auditor, msg);
+            assert_eq!(res, Err(RegistryError::NotAuditor(auditor)));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn attest_and_filter() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            let module = registered_test_module(&mut deps, &abstr.owner)?;
+
+            let msg = ExecuteMsg::UpdateAuditors {
+                to_add: vec![FIRST_TEST_ACCOUNT_ID],
+                to_remove: vec![],
+            };
+            let res = execute_as(&mut deps, &abstr.owner, msg)?;
+            assert_eq!(
+                res.events[0].attributes[2..],
+                [
+                    cosmwasm_std::attr("added", FIRST_TEST_ACCOUNT_ID.to_string()),
+                    cosmwasm_std::attr("removed", ""),
+                ]
+            );
+
+            let auditor = deps.api.addr_make(FIRST_ACCOUNT);
+            let msg = ExecuteMsg::AttestModule {
+                module: module.clone(),
+                report: "ipfs://report".to_owned(),
+            };
+            execute_as(&mut deps, &auditor, msg)?;
+            // A new attestation replaces the previous one of the auditor
+            let msg = ExecuteMsg::AttestModule {
+                module: module.clone(),
+                report: "ipfs://report-v2".to_owned(),
+            };
+            execute_as(&mut deps, &auditor, msg)?;
+
+            let env = mock_env_validated(deps.api);
+            let res: ModuleAttestationsResponse = from_json(query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ModuleAttestations {
+                    module: module.clone(),
+                },
+            )?)?;
+            assert_eq!(
+                res.attestations,
+                vec![ModuleAttestation {
+                    auditor: FIRST_TEST_ACCOUNT_ID,
+                    report: "ipfs://report-v2".to_owned(),
+                    date: env.block.time,
+                }]
+            );
+
+            let list =
+                |deps: &MockDeps, auditor: AccountId| -> Result<Vec<ModuleInfo>, RegistryError> {
+                    let res: ModulesListResponse = from_json(query(
+                        deps.as_ref(),
+                        mock_env_validated(deps.api),
+                        QueryMsg::ModuleList {
+                            filter: Some(ModuleFilter {
+                                attested_by: Some(auditor),
+                                ..Default::default()
+                            }),
+                            start_after: None,
+                            limit: None,
+                        },
+                    )?)?;
+                    Ok(res.modules.into_iter().map(|m| m.module.info).collect())
+                };
+            assert_eq!(list(&deps, FIRST_TEST_ACCOUNT_ID)?, vec![module]);
+            assert!(list(&deps, SECOND_TEST_ACCOUNT_ID)?.is_empty());
+            Ok(())
+        }
+    }
+
     mod register_account {
         use super::*;
 
@@ -2345,6 +2993,277 @@ mod tests {
         }
     }
 
+    mod module_subscription {
+        use super::*;
+
+        use crate::contract::query;
+        use abstract_std::objects::module::{LapsePolicy, ModuleStanding, Monetization};
+        use cosmwasm_std::{coin, SubMsg};
+
+        const PERIOD: u64 = 100;
+
+        fn test_module() -> ModuleInfo {
+            ModuleInfo::from_id(TEST_MODULE_ID, ModuleVersion::Version(TEST_VERSION.into()))
+                .unwrap()
+        }
+
+        fn subscription_module(deps: &mut MockDeps) -> Result<ModuleInfo, RegistryError> {
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let mut new_module = test_module();
+            new_module.namespace = Namespace::new(ABSTRACT_NAMESPACE)?;
+            execute_as(
+                deps,
+                &abstr.owner,
+                ExecuteMsg::ProposeModules {
+                    modules: vec![(new_module.clone(), ModuleReference::App(0))],
+                },
+            )?;
+            execute_as(
+                deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateModuleConfiguration {
+                    module_name: new_module.name.clone(),
+                    namespace: new_module.namespace.clone(),
+                    update_module: UpdateModule::Versioned {
+                        version: TEST_VERSION.to_owned(),
+                        metadata: None,
+                        monetization: Some(Monetization::Subscription {
+                            fee: FixedFee::new(&coin(10, "ujuno")),
+                            period: PERIOD,
+                            lapse_policy: LapsePolicy::Suspend { grace_period: 0 },
+                        }),
+                        instantiation_funds: None,
+                    },
+                },
+            )?;
+            Ok(new_module)
+        }
+
+        #[coverage_helper::test]
+        fn pays_namespace_owner_and_extends_subscription() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            let module = subscription_module(&mut deps)?;
+            let other = deps.api.addr_make(TEST_OTHER);
+
+            let msg = ExecuteMsg::PayModuleSubscription {
+                account_id: FIRST_TEST_ACCOUNT_ID,
+                module: module.clone(),
+                periods: 2,
+            };
+            // Fee must be paid for every period
+            let res = execute_as_with_funds(&mut deps, &other, msg.clone(), &[coin(10, "ujuno")]);
+            assert!(matches!(res, Err(RegistryError::Abstract(_))));
+
+            let res = execute_as_with_funds(&mut deps, &other, msg.clone(), &[coin(20, "ujuno")])?;
+            assert_eq!(
+                res.messages,
+                vec![SubMsg::new(BankMsg::Send {
+                    to_address: abstr.account.addr().to_string(),
+                    amount: vec![coin(20, "ujuno")],
+                })]
+            );
+
+            let now = mock_env_validated(deps.api).block.time;
+            let standing = |deps: &MockDeps| -> Result<ModuleStanding, RegistryError> {
+                let res = query(
+                    deps.as_ref(),
+                    mock_env_validated(deps.api),
+                    QueryMsg::ModuleStanding {
+                        account_id: FIRST_TEST_ACCOUNT_ID,
+                        module: module.clone(),
+                    },
+                )?;
+                Ok(from_json::<ModuleStandingResponse>(&res)?.standing)
+            };
+            assert_eq!(
+                standing(&deps)?,
+                ModuleStanding::Active {
+                    paid_until: now.plus_seconds(2 * PERIOD)
+                }
+            );
+
+            // Paying again extends the paid period
+            execute_as_with_funds(&mut deps, &other, msg, &[coin(20, "ujuno")])?;
+            assert_eq!(
+                standing(&deps)?,
+                ModuleStanding::Active {
+                    paid_until: now.plus_seconds(4 * PERIOD)
+                }
+            );
+
+            let res = query(
+                deps.as_ref(),
+                mock_env_validated(deps.api),
+                QueryMsg::AccountStanding {
+                    account_id: FIRST_TEST_ACCOUNT_ID,
+                    start_after: None,
+                    limit: None,
+                },
+            )?;
+            assert_eq!(
+                from_json::<AccountStandingResponse>(&res)?.modules,
+                vec![(
+                    module.id(),
+                    ModuleStanding::Active {
+                        paid_until: now.plus_seconds(4 * PERIOD)
+                    }
+                )]
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn lapsed_subscription_is_suspended() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            mock_init_with_account(&mut deps, false)?;
+            let module = subscription_module(&mut deps)?;
+            let other = deps.api.addr_make(TEST_OTHER);
+
+            execute_as_with_funds(
+                &mut deps,
+                &other,
+                ExecuteMsg::PayModuleSubscription {
+                    account_id: FIRST_TEST_ACCOUNT_ID,
+                    module: module.clone(),
+                    periods: 1,
+                },
+                &[coin(10, "ujuno")],
+            )?;
+
+            let mut env = mock_env_validated(deps.api);
+            let paid_until = env.block.time.plus_seconds(PERIOD);
+            env.block.time = paid_until;
+            let res = query(
+                deps.as_ref(),
+                env,
+                QueryMsg::ModuleStanding {
+                    account_id: FIRST_TEST_ACCOUNT_ID,
+                    module,
+                },
+            )?;
+            assert_eq!(
+                from_json::<ModuleStandingResponse>(&res)?.standing,
+                ModuleStanding::Suspended { paid_until }
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn missing_subscription_is_suspended() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            mock_init_with_account(&mut deps, false)?;
+            // E.g. installed before the module required a subscription
+            let module = subscription_module(&mut deps)?;
+
+            let res = query(
+                deps.as_ref(),
+                mock_env_validated(deps.api),
+                QueryMsg::ModuleStanding {
+                    account_id: FIRST_TEST_ACCOUNT_ID,
+                    module: ModuleInfo {
+                        version: ModuleVersion::Latest,
+                        ..module
+                    },
+                },
+            )?;
+            assert_eq!(
+                from_json::<ModuleStandingResponse>(&res)?.standing,
+                ModuleStanding::Suspended {
+                    paid_until: Timestamp::from_seconds(0)
+                }
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn rejects_overflowing_subscription() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            mock_init_with_account(&mut deps, false)?;
+            let module = subscription_module(&mut deps)?;
+            let other = deps.api.addr_make(TEST_OTHER);
+
+            let periods = u64::MAX / PERIOD;
+            let res = execute_as_with_funds(
+                &mut deps,
+                &other,
+                ExecuteMsg::PayModuleSubscription {
+                    account_id: FIRST_TEST_ACCOUNT_ID,
+                    module,
+                    periods,
+                },
+                &[coin(10 * periods as u128, "ujuno")],
+            );
+            assert_eq!(
+                res,
+                Err(RegistryError::SubscriptionOverflow {
+                    account_id: FIRST_TEST_ACCOUNT_ID,
+                    period: PERIOD,
+                    periods,
+                })
+            );
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn rejects_zero_period() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            let module = subscription_module(&mut deps)?;
+
+            let res = execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::UpdateModuleConfiguration {
+                    module_name: module.name,
+                    namespace: module.namespace,
+                    update_module: UpdateModule::Versioned {
+                        version: TEST_VERSION.to_owned(),
+                        metadata: None,
+                        monetization: Some(Monetization::Subscription {
+                            fee: FixedFee::new(&coin(10, "ujuno")),
+                            period: 0,
+                            lapse_policy: LapsePolicy::Continue,
+                        }),
+                        instantiation_funds: None,
+                    },
+                },
+            );
+            assert_eq!(res, Err(RegistryError::ZeroSubscriptionPeriod {}));
+            Ok(())
+        }
+
+        #[coverage_helper::test]
+        fn requires_subscription_monetization() -> RegistryTestResult {
+            let mut deps = registry_mock_deps();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            mock_init_with_account(&mut deps, false)?;
+            let mut module = test_module();
+            module.namespace = Namespace::new(ABSTRACT_NAMESPACE)?;
+            execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::ProposeModules {
+                    modules: vec![(module.clone(), ModuleReference::App(0))],
+                },
+            )?;
+
+            let res = execute_as(
+                &mut deps,
+                &abstr.owner,
+                ExecuteMsg::PayModuleSubscription {
+                    account_id: FIRST_TEST_ACCOUNT_ID,
+                    module: module.clone(),
+                    periods: 1,
+                },
+            );
+            assert_eq!(res, Err(RegistryError::NoModuleSubscription(module)));
+            Ok(())
+        }
+    }
+
     mod query_account_owner {
         use abstract_sdk::namespaces::OWNERSHIP_STORAGE_KEY;
 
@@ -64,6 +64,17 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> V
         }
         ExecuteMsg::RemoveModule { module } => remove_module(deps, info, module),
         ExecuteMsg::YankModule { module } => yank_module(deps, info, module),
+        ExecuteMsg::DeprecateModule {
+            module,
+            replacement,
+            reason,
+        } => deprecate_module(deps, info, module, replacement, reason),
+        ExecuteMsg::UpdateAuditors { to_add, to_remove } => {
+            update_auditors(deps, info, to_add, to_remove)
+        }
+        ExecuteMsg::AttestModule { module, report } => {
+            attest_module(deps, env, info, module, report)
+        }
         ExecuteMsg::UpdateModuleConfiguration {
             module_name,
             namespace,
@@ -74,6 +85,11 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> V
             account_id,
         } => claim_namespace(deps, info, account_id, namespace),
         ExecuteMsg::ForgoNamespace { namespaces } => forgo_namespace(deps, info, namespaces),
+        ExecuteMsg::TransferNamespace {
+            namespace,
+            to_account,
+        } => transfer_namespace(deps, info, namespace, to_account),
+        ExecuteMsg::AcceptNamespace { namespace } => accept_namespace(deps, info, namespace),
         ExecuteMsg::AddAccount { namespace, creator } => {
             add_account(deps, info, namespace, creator)
         }
@@ -81,6 +97,11 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> V
             security_enabled,
             namespace_registration_fee,
         } => update_config(deps, info, security_enabled, namespace_registration_fee),
+        ExecuteMsg::PayModuleSubscription {
+            account_id,
+            module,
+            periods,
+        } => pay_module_subscription(deps, env, info, account_id, module, periods),
         ExecuteMsg::UpdateOwnership(action) => {
             execute_update_ownership!(VcResponse, deps, env, info, action)
         }
@@ -88,7 +109,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> V
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> VCResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> VCResult<Binary> {
     match msg {
         QueryMsg::Accounts { account_ids } => {
             to_json_binary(&queries::handle_accounts_address_query(deps, account_ids)?)
@@ -100,6 +121,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> VCResult<Binary> {
         QueryMsg::Namespace { namespace } => {
             to_json_binary(&queries::handle_namespace_query(deps, namespace)?)
         }
+        QueryMsg::PendingNamespaceTransfer { namespace } => to_json_binary(
+            &queries::handle_pending_namespace_transfer_query(deps, namespace)?,
+        ),
         QueryMsg::Config {} => {
             let config = CONFIG.load(deps.storage)?;
             let local_account_sequence = LOCAL_ACCOUNT_SEQUENCE.load(deps.storage)?;
@@ -131,6 +155,26 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> VCResult<Binary> {
                 limit,
             )?)
         }
+        QueryMsg::ModuleAttestations { module } => {
+            to_json_binary(&queries::handle_module_attestations_query(deps, module)?)
+        }
+        QueryMsg::Auditors { start_after, limit } => {
+            to_json_binary(&queries::handle_auditors_query(deps, start_after, limit)?)
+        }
+        QueryMsg::ModuleStanding { account_id, module } => to_json_binary(
+            &queries::handle_module_standing_query(deps, &env, account_id, module)?,
+        ),
+        QueryMsg::AccountStanding {
+            account_id,
+            start_after,
+            limit,
+        } => to_json_binary(&queries::handle_account_standing_query(
+            deps,
+            &env,
+            account_id,
+            start_after,
+            limit,
+        )?),
         QueryMsg::Ownership {} => query_ownership!(deps),
     }
     .map_err(Into::into)
@@ -58,6 +58,9 @@ pub enum RegistryError {
     #[error("Namespace {} is already occupied by account {}", namespace, id)]
     NamespaceOccupied { namespace: String, id: AccountId },
 
+    #[error("Namespace {} has no pending transfer", namespace)]
+    NoPendingNamespaceTransfer { namespace: Namespace },
+
     #[error("Exceeds namespace limit: {}, current: {}", limit, current)]
     ExceedsNamespaceLimit { limit: usize, current: usize },
 
@@ -76,6 +79,31 @@ pub enum RegistryError {
     #[error("Sender {0} is not the IBC host {1}")]
     SenderNotIbcHost(String, String),
 
+    #[error("Module {0} is not monetized through a subscription")]
+    NoModuleSubscription(ModuleInfo),
+
+    #[error("Sender {0} is not the Account of an approved auditor")]
+    NotAuditor(Addr),
+
+    #[error("An attestation requires an audit report")]
+    EmptyAuditReport {},
+
+    #[error("Module {0} can not be its own replacement")]
+    InvalidModuleReplacement(ModuleInfo),
+
+    #[error("A module subscription must be paid for at least one period")]
+    ZeroSubscriptionPeriods {},
+
+    #[error("The subscription period of a module must be longer than zero seconds")]
+    ZeroSubscriptionPeriod {},
+
+    #[error("Paying {periods} periods of {period} seconds overflows the subscription of {account_id}")]
+    SubscriptionOverflow {
+        account_id: AccountId,
+        period: u64,
+        periods: u64,
+    },
+
     #[error("requested sequence is invalid. Expected: {expected}, actual: {actual}")]
     InvalidAccountSequence { expected: u32, actual: u32 },
 }
@@ -13,12 +13,16 @@ use abstract_sdk::std::{
 use abstract_std::{
     objects::module::ModuleStatus,
     registry::{
-        state::{NAMESPACES, PENDING_MODULES, REV_NAMESPACES},
-        AccountListResponse, AccountsResponse, ModuleConfiguration, NamespaceInfo,
-        NamespaceResponse,
+        state::{
+            AUDITORS, MODULE_ATTESTATIONS, MODULE_SUBSCRIPTIONS, NAMESPACES, PENDING_MODULES,
+            PENDING_NAMESPACE_TRANSFERS, REV_NAMESPACES,
+        },
+        AccountListResponse, AccountStandingResponse, AccountsResponse, AuditorsResponse,
+        ModuleAttestationsResponse, ModuleConfiguration, ModuleStandingResponse, NamespaceInfo,
+        NamespaceResponse, PendingNamespaceTransferResponse,
     },
 };
-use cosmwasm_std::{Deps, Order, StdError, StdResult};
+use cosmwasm_std::{Deps, Env, Order, StdError, StdResult};
 use cw_storage_plus::{Bound, Map};
 
 use crate::{contract::VCResult, error::RegistryError};
@@ -133,6 +137,7 @@ pub fn handle_module_list_query(
         name: ref name_filter,
         version: version_filter,
         status,
+        attested_by,
     } = filter.unwrap_or_default();
 
     let mod_lib = match status {
@@ -173,6 +178,18 @@ pub fn handle_module_list_query(
     if let Some(version) = version_filter.map(ModuleVersion::Version) {
         modules.retain(|(info, _)| info.version == version);
     }
+    if let Some(auditor) = attested_by {
+        let mut attested = Vec::with_capacity(modules.len());
+        for (info, reference) in modules {
+            let attestations = MODULE_ATTESTATIONS
+                .may_load(deps.storage, &info)?
+                .unwrap_or_default();
+            if attestations.iter().any(|a| a.auditor == auditor) {
+                attested.push((info, reference));
+            }
+        }
+        modules = attested;
+    }
 
     let modules = modules
         .into_iter()
@@ -219,6 +236,14 @@ pub fn handle_namespace_query(deps: Deps, namespace: Namespace) -> StdResult<Nam
     }))
 }
 
+pub fn handle_pending_namespace_transfer_query(
+    deps: Deps,
+    namespace: Namespace,
+) -> StdResult<PendingNamespaceTransferResponse> {
+    let to_account = PENDING_NAMESPACE_TRANSFERS.may_load(deps.storage, &namespace)?;
+    Ok(PendingNamespaceTransferResponse { to_account })
+}
+
 pub fn handle_namespace_list_query(
     deps: Deps,
     start_after: Option<Namespace>,
@@ -235,6 +260,78 @@ pub fn handle_namespace_list_query(
     Ok(NamespaceListResponse { namespaces })
 }
 
+pub fn handle_module_standing_query(
+    deps: Deps,
+    env: &Env,
+    account_id: AccountId,
+    module: ModuleInfo,
+) -> StdResult<ModuleStandingResponse> {
+    // Resolves the version if latest is requested, installed versions may have been yanked since
+    let module = match module.version {
+        ModuleVersion::Latest => {
+            handle_modules_query(deps, vec![module])?
+                .modules
+                .swap_remove(0)
+                .module
+                .info
+        }
+        ModuleVersion::Version(_) => module,
+    };
+    let subscription = MODULE_SUBSCRIPTIONS.may_load(deps.storage, (&account_id, &module.id()))?;
+    let standing = ModuleConfiguration::from_storage(deps.storage, &module)?
+        .monetization
+        .standing(subscription, &env.block);
+
+    Ok(ModuleStandingResponse { standing })
+}
+
+pub fn handle_account_standing_query(
+    deps: Deps,
+    env: &Env,
+    account_id: AccountId,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<AccountStandingResponse> {
+    let start_bound = start_after.as_deref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let modules = MODULE_SUBSCRIPTIONS
+        .prefix(&account_id)
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|res| {
+            res.map(|(module_id, subscription)| (module_id, subscription.standing(&env.block)))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(AccountStandingResponse { modules })
+}
+
+pub fn handle_module_attestations_query(
+    deps: Deps,
+    module: ModuleInfo,
+) -> StdResult<ModuleAttestationsResponse> {
+    let attestations = MODULE_ATTESTATIONS
+        .may_load(deps.storage, &module)?
+        .unwrap_or_default();
+    Ok(ModuleAttestationsResponse { attestations })
+}
+
+pub fn handle_auditors_query(
+    deps: Deps,
+    start_after: Option<AccountId>,
+    limit: Option<u8>,
+) -> StdResult<AuditorsResponse> {
+    let start_bound = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let auditors = AUDITORS
+        .keys(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(AuditorsResponse { auditors })
+}
+
 /// Filter the modules with their primary key prefix (namespace)
 fn filter_modules_by_namespace(
     deps: Deps,
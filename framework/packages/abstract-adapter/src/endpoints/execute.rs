@@ -1,10 +1,10 @@
 use abstract_sdk::{
     base::{ExecuteEndpoint, Handler, IbcCallbackEndpoint, ModuleIbcEndpoint},
     features::ModuleIdentification,
-    AbstractResponse, AccountVerification,
+    AbstractResponse, AccountVerification, ModuleRegistryInterface,
 };
 use abstract_std::{
-    account::state::ACCOUNT_MODULES,
+    account::state::{ACCOUNT_ID, ACCOUNT_MODULES},
     adapter::{AdapterBaseMsg, AdapterExecuteMsg, AdapterRequestMsg, BaseExecuteMsg, ExecuteMsg},
     objects::ownership::nested_admin::query_top_level_owner_addr,
 };
@@ -110,7 +110,7 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
     /// 2. The sender is a account of the given account address.
     fn handle_app_msg(
         mut self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
         request: AdapterRequestMsg<CustomExecMsg>,
@@ -156,6 +156,10 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
                 .assert_is_account(sender)
                 .map_err(|_| unauthorized_sender())?,
         };
+        // Calls are rejected while the Account's subscription to this adapter is suspended
+        let account_id = ACCOUNT_ID.query(&deps.querier, account.addr().clone())?;
+        self.assert_module_standing(deps.branch(), &account_id, &env.block)?;
+
         self.target_account = Some(account);
         self.execute_handler()?(deps, env, info, self, request.request)
     }
@@ -511,7 +515,7 @@ mod tests {
         #[coverage_helper::test]
         fn unauthorized_addresses_are_unauthorized() {
             let mut deps = mock_dependencies();
-            deps.querier = MockQuerierBuilder::new(deps.api)
+            deps.querier = abstract_mock_querier_builder(deps.api)
                 .account(&test_account(deps.api), TEST_ACCOUNT_ID)
                 .set_account_admin_call_to(&test_account(deps.api))
                 .build();
@@ -545,7 +549,7 @@ mod tests {
         fn executing_as_account_account_is_allowed() {
             let mut deps = mock_dependencies();
             let account = test_account(deps.api);
-            deps.querier = MockQuerierBuilder::new(deps.api)
+            deps.querier = abstract_mock_querier_builder(deps.api)
                 .account(&account, TEST_ACCOUNT_ID)
                 .set_account_admin_call_to(&account)
                 .build();
@@ -565,7 +569,7 @@ mod tests {
         #[coverage_helper::test]
         fn executing_as_authorized_address_not_allowed_without_account() {
             let mut deps = mock_dependencies();
-            deps.querier = MockQuerierBuilder::new(deps.api)
+            deps.querier = abstract_mock_querier_builder(deps.api)
                 .account(&test_account(deps.api), TEST_ACCOUNT_ID)
                 .set_account_admin_call_to(&test_account(deps.api))
                 .build();
@@ -587,7 +591,7 @@ mod tests {
         fn executing_as_authorized_address_is_allowed_via_account() {
             let mut deps = mock_dependencies();
             let account = test_account(deps.api);
-            deps.querier = MockQuerierBuilder::new(deps.api)
+            deps.querier = abstract_mock_querier_builder(deps.api)
                 .account(&account, TEST_ACCOUNT_ID)
                 .set_account_admin_call_to(&account)
                 .build();
@@ -610,7 +614,7 @@ mod tests {
             let mut deps = mock_dependencies();
             let account = test_account(deps.api);
             let another_account = Account::new(deps.api.addr_make("some_other_account"));
-            deps.querier = MockQuerierBuilder::new(deps.api)
+            deps.querier = abstract_mock_querier_builder(deps.api)
                 .account(&account, TEST_ACCOUNT_ID)
                 .account(
                     &another_account,
@@ -632,4 +636,106 @@ mod tests {
             assert_unauthorized(res);
         }
     }
+
+    mod usage_fee {
+        use super::*;
+
+        use crate::mock::{mock_init_custom, MockAdapterContract};
+        use abstract_sdk::AbstractSdkError;
+        use abstract_std::{
+            objects::{
+                fee::FixedFee,
+                module::{Module, Monetization},
+                module_reference::ModuleReference,
+            },
+            registry::{
+                self, ModuleConfiguration, ModuleResponse, ModulesResponse, NamespaceInfo,
+                NamespaceResponse,
+            },
+        };
+        use cosmwasm_std::{coin, coins, from_json, to_json_binary, BankMsg, SubMsg};
+
+        const USAGE_FEE_ADAPTER: MockAdapterContract =
+            MockAdapterContract::new(TEST_MODULE_ID, TEST_VERSION, None).with_execute(
+                |deps, _, mut info, module, _| {
+                    let fee_msg = module.charge_usage_fee(deps.as_ref(), &mut info)?;
+                    Ok(Response::new()
+                        .add_messages(fee_msg)
+                        .add_attribute("remaining_funds", format!("{:?}", info.funds)))
+                },
+            );
+
+        fn execute_with_funds(
+            deps: &mut MockDeps,
+            sender: &Addr,
+            funds: &[cosmwasm_std::Coin],
+        ) -> Result<Response, MockError> {
+            let env = mock_env_validated(deps.api);
+            USAGE_FEE_ADAPTER.execute(
+                deps.as_mut(),
+                env,
+                message_info(sender, funds),
+                ExecuteMsg::Module(AdapterRequestMsg {
+                    account_address: None,
+                    request: MockExecMsg {},
+                }),
+            )
+        }
+
+        #[coverage_helper::test]
+        fn charges_usage_fee() -> AdapterMockResult {
+            let mut deps = mock_dependencies();
+            let abstr = AbstractMockAddrs::new(deps.api);
+            let account = test_account(deps.api);
+            let namespace_account = abstr.account.clone();
+            let adapter_addr = abstr.module_address.clone();
+            deps.querier = abstract_mock_querier_builder(deps.api)
+                .account(&account, TEST_ACCOUNT_ID)
+                .with_smart_handler(&abstr.registry, move |msg| {
+                    let res = match from_json(msg).unwrap() {
+                        registry::QueryMsg::Modules { infos } => to_json_binary(&ModulesResponse {
+                            modules: vec![ModuleResponse {
+                                module: Module {
+                                    info: infos[0].clone(),
+                                    reference: ModuleReference::Adapter(adapter_addr.clone()),
+                                },
+                                config: ModuleConfiguration::new(
+                                    Monetization::UsageFee(FixedFee::new(&coin(10, "ujuno"))),
+                                    None,
+                                    vec![],
+                                ),
+                            }],
+                        }),
+                        registry::QueryMsg::Namespace { .. } => {
+                            to_json_binary(&NamespaceResponse::Claimed(NamespaceInfo {
+                                account_id: ABSTRACT_ACCOUNT_ID,
+                                account: namespace_account.clone(),
+                            }))
+                        }
+                        _ => panic!("unexpected message"),
+                    };
+                    Ok(res.unwrap())
+                })
+                .build();
+            mock_init_custom(&mut deps, USAGE_FEE_ADAPTER)?;
+
+            let res = execute_with_funds(&mut deps, account.addr(), &coins(15, "ujuno"))?;
+            // Fee goes to the owner of the namespace, the rest stays available to the adapter
+            assert_eq!(
+                res.messages,
+                vec![SubMsg::new(BankMsg::Send {
+                    to_address: abstr.account.addr().to_string(),
+                    amount: coins(10, "ujuno"),
+                })]
+            );
+            assert_eq!(res.attributes[0].value, format!("{:?}", coins(5, "ujuno")));
+
+            let res = execute_with_funds(&mut deps, account.addr(), &[]);
+            assert!(matches!(
+                res,
+                Err(MockError::AbstractSdk(AbstractSdkError::Abstract(_)))
+            ));
+            Ok(())
+        }
+    }
 }
@@ -1,8 +1,8 @@
 use abstract_sdk::features::ModuleIdentification;
 use abstract_sdk::{
     base::{
-        AbstractContract, ExecuteHandlerFn, Handler, IbcCallbackHandlerFn, InstantiateHandlerFn,
-        ModuleIbcHandlerFn, QueryHandlerFn, ReplyHandlerFn, SudoHandlerFn,
+        AbstractContract, ExecuteHandlerFn, Handler, IbcCallbackHandlerFn, IbcCallbackIdHandlers,
+        InstantiateHandlerFn, ModuleIbcHandlerFn, QueryHandlerFn, ReplyHandlerFn, SudoHandlerFn,
     },
     namespaces::BASE_STATE,
     std::registry::Account,
@@ -143,6 +143,15 @@ impl<Error: ContractError, CustomInitMsg, CustomExecMsg, CustomQueryMsg, SudoMsg
         self
     }
 
+    /// add IBC callback handlers per callback id to contract
+    pub const fn with_ibc_callbacks(
+        mut self,
+        callback_handlers: IbcCallbackIdHandlers<Self, Error>,
+    ) -> Self {
+        self.contract = self.contract.with_ibc_callbacks(callback_handlers);
+        self
+    }
+
     /// add Module IBC to contract
     pub const fn with_module_ibc(
         mut self,
@@ -159,6 +168,8 @@ mod tests {
     use abstract_testing::prelude::*;
     use cosmwasm_std::Response;
 
+    use abstract_sdk::base::TypedIbcCallback;
+
     use super::*;
     use crate::mock::{AdapterMockResult, MOCK_ADAPTER, TEST_METADATA};
 
@@ -181,6 +192,12 @@ mod tests {
             .with_ibc_callback(|_, _, _, _, _| {
                 Ok(Response::new().set_data("mock_callback".as_bytes()))
             })
+            .with_ibc_callbacks(&[(
+                "mock_callback_id",
+                &TypedIbcCallback(|_, _, _, payload: String, _| {
+                    Ok(Response::new().set_data(payload.as_bytes()))
+                }),
+            )])
             .with_replies(&[(1u64, |_, _, _, msg| {
                 #[allow(deprecated)]
                 Ok(Response::new().set_data(msg.result.unwrap().data.unwrap()))
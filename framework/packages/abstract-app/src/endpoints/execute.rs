@@ -1,4 +1,6 @@
-use abstract_sdk::base::ModuleIbcEndpoint;
+use abstract_sdk::{
+    base::ModuleIbcEndpoint, features::AccountIdentification, ModuleRegistryInterface,
+};
 use abstract_std::app::{AppExecuteMsg, BaseExecuteMsg, ExecuteMsg};
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use schemars::JsonSchema;
@@ -27,13 +29,18 @@ impl<
 
     fn execute(
         self,
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
         msg: Self::ExecuteMsg,
     ) -> Result<Response, Error> {
         match msg {
-            ExecuteMsg::Module(request) => self.execute_handler()?(deps, env, info, self, request),
+            ExecuteMsg::Module(request) => {
+                // Calls are rejected while the Account's subscription to this app is suspended
+                let account_id = self.account_id(deps.as_ref())?;
+                self.assert_module_standing(deps.branch(), &account_id, &env.block)?;
+                self.execute_handler()?(deps, env, info, self, request)
+            }
             ExecuteMsg::Base(exec_msg) => self
                 .base_execute(deps, env, info, exec_msg)
                 .map_err(From::from),
@@ -62,3 +69,120 @@ impl<
         unreachable!("App BaseExecuteMsg could not be constructed")
     }
 }
+
+#[cfg(test)]
+mod test {
+    use abstract_sdk::AbstractSdkError;
+    use abstract_std::{
+        objects::{
+            fee::FixedFee,
+            module::{Module, Monetization},
+            module_reference::ModuleReference,
+        },
+        registry::{
+            self, ModuleConfiguration, ModuleResponse, ModulesResponse, NamespaceInfo,
+            NamespaceResponse,
+        },
+    };
+    use abstract_testing::{mock_env_validated, prelude::*};
+    use cosmwasm_std::{
+        coin, coins, from_json, testing::message_info, to_json_binary, BankMsg, SubMsg,
+    };
+
+    use super::*;
+    use crate::mock::*;
+
+    const USAGE_FEE_APP: MockAppContract = MockAppContract::new(TEST_MODULE_ID, TEST_VERSION, None)
+        .with_execute(|deps, _, mut info, module, _| {
+            let fee_msg = module.charge_usage_fee(deps.as_ref(), &mut info)?;
+            Ok(Response::new()
+                .add_messages(fee_msg)
+                .add_attribute("remaining_funds", format!("{:?}", info.funds)))
+        });
+
+    fn mock_deps_with_usage_fee(monetization: Monetization) -> MockDeps {
+        let mut deps = mock_init();
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let account = test_account(deps.api);
+        let namespace_account = abstr.account.clone();
+
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .with_smart_handler(&abstr.registry, move |msg| {
+                let res = match from_json(msg).unwrap() {
+                    registry::QueryMsg::Modules { infos } => to_json_binary(&ModulesResponse {
+                        modules: vec![ModuleResponse {
+                            module: Module {
+                                info: infos[0].clone(),
+                                reference: ModuleReference::App(1),
+                            },
+                            config: ModuleConfiguration::new(monetization.clone(), None, vec![]),
+                        }],
+                    }),
+                    registry::QueryMsg::Namespace { .. } => {
+                        to_json_binary(&NamespaceResponse::Claimed(NamespaceInfo {
+                            account_id: ABSTRACT_ACCOUNT_ID,
+                            account: namespace_account.clone(),
+                        }))
+                    }
+                    _ => panic!("unexpected message"),
+                };
+                Ok(res.unwrap())
+            })
+            .build();
+        deps
+    }
+
+    #[coverage_helper::test]
+    fn charges_usage_fee() -> AppTestResult {
+        let mut deps =
+            mock_deps_with_usage_fee(Monetization::UsageFee(FixedFee::new(&coin(10, "ujuno"))));
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let sender = deps.api.addr_make("sender");
+        let env = mock_env_validated(deps.api);
+
+        let res = USAGE_FEE_APP.execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&sender, &coins(15, "ujuno")),
+            ExecuteMsg::Module(MockExecMsg::DoSomething {}),
+        )?;
+        // Fee goes to the owner of the namespace, the rest stays available to the app
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: abstr.account.addr().to_string(),
+                amount: coins(10, "ujuno"),
+            })]
+        );
+        assert_eq!(res.attributes[0].value, format!("{:?}", coins(5, "ujuno")));
+
+        let res = USAGE_FEE_APP.execute(
+            deps.as_mut(),
+            env,
+            message_info(&sender, &coins(5, "ujuno")),
+            ExecuteMsg::Module(MockExecMsg::DoSomething {}),
+        );
+        assert!(matches!(
+            res,
+            Err(MockError::AbstractSdk(AbstractSdkError::Abstract(_)))
+        ));
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn no_usage_fee() -> AppTestResult {
+        let mut deps = mock_deps_with_usage_fee(Monetization::None);
+        let sender = deps.api.addr_make("sender");
+
+        let res = USAGE_FEE_APP.execute(
+            deps.as_mut(),
+            mock_env_validated(deps.api),
+            message_info(&sender, &coins(5, "ujuno")),
+            ExecuteMsg::Module(MockExecMsg::DoSomething {}),
+        )?;
+        assert!(res.messages.is_empty());
+        assert_eq!(res.attributes[0].value, format!("{:?}", coins(5, "ujuno")));
+        Ok(())
+    }
+}
@@ -32,3 +32,145 @@ impl<
         Ok(registry_query_result.reference.unwrap_native()?)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use abstract_sdk::{base::TypedIbcCallback, AbstractSdkError};
+    use abstract_std::{
+        ibc::{Callback, IbcResponseMsg, IbcResult},
+        objects::{
+            dependency::StaticDependency, module::Module, module_reference::ModuleReference,
+        },
+        registry::{ModuleConfiguration, ModuleResponse, ModulesResponse},
+    };
+    use abstract_testing::{mock_env_validated, prelude::*};
+    use cosmwasm_std::{to_json_binary, Addr, Response};
+
+    use super::*;
+    use crate::mock::*;
+
+    #[cosmwasm_schema::cw_serde]
+    struct MockCallbackPayload {
+        amount: u64,
+    }
+
+    const CALLBACK_APP: MockAppContract = MockAppContract::new(TEST_MODULE_ID, TEST_VERSION, None)
+        .with_dependencies(&[StaticDependency::new(
+            IBC_CLIENT,
+            &[abstract_std::constants::ABSTRACT_VERSION],
+        )])
+        .with_ibc_callbacks(&[(
+            "transfer",
+            &TypedIbcCallback(|_, _, _, payload: MockCallbackPayload, _| {
+                Ok(Response::new().add_attribute("amount", payload.amount.to_string()))
+            }),
+        )]);
+
+    fn mock_deps_with_ibc_client() -> (MockDeps, Addr) {
+        let mut deps = mock_init();
+        let ibc_client = deps.api.addr_make("ibc_client");
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let account = test_account(deps.api);
+
+        let client = ibc_client.clone();
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .with_smart_handler(&abstr.registry, move |_| {
+                Ok(to_json_binary(&ModulesResponse {
+                    modules: vec![ModuleResponse {
+                        module: Module {
+                            info: ModuleInfo::from_id_latest(IBC_CLIENT).unwrap(),
+                            reference: ModuleReference::Native(client.clone()),
+                        },
+                        config: ModuleConfiguration::default(),
+                    }],
+                })
+                .unwrap())
+            })
+            .build();
+        (deps, ibc_client)
+    }
+
+    fn callback_msg(callback_id: &str) -> IbcResponseMsg {
+        IbcResponseMsg {
+            callback: Callback::with_id(callback_id, &MockCallbackPayload { amount: 42 }).unwrap(),
+            result: IbcResult::FatalError("timeout".to_owned()),
+        }
+    }
+
+    #[coverage_helper::test]
+    fn routes_callback_to_typed_handler() -> AppTestResult {
+        let (mut deps, ibc_client) = mock_deps_with_ibc_client();
+        let env = mock_env_validated(deps.api);
+
+        let res = CALLBACK_APP.ibc_callback(
+            deps.as_mut(),
+            env,
+            message_info(&ibc_client, &[]),
+            callback_msg("transfer"),
+        )?;
+
+        assert_eq!(res.attributes, vec![cosmwasm_std::attr("amount", "42")]);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn unknown_callback_id() {
+        let (mut deps, ibc_client) = mock_deps_with_ibc_client();
+        let env = mock_env_validated(deps.api);
+
+        let err = CALLBACK_APP
+            .ibc_callback(
+                deps.as_mut(),
+                env,
+                message_info(&ibc_client, &[]),
+                callback_msg("unknown"),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            MockError::AbstractSdk(AbstractSdkError::UnknownIbcCallbackId {
+                callback_id: "unknown".to_owned(),
+                module: TEST_MODULE_ID.to_owned(),
+            })
+        );
+    }
+
+    #[coverage_helper::test]
+    fn callback_without_id_is_not_routed() {
+        #[cosmwasm_schema::cw_serde]
+        struct IdLikeMsg {
+            callback_id: String,
+            payload: cosmwasm_std::Binary,
+        }
+
+        let (mut deps, ibc_client) = mock_deps_with_ibc_client();
+        let env = mock_env_validated(deps.api);
+
+        // A message that looks like an id callback is still handled by the default handler
+        let callback = Callback::new(&IdLikeMsg {
+            callback_id: "transfer".to_owned(),
+            payload: to_json_binary(&MockCallbackPayload { amount: 42 }).unwrap(),
+        })
+        .unwrap();
+        let err = CALLBACK_APP
+            .ibc_callback(
+                deps.as_mut(),
+                env,
+                message_info(&ibc_client, &[]),
+                IbcResponseMsg {
+                    callback,
+                    result: IbcResult::FatalError("timeout".to_owned()),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            MockError::AbstractSdk(AbstractSdkError::NoModuleIbcHandler(
+                TEST_MODULE_ID.to_owned()
+            ))
+        );
+    }
+}
@@ -2,8 +2,8 @@ use abstract_std::{
     app::MigrateMsg,
     objects::module_version::{assert_contract_upgrade, set_module_data},
 };
-use cosmwasm_std::Response;
-use cw2::set_contract_version;
+use cosmwasm_std::StdError;
+use cw2::{get_contract_version, set_contract_version};
 use schemars::JsonSchema;
 use serde::Serialize;
 
@@ -23,13 +23,16 @@ impl<
 
     fn migrate(
         self,
-        deps: cosmwasm_std::DepsMut,
+        mut deps: cosmwasm_std::DepsMut,
         env: cosmwasm_std::Env,
         msg: Self::MigrateMsg,
     ) -> Result<cosmwasm_std::Response, Self::Error> {
         let (name, version_string, metadata) = self.info();
-        let to_version = version_string.parse().unwrap();
-        assert_contract_upgrade(deps.storage, name, to_version)?;
+        let from_version = get_contract_version(deps.storage)?
+            .version
+            .parse()
+            .map_err(|e| StdError::parse_err("semver::Version", e))?;
+        assert_contract_upgrade(deps.storage, name, version_string.parse().unwrap())?;
         set_module_data(
             deps.storage,
             name,
@@ -38,9 +41,96 @@ impl<
             metadata,
         )?;
         set_contract_version(deps.storage, name, version_string)?;
-        if let Some(migrate_fn) = self.maybe_migrate_handler() {
-            return migrate_fn(deps, env, self, msg.module);
-        }
-        Ok(Response::default())
+
+        let to_version = version_string.parse().unwrap();
+        let response = self.run_migration_steps(deps.branch(), &env, &from_version, &to_version)?;
+        let Some(migrate_fn) = self.maybe_migrate_handler() else {
+            return Ok(response);
+        };
+        let custom_response = migrate_fn(deps, env, self, msg.module)?;
+        let mut response = response
+            .add_submessages(custom_response.messages)
+            .add_attributes(custom_response.attributes)
+            .add_events(custom_response.events);
+        response.data = custom_response.data;
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use abstract_sdk::base::MigrateEndpoint;
+    use abstract_std::app::{BaseMigrateMsg, MigrateMsg};
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{attr, Response, StdError};
+
+    use crate::mock::*;
+
+    const MIGRATING_APP: MockAppContract = MockAppContract::new(TEST_MODULE_ID, "0.2.0", None)
+        .with_migrations(&[
+            ("<0.1.0", |_, _, _| {
+                Ok(Response::new().add_attribute("step", "v00_to_v01"))
+            }),
+            ("<0.1.5", |_, _, _| {
+                Ok(Response::new().add_attribute("step", "v013_to_v015"))
+            }),
+            ("<0.2.0", |_, _, _| {
+                Ok(Response::new().add_attribute("step", "v01_to_v02"))
+            }),
+            ("<0.3.0", |_, _, _| {
+                Ok(Response::new().add_attribute("step", "v02_to_v03"))
+            }),
+        ])
+        .with_migrate(|_, _, _, _| Ok(Response::new().add_attribute("custom", "migrate")));
+
+    #[coverage_helper::test]
+    fn runs_applicable_steps_in_order() -> AppTestResult {
+        let mut deps = mock_init();
+        let env = mock_env_validated(deps.api);
+        cw2::set_contract_version(&mut deps.storage, TEST_MODULE_ID, "0.1.3")?;
+
+        let res = MIGRATING_APP.migrate(
+            deps.as_mut(),
+            env,
+            MigrateMsg {
+                base: BaseMigrateMsg {},
+                module: MockMigrateMsg,
+            },
+        )?;
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("step", "v013_to_v015"),
+                attr("step", "v01_to_v02"),
+                attr("migration_steps", "<0.1.5,<0.2.0"),
+                attr("custom", "migrate"),
+            ]
+        );
+        assert_eq!(
+            cw2::get_contract_version(&deps.storage)?.version,
+            "0.2.0".to_owned()
+        );
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn invalid_stored_version_errors() -> AppTestResult {
+        let mut deps = mock_init();
+        let env = mock_env_validated(deps.api);
+        cw2::set_contract_version(&mut deps.storage, TEST_MODULE_ID, "not-a-version")?;
+
+        let res = MIGRATING_APP.migrate(
+            deps.as_mut(),
+            env,
+            MigrateMsg {
+                base: BaseMigrateMsg {},
+                module: MockMigrateMsg,
+            },
+        );
+        assert!(matches!(
+            res,
+            Err(MockError::Std(StdError::ParseErr { .. }))
+        ));
+        Ok(())
     }
 }
@@ -14,8 +14,8 @@ use cosmwasm_std::{Empty, StdResult, Storage};
 use cw_storage_plus::Item;
 
 use crate::{
-    AbstractContract, AppError, ExecuteHandlerFn, IbcCallbackHandlerFn, InstantiateHandlerFn,
-    MigrateHandlerFn, QueryHandlerFn, ReplyHandlerFn,
+    AbstractContract, AppError, ExecuteHandlerFn, IbcCallbackHandlerFn, IbcCallbackIdHandlers,
+    InstantiateHandlerFn, MigrateHandlerFn, MigrationStepFn, QueryHandlerFn, ReplyHandlerFn,
 };
 
 pub trait ContractError:
@@ -129,6 +129,15 @@ impl<
         self
     }
 
+    /// add ordered migration steps, keyed by the version requirement of the migrated-from version
+    pub const fn with_migrations(
+        mut self,
+        migration_steps: &'static [(&'static str, MigrationStepFn<Self, Error>)],
+    ) -> Self {
+        self.contract = self.contract.with_migrations(migration_steps);
+        self
+    }
+
     pub const fn with_replies(
         mut self,
         reply_handlers: &'static [(u64, ReplyHandlerFn<Self, Error>)],
@@ -148,6 +157,15 @@ impl<
         self
     }
 
+    /// add IBC callback handlers per callback id to contract
+    pub const fn with_ibc_callbacks(
+        mut self,
+        callback_handlers: IbcCallbackIdHandlers<Self, Error>,
+    ) -> Self {
+        self.contract = self.contract.with_ibc_callbacks(callback_handlers);
+        self
+    }
+
     /// add Module IBC to contract
     pub const fn with_module_ibc(
         mut self,
@@ -163,7 +181,7 @@ mod tests {
     use abstract_testing::prelude::*;
     use cosmwasm_std::Response;
 
-    use crate::mock::MockAppContract;
+    use crate::{mock::MockAppContract, TypedIbcCallback};
 
     #[coverage_helper::test]
     fn builder() {
@@ -175,6 +193,12 @@ mod tests {
             .with_ibc_callback(|_, _, _, _, _| {
                 Ok(Response::new().set_data("mock_callback".as_bytes()))
             })
+            .with_ibc_callbacks(&[(
+                "mock_callback_id",
+                &TypedIbcCallback(|_, _, _, payload: String, _| {
+                    Ok(Response::new().set_data(payload.as_bytes()))
+                }),
+            )])
             .with_replies(&[(1u64, |_, _, _, msg| {
                 #[allow(deprecated)]
                 Ok(Response::new().set_data(msg.result.unwrap().data.unwrap()))
@@ -53,8 +53,8 @@ use cw_orch::{
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    client::AbstractClientResult, infrastructure::Infrastructure, AbstractClientError, Application,
-    Environment, Publisher,
+    client::AbstractClientResult, infrastructure::Infrastructure, AbstractClientError,
+    AccountBatch, Application, Environment, Publisher,
 };
 
 /// A builder for creating [`Accounts`](Account).
@@ -560,6 +560,12 @@ impl<Chain: CwEnv> Account<Chain> {
         self.configure(&account::ExecuteMsg::Execute { msgs }, funds)
     }
 
+    /// Builder to combine account operations into a single transaction.
+    /// See [`AccountBatch`] for simulating and broadcasting the batch.
+    pub fn batch(&self) -> AccountBatch<Chain> {
+        AccountBatch::new(self)
+    }
+
     /// Executes a [`account::ExecuteMsg`] on the account.
     pub fn configure(
         &self,
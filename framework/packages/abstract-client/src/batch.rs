@@ -0,0 +1,345 @@
+//! # Account transaction batching
+//!
+//! [`AccountBatch`] accumulates [`Account`] operations and broadcasts them as a single transaction,
+//! or simulates them without changing the chain state.
+//!
+//! ```
+//! # use abstract_client::{AbstractClient, AbstractClientError};
+//! # use cw_orch::prelude::*;
+//! # let chain = MockBech32::new("mock");
+//! # let client = AbstractClient::builder(chain.clone()).build()?;
+//! let account = client.account_builder().build()?;
+//!
+//! let mut batch = account.batch();
+//! batch.configure(
+//!     &abstract_std::account::ExecuteMsg::UpdateInfo {
+//!         name: Some("Alice".to_owned()),
+//!         description: None,
+//!         link: None,
+//!     },
+//!     &[],
+//! )?;
+//!
+//! // Simulating does not change the account
+//! assert!(batch.simulate()?.is_ok());
+//! assert_ne!(account.info()?.name, Some("Alice".to_owned()));
+//!
+//! batch.commit()?;
+//! assert_eq!(account.info()?.name, Some("Alice".to_owned()));
+//! # Ok::<(), AbstractClientError>(())
+//! ```
+use abstract_interface::{DependencyCreation, InstallConfig};
+use abstract_std::{
+    account::{self, ModuleInstallConfig},
+    objects::module::{ModuleInfo, ModuleVersion},
+};
+use cosmwasm_std::{to_json_binary, wasm_execute, Api, CosmosMsg, Event, Order, Storage};
+use cw_orch::{
+    environment::StateInterface,
+    mock::{cw_multi_test::AppResponse, MockBase},
+    prelude::*,
+};
+
+use crate::{client::AbstractClientResult, Account, Environment};
+
+/// Environment that can broadcast and simulate multiple messages in one transaction.
+///
+/// Implemented for the mock environments and, with the `daemon` feature, for [`Daemon`](cw_orch::daemon::Daemon).
+pub trait BatchTxHandler: CwEnv {
+    /// Broadcast `msgs`, signed by the sender, in a single transaction.
+    fn commit_batch(&self, msgs: Vec<CosmosMsg>) -> Result<Self::Response, CwOrchError>;
+
+    /// Execute `msgs` without committing the resulting state.
+    fn simulate_batch(&self, msgs: Vec<CosmosMsg>) -> Result<BatchSimulation, CwOrchError>;
+}
+
+/// Outcome of a simulated batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchSimulation {
+    /// Estimated gas, `None` for environments without gas metering
+    pub gas_used: Option<u64>,
+    /// Events emitted by the transaction, empty if it failed
+    pub events: Vec<Event>,
+    /// Error the transaction would fail with
+    pub error: Option<String>,
+}
+
+impl BatchSimulation {
+    /// Whether the transaction would succeed
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Builder of a multi-message transaction on an [`Account`].
+/// Get the builder with [`Account::batch`].
+pub struct AccountBatch<'a, Chain: CwEnv> {
+    account: &'a Account<Chain>,
+    msgs: Vec<CosmosMsg>,
+}
+
+impl<'a, Chain: CwEnv> AccountBatch<'a, Chain> {
+    pub(crate) fn new(account: &'a Account<Chain>) -> Self {
+        Self {
+            account,
+            msgs: vec![],
+        }
+    }
+
+    /// Install an application on the account.
+    pub fn install_app<M: InstallConfig>(
+        &mut self,
+        configuration: &M::InitMsg,
+        funds: &[Coin],
+    ) -> AbstractClientResult<&mut Self> {
+        self.install_modules(vec![M::install_config(configuration)?], funds)
+    }
+
+    /// Install an adapter on the account.
+    pub fn install_adapter<M: InstallConfig<InitMsg = Empty>>(
+        &mut self,
+        funds: &[Coin],
+    ) -> AbstractClientResult<&mut Self> {
+        self.install_modules(vec![M::install_config(&Empty {})?], funds)
+    }
+
+    /// Install an application and its dependencies on the account.
+    pub fn install_app_with_dependencies<M: DependencyCreation + InstallConfig>(
+        &mut self,
+        module_configuration: &M::InitMsg,
+        dependencies_config: M::DependenciesConfig,
+        funds: &[Coin],
+    ) -> AbstractClientResult<&mut Self> {
+        let mut install_configs = M::dependency_install_configs(dependencies_config)?;
+        install_configs.push(M::install_config(module_configuration)?);
+        self.install_modules(install_configs, funds)
+    }
+
+    /// Install modules on the account.
+    pub fn install_modules(
+        &mut self,
+        modules: Vec<ModuleInstallConfig>,
+        funds: &[Coin],
+    ) -> AbstractClientResult<&mut Self> {
+        self.configure(&account::ExecuteMsg::InstallModules { modules }, funds)
+    }
+
+    /// Upgrade the account to the given version.
+    pub fn upgrade(&mut self, version: ModuleVersion) -> AbstractClientResult<&mut Self> {
+        let modules = vec![(
+            ModuleInfo::from_id(abstract_std::constants::ACCOUNT, version)?,
+            Some(to_json_binary(&account::MigrateMsg {}).map_err(Into::<CwOrchError>::into)?),
+        )];
+        self.configure(&account::ExecuteMsg::Upgrade { modules }, &[])
+    }
+
+    /// Execute [`CosmosMsg`]s on the account.
+    pub fn execute(
+        &mut self,
+        execute_msgs: impl IntoIterator<Item = impl Into<CosmosMsg>>,
+        funds: &[Coin],
+    ) -> AbstractClientResult<&mut Self> {
+        let msgs = execute_msgs.into_iter().map(Into::into).collect();
+        self.configure(&account::ExecuteMsg::Execute { msgs }, funds)
+    }
+
+    /// Add an [`account::ExecuteMsg`] to the batch.
+    pub fn configure(
+        &mut self,
+        execute_msg: &account::ExecuteMsg,
+        funds: &[Coin],
+    ) -> AbstractClientResult<&mut Self> {
+        let msg = wasm_execute(self.account.address()?, execute_msg, funds.to_vec())
+            .map_err(Into::<CwOrchError>::into)?;
+        self.msgs.push(msg.into());
+        Ok(self)
+    }
+
+    /// Messages of the transaction, in execution order.
+    pub fn msgs(&self) -> &[CosmosMsg] {
+        &self.msgs
+    }
+}
+
+impl<'a, Chain: BatchTxHandler> AccountBatch<'a, Chain> {
+    /// Broadcast all operations in a single transaction.
+    pub fn commit(&self) -> AbstractClientResult<Chain::Response> {
+        self.account
+            .environment()
+            .commit_batch(self.msgs.clone())
+            .map_err(Into::into)
+    }
+
+    /// Simulate the transaction without broadcasting it.
+    pub fn simulate(&self) -> AbstractClientResult<BatchSimulation> {
+        self.account
+            .environment()
+            .simulate_batch(self.msgs.clone())
+            .map_err(Into::into)
+    }
+}
+
+impl<A: Api, S: StateInterface> BatchTxHandler for MockBase<A, S> {
+    fn commit_batch(&self, msgs: Vec<CosmosMsg>) -> Result<AppResponse, CwOrchError> {
+        let responses = self
+            .app
+            .borrow_mut()
+            .execute_multi(self.sender_addr(), msgs)
+            .map_err(CwOrchError::from)?;
+        Ok(AppResponse {
+            data: responses.last().and_then(|response| response.data.clone()),
+            events: responses
+                .into_iter()
+                .flat_map(|response| response.events)
+                .collect(),
+        })
+    }
+
+    fn simulate_batch(&self, msgs: Vec<CosmosMsg>) -> Result<BatchSimulation, CwOrchError> {
+        let mut app = self.app.borrow_mut();
+        let snapshot: Vec<(Vec<u8>, Vec<u8>)> =
+            app.storage().range(None, None, Order::Ascending).collect();
+
+        // A failed execution is reverted by the app, a successful one by restoring the snapshot
+        let simulation = match app.execute_multi(self.sender_addr(), msgs) {
+            Ok(responses) => {
+                let storage = app.storage_mut();
+                let keys: Vec<Vec<u8>> = storage
+                    .range(None, None, Order::Ascending)
+                    .map(|(key, _)| key)
+                    .collect();
+                for key in keys {
+                    storage.remove(&key);
+                }
+                for (key, value) in snapshot {
+                    storage.set(&key, &value);
+                }
+                BatchSimulation {
+                    gas_used: None,
+                    events: responses
+                        .into_iter()
+                        .flat_map(|response| response.events)
+                        .collect(),
+                    error: None,
+                }
+            }
+            Err(error) => BatchSimulation {
+                gas_used: None,
+                events: vec![],
+                error: Some(format!("{error:?}")),
+            },
+        };
+        Ok(simulation)
+    }
+}
+
+#[cfg(feature = "daemon")]
+mod daemon {
+    use cosmrs::{cosmwasm::MsgExecuteContract, tx::Msg, Any};
+    use cosmwasm_std::{Addr, CosmosMsg, WasmMsg};
+    use cw_orch::{
+        daemon::{CosmTxResponse, Daemon},
+        prelude::*,
+    };
+
+    use super::{BatchSimulation, BatchTxHandler};
+
+    /// Encode a batched message, only contract executions can be batched.
+    pub(super) fn to_any(sender: &Addr, msg: CosmosMsg) -> Result<Any, CwOrchError> {
+        let CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) = msg
+        else {
+            return Err(CwOrchError::StdErr(format!(
+                "Only contract executions can be batched, got {msg:?}"
+            )));
+        };
+        let parse_error = |error: cosmrs::ErrorReport| CwOrchError::StdErr(error.to_string());
+        let funds = funds
+            .into_iter()
+            .map(|coin| {
+                Ok(cosmrs::Coin {
+                    denom: coin.denom.parse().map_err(parse_error)?,
+                    amount: coin.amount.u128(),
+                })
+            })
+            .collect::<Result<Vec<_>, CwOrchError>>()?;
+        MsgExecuteContract {
+            sender: sender.as_str().parse().map_err(parse_error)?,
+            contract: contract_addr.parse().map_err(parse_error)?,
+            msg: msg.to_vec(),
+            funds,
+        }
+        .to_any()
+        .map_err(parse_error)
+    }
+
+    impl BatchTxHandler for Daemon {
+        fn commit_batch(&self, msgs: Vec<CosmosMsg>) -> Result<CosmTxResponse, CwOrchError> {
+            let sender = self.sender_addr();
+            let msgs = msgs
+                .into_iter()
+                .map(|msg| to_any(&sender, msg))
+                .collect::<Result<Vec<_>, _>>()?;
+            self.commit_any(msgs, None).map_err(Into::into)
+        }
+
+        /// Simulates the transaction on the node. The node only reports the gas estimate, so no events are returned.
+        fn simulate_batch(&self, msgs: Vec<CosmosMsg>) -> Result<BatchSimulation, CwOrchError> {
+            let sender = self.sender_addr();
+            let msgs = msgs
+                .into_iter()
+                .map(|msg| to_any(&sender, msg))
+                .collect::<Result<Vec<_>, _>>()?;
+            let simulation = match self.rt_handle.block_on(self.sender().simulate(msgs, None)) {
+                Ok((gas_used, _fee)) => BatchSimulation {
+                    gas_used: Some(gas_used),
+                    events: vec![],
+                    error: None,
+                },
+                Err(error) => BatchSimulation {
+                    gas_used: None,
+                    events: vec![],
+                    error: Some(error.to_string()),
+                },
+            };
+            Ok(simulation)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use cosmwasm_std::{coins, testing::MockApi, wasm_execute, BankMsg};
+
+        use super::*;
+
+        #[coverage_helper::test]
+        fn encodes_contract_executions() {
+            let api = MockApi::default().with_prefix("juno");
+            let sender = api.addr_make("sender");
+            let account = api.addr_make("account");
+            let msg = wasm_execute(&account, &Empty {}, coins(10, "ujuno")).unwrap();
+
+            let any = to_any(&sender, msg.into()).unwrap();
+            let decoded = MsgExecuteContract::from_any(&any).unwrap();
+            assert_eq!(decoded.sender.to_string(), sender.to_string());
+            assert_eq!(decoded.contract.to_string(), account.to_string());
+            assert_eq!(decoded.msg, b"{}".to_vec());
+            assert_eq!(decoded.funds[0].amount, 10);
+        }
+
+        #[coverage_helper::test]
+        fn rejects_other_messages() {
+            let api = MockApi::default().with_prefix("juno");
+            let sender = api.addr_make("sender");
+            let msg = BankMsg::Send {
+                to_address: sender.to_string(),
+                amount: coins(10, "ujuno"),
+            };
+
+            assert!(to_any(&sender, msg.into()).is_err());
+        }
+    }
+}
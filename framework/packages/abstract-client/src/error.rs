@@ -55,10 +55,31 @@ pub enum AbstractClientError {
         ibc_client_addr: cosmwasm_std::Addr,
     },
 
+    #[cfg(feature = "interchain")]
+    #[error("Sub-account of remote account {parent} was not created")]
+    RemoteSubAccountNotCreated {
+        parent: abstract_std::objects::AccountId,
+    },
+
+    #[cfg(feature = "interchain")]
+    #[error("Remote account {account_id} is owned by the IBC host, its namespace can only be claimed on creation")]
+    RemoteNamespaceClaim {
+        account_id: abstract_std::objects::AccountId,
+    },
+
     #[cfg(feature = "interchain")]
     #[error(transparent)]
     InterchainError(#[from] cw_orch_interchain::core::InterchainError),
 
     #[error("Service API only allows claiming service modules")]
     ExpectedService {},
+
+    #[error(transparent)]
+    ManifestParse(#[from] serde_json::Error),
+
+    #[error("Top-level account of the manifest needs a namespace")]
+    ManifestWithoutNamespace {},
+
+    #[error("Sub-account of {parent} in the manifest needs a namespace or a name")]
+    ManifestUnidentifiedSubAccount { parent: String },
 }
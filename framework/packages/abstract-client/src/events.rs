@@ -0,0 +1,49 @@
+//! # Event decoding
+//!
+//! [`AbstractEvents`] decodes the Abstract events of a transaction into [`AbstractEvent`]s,
+//! so indexers don't need to parse the attributes themselves.
+//!
+//! ```
+//! # use abstract_client::{AbstractClient, AbstractClientError};
+//! # use cw_orch::prelude::*;
+//! use abstract_client::events::{AbstractEvent, AbstractEvents};
+//!
+//! # let chain = MockBech32::new("mock");
+//! # let client = AbstractClient::builder(chain.clone()).build()?;
+//! let account = client.account_builder().build()?;
+//!
+//! let mut batch = account.batch();
+//! batch.configure(
+//!     &abstract_std::account::ExecuteMsg::CreateSubAccount {
+//!         name: None,
+//!         description: None,
+//!         link: None,
+//!         namespace: None,
+//!         install_modules: vec![],
+//!         account_id: None,
+//!     },
+//!     &[],
+//! )?;
+//! let events = batch.commit()?.abstract_events()?;
+//!
+//! assert!(events
+//!     .iter()
+//!     .any(|event| matches!(event, AbstractEvent::SubAccountCreated { .. })));
+//! # Ok::<(), AbstractClientError>(())
+//! ```
+pub use abstract_std::events::{AbstractEvent, IbcCallbackKind};
+use cw_orch::prelude::IndexResponse;
+
+use crate::client::AbstractClientResult;
+
+/// Decode the Abstract events of a transaction response.
+pub trait AbstractEvents {
+    /// All Abstract events emitted by the transaction, in emission order.
+    fn abstract_events(&self) -> AbstractClientResult<Vec<AbstractEvent>>;
+}
+
+impl<T: IndexResponse> AbstractEvents for T {
+    fn abstract_events(&self) -> AbstractClientResult<Vec<AbstractEvent>> {
+        AbstractEvent::from_events(&self.events()).map_err(Into::into)
+    }
+}
@@ -3,7 +3,7 @@ mod remote_application;
 use std::collections::HashMap;
 
 use cw_orch_interchain::prelude::*;
-pub use remote_account::RemoteAccount;
+pub use remote_account::{RemoteAccount, RemoteSubAccountBuilder};
 pub use remote_application::RemoteApplication;
 
 use crate::{client::AbstractClientResult, AbstractClient, Environment};
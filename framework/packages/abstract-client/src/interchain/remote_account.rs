@@ -4,24 +4,25 @@
 //!
 
 use abstract_interface::{
-    Abstract, AccountDetails, AccountI, AccountQueryFns as _, DependencyCreation, IbcClient,
-    InstallConfig, RegisteredModule,
+    Abstract, AbstractInterfaceError, AccountDetails, AccountI, AccountQueryFns as _,
+    DependencyCreation, IbcClient, InstallConfig, RegisteredModule,
 };
 use abstract_std::{
     account::{
         self, state::AccountInfo, AccountModuleInfo, InfoResponse, ModuleAddressesResponse,
-        ModuleInfosResponse, ModuleInstallConfig,
+        ModuleInfosResponse, ModuleInstallConfig, SubAccountIdsResponse,
     },
     ibc_client::{self, QueryMsgFns as _},
     ibc_host,
     objects::{
         module::{ModuleId, ModuleInfo, ModuleVersion},
         namespace::Namespace,
-        ownership, AccountId, TruncatedChainId,
+        ownership::{self, Expiration, GovAction, GovernanceDetails},
+        AccountId, TruncatedChainId,
     },
-    IBC_CLIENT,
+    registry, IBC_CLIENT,
 };
-use cosmwasm_std::{to_json_binary, CosmosMsg, Uint128};
+use cosmwasm_std::{to_json_binary, wasm_execute, CosmosMsg, StdResult, Uint128};
 use cw_orch::{
     contract::Contract,
     environment::{Environment as _, MutCwEnv},
@@ -196,6 +197,142 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccountBuilder<Cha
     }
 }
 
+/// A builder for creating sub-accounts of a [`RemoteAccount`].
+/// Get the builder from [`RemoteAccount::sub_account_builder`]
+/// and create the sub-account with the `build` method.
+pub struct RemoteSubAccountBuilder<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> {
+    parent: &'a RemoteAccount<Chain, IBC>,
+    name: String,
+    description: Option<String>,
+    link: Option<String>,
+    namespace: Option<Namespace>,
+    install_modules: Vec<ModuleInstallConfig>,
+    expected_local_account_id: Option<u32>,
+}
+
+impl<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>>
+    RemoteSubAccountBuilder<'a, Chain, IBC>
+{
+    pub(crate) fn new(parent: &'a RemoteAccount<Chain, IBC>) -> Self {
+        Self {
+            parent,
+            name: String::from("Sub Account"),
+            description: None,
+            link: None,
+            namespace: None,
+            install_modules: vec![],
+            expected_local_account_id: None,
+        }
+    }
+
+    /// Username for the sub-account
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Description for the sub-account
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// http(s) or ipfs link for the sub-account
+    pub fn link(mut self, link: impl Into<String>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Unique namespace for the sub-account
+    /// Setting this will claim the namespace for the sub-account on construction.
+    pub fn namespace(mut self, namespace: Namespace) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    /// Install an adapter on the sub-account.
+    pub fn install_adapter<M: InstallConfig<InitMsg = Empty>>(
+        mut self,
+    ) -> AbstractClientResult<Self> {
+        self.install_modules.push(M::install_config(&Empty {})?);
+        Ok(self)
+    }
+
+    /// Install an application on the sub-account.
+    pub fn install_app<M: InstallConfig>(
+        mut self,
+        configuration: &M::InitMsg,
+    ) -> AbstractClientResult<Self> {
+        self.install_modules.push(M::install_config(configuration)?);
+        Ok(self)
+    }
+
+    /// Install a standalone on the sub-account.
+    pub fn install_standalone<M: InstallConfig>(
+        mut self,
+        configuration: &M::InitMsg,
+    ) -> AbstractClientResult<Self> {
+        self.install_modules.push(M::install_config(configuration)?);
+        Ok(self)
+    }
+
+    /// Install a service on the sub-account.
+    pub fn install_service<M: InstallConfig>(
+        mut self,
+        configuration: &M::InitMsg,
+    ) -> AbstractClientResult<Self> {
+        self.install_modules.push(M::install_config(configuration)?);
+        Ok(self)
+    }
+
+    /// Install an application with dependencies on the sub-account.
+    pub fn install_app_with_dependencies<M: DependencyCreation + InstallConfig>(
+        mut self,
+        module_configuration: &M::InitMsg,
+        dependencies_config: M::DependenciesConfig,
+    ) -> AbstractClientResult<Self> {
+        let deps_install_config = M::dependency_install_configs(dependencies_config)?;
+        self.install_modules.extend(deps_install_config);
+        self.install_modules
+            .push(M::install_config(module_configuration)?);
+        Ok(self)
+    }
+
+    /// Assign expected local account_id on the host chain to the sub-account
+    pub fn expected_account_id(mut self, local_account_id: u32) -> Self {
+        self.expected_local_account_id = Some(local_account_id);
+        self
+    }
+
+    /// Builds the sub-account and waits for the IBC packets to be relayed.
+    pub fn build(self) -> AbstractClientResult<RemoteAccount<Chain, IBC>> {
+        let existing_sub_accounts = self.parent.sub_account_ids()?;
+
+        self.parent
+            .execute_on_account(vec![account::ExecuteMsg::CreateSubAccount {
+                name: Some(self.name),
+                description: self.description,
+                link: self.link,
+                namespace: self.namespace.as_ref().map(ToString::to_string),
+                install_modules: self.install_modules,
+                account_id: self.expected_local_account_id,
+            }])?;
+
+        let sub_account_id = match self.expected_local_account_id {
+            Some(id) => id,
+            None => self
+                .parent
+                .sub_account_ids()?
+                .into_iter()
+                .find(|id| !existing_sub_accounts.contains(id))
+                .ok_or_else(|| AbstractClientError::RemoteSubAccountNotCreated {
+                    parent: self.parent.id(),
+                })?,
+        };
+        Ok(self.parent.sub_account(AccountId::local(sub_account_id)))
+    }
+}
+
 /// Represents an existing remote Abstract account.
 ///
 /// Get this struct from [`Account::remote_account`](crate::Account::remote_account)
@@ -208,6 +345,8 @@ pub struct RemoteAccount<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> {
     remote_account_id: AccountId,
     host_chain: Chain,
     ibc_env: IBC,
+    /// Sub-accounts between the remote account and this account, empty if this is the remote account
+    sub_account_path: Vec<AccountId>,
 }
 
 impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC> {
@@ -222,6 +361,7 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
             remote_account_id,
             host_chain,
             ibc_env,
+            sub_account_path: vec![],
         }
     }
 
@@ -316,6 +456,40 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
         self.install_module_host_internal(modules)
     }
 
+    /// Install a standalone on account.
+    pub fn install_standalone<
+        M: RegisteredModule
+            + From<Contract<Chain>>
+            + ExecutableContract
+            + QueryableContract
+            + ContractInstance<Chain>
+            + InstallConfig,
+    >(
+        &self,
+        configuration: &M::InitMsg,
+    ) -> AbstractClientResult<RemoteApplication<Chain, IBC, M>> {
+        let modules = vec![M::install_config(configuration)?];
+
+        self.install_module_host_internal(modules)
+    }
+
+    /// Install a service on account.
+    pub fn install_service<
+        M: RegisteredModule
+            + From<Contract<Chain>>
+            + ExecutableContract
+            + QueryableContract
+            + ContractInstance<Chain>
+            + InstallConfig,
+    >(
+        &self,
+        configuration: &M::InitMsg,
+    ) -> AbstractClientResult<RemoteApplication<Chain, IBC, M>> {
+        let modules = vec![M::install_config(configuration)?];
+
+        self.install_module_host_internal(modules)
+    }
+
     /// Installs an App module and its dependencies with the provided dependencies config.
     pub fn install_app_with_dependencies<
         M: RegisteredModule
@@ -337,6 +511,37 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
         self.install_module_host_internal(install_configs)
     }
 
+    /// Installs a Standalone module and its dependencies with the provided dependencies config.
+    pub fn install_standalone_with_dependencies<
+        M: RegisteredModule
+            + From<Contract<Chain>>
+            + ExecutableContract
+            + QueryableContract
+            + ContractInstance<Chain>
+            + DependencyCreation
+            + InstallConfig,
+    >(
+        &self,
+        module_configuration: &M::InitMsg,
+        dependencies_config: M::DependenciesConfig,
+    ) -> AbstractClientResult<RemoteApplication<Chain, IBC, M>> {
+        let mut install_configs: Vec<ModuleInstallConfig> =
+            M::dependency_install_configs(dependencies_config)?;
+        install_configs.push(M::install_config(module_configuration)?);
+
+        self.install_module_host_internal(install_configs)
+    }
+
+    /// Uninstall a module from the account.
+    pub fn uninstall_module(
+        &self,
+        module_id: impl Into<String>,
+    ) -> AbstractClientResult<SuccessNestedPacketsFlow<Chain, Empty>> {
+        self.execute_on_account(vec![account::ExecuteMsg::UninstallModule {
+            module_id: module_id.into(),
+        }])
+    }
+
     /// Upgrades the account to the latest version
     ///
     /// Migrates account to its respective new versions.
@@ -352,15 +557,7 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
                     .map_err(Into::<CwOrchError>::into)?,
             ),
         )];
-        self.ibc_client_execute(
-            ibc_client::ExecuteMsg::RemoteAction {
-                host_chain: self.host_chain_id(),
-                action: ibc_host::HostAction::Dispatch {
-                    account_msgs: vec![account::ExecuteMsg::Upgrade { modules }],
-                },
-            },
-            vec![],
-        )
+        self.execute_on_account(vec![account::ExecuteMsg::Upgrade { modules }])
     }
 
     /// Returns owner of the account
@@ -381,6 +578,54 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
             .map_err(Into::into)
     }
 
+    /// Update the ownership of the account, see [`GovAction`] for the possible actions.
+    pub fn update_ownership(
+        &self,
+        action: GovAction,
+    ) -> AbstractClientResult<SuccessNestedPacketsFlow<Chain, Empty>> {
+        self.execute_on_account(vec![account::ExecuteMsg::UpdateOwnership(action)])
+    }
+
+    /// Propose to transfer the ownership of the account to `new_owner`.
+    /// Once accepted, the account can't be controlled from the origin chain anymore.
+    pub fn transfer_ownership(
+        &self,
+        new_owner: GovernanceDetails<String>,
+        expiry: Option<Expiration>,
+    ) -> AbstractClientResult<SuccessNestedPacketsFlow<Chain, Empty>> {
+        self.update_ownership(GovAction::TransferOwnership { new_owner, expiry })
+    }
+
+    /// Claim a namespace for the sub-account on the host chain, the claim is executed by its parent account.
+    /// Remote accounts are owned by the IBC host and can only claim a namespace on creation,
+    /// see [`RemoteAccountBuilder::namespace`].
+    pub fn claim_namespace(
+        &self,
+        namespace: impl Into<String>,
+    ) -> AbstractClientResult<SuccessNestedPacketsFlow<Chain, Empty>> {
+        let Some((_, parent_path)) = self.sub_account_path.split_last() else {
+            return Err(AbstractClientError::RemoteNamespaceClaim {
+                account_id: self.id(),
+            });
+        };
+        let registry = self.host_abstract()?.registry.address()?;
+        let claim_msg = wasm_execute(
+            registry,
+            &registry::ExecuteMsg::ClaimNamespace {
+                account_id: self.id(),
+                namespace: namespace.into(),
+            },
+            vec![],
+        )
+        .map_err(AbstractInterfaceError::from)?;
+        self.dispatch(
+            parent_path,
+            vec![account::ExecuteMsg::Execute {
+                msgs: vec![claim_msg.into()],
+            }],
+        )
+    }
+
     /// Executes a [`CosmosMsg`] on the account.
     pub fn execute(
         &self,
@@ -391,10 +636,32 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
     }
 
     /// Executes a list of [account::ExecuteMsg] on the account.
+    /// For sub-accounts the messages are forwarded by their parent accounts.
     pub fn execute_on_account(
         &self,
         account_msgs: Vec<account::ExecuteMsg>,
     ) -> AbstractClientResult<SuccessNestedPacketsFlow<Chain, Empty>> {
+        self.dispatch(&self.sub_account_path, account_msgs)
+    }
+
+    /// Executes the messages on the account at the end of `sub_account_path`, the remote account if empty.
+    fn dispatch(
+        &self,
+        sub_account_path: &[AccountId],
+        mut account_msgs: Vec<account::ExecuteMsg>,
+    ) -> AbstractClientResult<SuccessNestedPacketsFlow<Chain, Empty>> {
+        if !sub_account_path.is_empty() {
+            let host_abstract = self.host_abstract()?;
+            for sub_account_id in sub_account_path.iter().rev() {
+                let sub_account = host_abstract.registry.account(sub_account_id.clone())?;
+                let msgs = account_msgs
+                    .iter()
+                    .map(|msg| wasm_execute(sub_account.addr(), msg, vec![]).map(Into::into))
+                    .collect::<StdResult<Vec<CosmosMsg>>>()
+                    .map_err(AbstractInterfaceError::from)?;
+                account_msgs = vec![account::ExecuteMsg::Execute { msgs }];
+            }
+        }
         self.ibc_client_execute(
             ibc_client::ExecuteMsg::RemoteAction {
                 host_chain: self.host_chain_id(),
@@ -493,6 +760,56 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
         self.module_installed(IBC_CLIENT)
     }
 
+    /// Builder to create a sub-account of this account on the host chain
+    pub fn sub_account_builder(&self) -> RemoteSubAccountBuilder<Chain, IBC> {
+        RemoteSubAccountBuilder::new(self)
+    }
+
+    /// Get Sub Accounts of this account
+    pub fn sub_accounts(&self) -> AbstractClientResult<Vec<RemoteAccount<Chain, IBC>>> {
+        Ok(self
+            .sub_account_ids()?
+            .into_iter()
+            .map(|id| self.sub_account(AccountId::local(id)))
+            .collect())
+    }
+
+    fn sub_account_ids(&self) -> AbstractClientResult<Vec<u32>> {
+        let account = self.address()?;
+
+        let mut sub_accounts = vec![];
+        let mut start_after = None;
+        loop {
+            let res: SubAccountIdsResponse = self
+                .host_chain()
+                .query(
+                    &account::QueryMsg::SubAccountIds {
+                        start_after,
+                        limit: None,
+                    },
+                    &account,
+                )
+                .map_err(Into::into)?;
+            start_after = res.sub_accounts.last().cloned();
+
+            if res.sub_accounts.is_empty() {
+                break;
+            }
+            sub_accounts.extend(res.sub_accounts);
+        }
+        Ok(sub_accounts)
+    }
+
+    fn sub_account(&self, sub_account_id: AccountId) -> RemoteAccount<Chain, IBC> {
+        let mut sub_account_path = self.sub_account_path.clone();
+        sub_account_path.push(sub_account_id.clone());
+        RemoteAccount {
+            remote_account_id: sub_account_id,
+            sub_account_path,
+            ..self.clone()
+        }
+    }
+
     /// Retrieve installed application on account
     pub fn application<
         M: RegisteredModule
@@ -519,15 +836,7 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> RemoteAccount<Chain, IBC
         &self,
         modules: Vec<ModuleInstallConfig>,
     ) -> AbstractClientResult<RemoteApplication<Chain, IBC, M>> {
-        let _ = self.ibc_client_execute(
-            ibc_client::ExecuteMsg::RemoteAction {
-                host_chain: self.host_chain_id(),
-                action: ibc_host::HostAction::Dispatch {
-                    account_msgs: vec![account::ExecuteMsg::InstallModules { modules }],
-                },
-            },
-            vec![],
-        )?;
+        let _ = self.execute_on_account(vec![account::ExecuteMsg::InstallModules { modules }])?;
 
         let module = self.module()?;
         RemoteApplication::new(self.clone(), module)
@@ -5,7 +5,7 @@
 use std::fmt::Debug;
 
 use abstract_interface::{AbstractInterfaceError, RegisteredModule};
-use abstract_std::{account, adapter, ibc_client, ibc_host};
+use abstract_std::{account, adapter};
 use cosmwasm_std::to_json_binary;
 use cw_orch::{contract::Contract, prelude::*};
 use cw_orch_interchain::{core::SuccessNestedPacketsFlow, prelude::*};
@@ -112,13 +112,7 @@ impl<Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>, M: ContractInstance<Chai
             })
         }
 
-        let _ = self.remote_account.ibc_client_execute(
-            ibc_client::ExecuteMsg::RemoteAction {
-                host_chain: self.remote_account.host_chain_id(),
-                action: ibc_host::HostAction::Dispatch { account_msgs },
-            },
-            vec![],
-        )?;
+        let _ = self.remote_account.execute_on_account(account_msgs)?;
         Ok(())
     }
 }
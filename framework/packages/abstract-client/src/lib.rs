@@ -3,10 +3,13 @@
 #![cfg_attr(all(coverage_nightly, test), feature(coverage_attribute))]
 pub(crate) mod account;
 mod application;
+pub mod batch;
 pub mod builder;
 mod client;
 mod error;
+pub mod events;
 pub(crate) mod infrastructure;
+pub mod manifest;
 #[cfg(feature = "test-utils")]
 mod mut_client;
 mod publisher;
@@ -22,6 +25,7 @@ pub use abstract_std::objects::{gov_type::GovernanceDetails, namespace::Namespac
 pub use abstract_interface::ClientResolve;
 pub use account::{Account, AccountBuilder};
 pub use application::Application;
+pub use batch::{AccountBatch, BatchSimulation, BatchTxHandler};
 pub use builder::AbstractClientBuilder;
 pub use client::AbstractClient;
 pub use error::AbstractClientError;
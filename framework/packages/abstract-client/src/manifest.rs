@@ -0,0 +1,436 @@
+//! # Account manifests
+//!
+//! An [`AccountManifest`] declares the desired state of an Account tree: its sub-accounts, installed modules,
+//! whitelisted addresses and ownership. [`AbstractClient::plan_manifest`] lists the actions needed to reach
+//! that state and [`AbstractClient::apply_manifest`] executes them. Applying a manifest twice is a no-op.
+//!
+//! Manifests implement [`Deserialize`], so they can be read from JSON with [`AccountManifest::from_json`]
+//! or from any other serde format like YAML.
+//!
+//! ```
+//! # use abstract_client::{AbstractClient, AbstractClientError};
+//! # use cw_orch::prelude::*;
+//! # let chain = MockBech32::new("mock");
+//! # let client = AbstractClient::builder(chain.clone()).build()?;
+//! use abstract_client::manifest::AccountManifest;
+//!
+//! let manifest = AccountManifest::from_json(
+//!     r#"{
+//!         "namespace": "treasury",
+//!         "name": "Treasury",
+//!         "sub_accounts": [{ "name": "Payroll" }]
+//!     }"#,
+//! )?;
+//!
+//! let treasury = client.apply_manifest(&manifest)?;
+//! assert_eq!(treasury.sub_accounts()?.len(), 1);
+//! // Everything is in place
+//! assert!(client.plan_manifest(&manifest)?.is_empty());
+//! # Ok::<(), AbstractClientError>(())
+//! ```
+use abstract_interface::{AccountQueryFns, MFactoryQueryFns};
+use abstract_std::{
+    account::{self, InternalConfigAction, ModuleInstallConfig},
+    objects::{
+        gov_type::GovernanceDetails,
+        module::{ModuleInfo, ModuleVersion},
+        namespace::Namespace,
+        ownership::GovAction,
+    },
+};
+use cosmwasm_std::{to_json_binary, Addr, Binary};
+use cw_orch::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{client::AbstractClientResult, AbstractClient, AbstractClientError, Account};
+
+/// Desired state of an Account and its sub-accounts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccountManifest {
+    /// Namespace claimed by the account. Identifies the account and is required for top-level accounts.
+    pub namespace: Option<String>,
+    /// Name of the account, identifies sub-accounts without a namespace.
+    pub name: Option<String>,
+    /// Description of the account
+    pub description: Option<String>,
+    /// Link of the account
+    pub link: Option<String>,
+    /// Owner of the account, defaults to the sender for top-level accounts and to the parent for sub-accounts.
+    pub ownership: Option<GovernanceDetails<String>>,
+    /// Modules to install, dependencies must be listed before their dependents.
+    #[serde(default)]
+    pub modules: Vec<ModuleManifest>,
+    /// Addresses to whitelist on top of the installed modules.
+    /// Other whitelisted addresses are removed from the whitelist.
+    #[serde(default)]
+    pub whitelist: Vec<String>,
+    /// Sub-accounts owned by this account
+    #[serde(default)]
+    pub sub_accounts: Vec<AccountManifest>,
+}
+
+/// Module installed on an Account of the manifest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModuleManifest {
+    /// Id of the module
+    pub id: String,
+    /// Version of the module, defaults to the latest version.
+    /// An installed module with another version is upgraded.
+    pub version: Option<String>,
+    /// Instantiate message of the module
+    pub init_msg: Option<Value>,
+    /// Migrate message used when the module is upgraded
+    pub migrate_msg: Option<Value>,
+}
+
+/// Action needed to bring the chain state in line with a manifest.
+/// Accounts are referred to by their path of namespaces or names, i.e. `treasury/payroll`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManifestAction {
+    /// Create the account with its modules
+    CreateAccount {
+        /// Path of the account
+        path: String,
+        /// Modules installed on creation
+        modules: Vec<String>,
+    },
+    /// Install missing modules
+    InstallModules {
+        /// Path of the account
+        path: String,
+        /// Modules to install
+        modules: Vec<String>,
+    },
+    /// Upgrade a module to the version of the manifest
+    UpgradeModule {
+        /// Path of the account
+        path: String,
+        /// Module to upgrade
+        module: String,
+        /// Version to upgrade to
+        version: String,
+    },
+    /// Whitelist addresses
+    Whitelist {
+        /// Path of the account
+        path: String,
+        /// Addresses to whitelist
+        addresses: Vec<String>,
+    },
+    /// Remove addresses that are neither installed modules nor listed in the manifest from the whitelist
+    RemoveFromWhitelist {
+        /// Path of the account
+        path: String,
+        /// Addresses to remove from the whitelist
+        addresses: Vec<String>,
+    },
+    /// Propose a new owner, who has to accept the ownership
+    TransferOwnership {
+        /// Path of the account
+        path: String,
+        /// Proposed owner
+        new_owner: GovernanceDetails<String>,
+    },
+}
+
+impl AccountManifest {
+    /// Parse a JSON manifest
+    pub fn from_json(manifest: &str) -> AbstractClientResult<Self> {
+        serde_json::from_str(manifest).map_err(Into::into)
+    }
+
+    fn path(&self, parent_path: Option<&str>) -> AbstractClientResult<String> {
+        let label = match (parent_path, &self.namespace, &self.name) {
+            (_, Some(namespace), _) => namespace,
+            (Some(_), None, Some(name)) => name,
+            (None, None, _) => return Err(AbstractClientError::ManifestWithoutNamespace {}),
+            (Some(path), None, None) => {
+                return Err(AbstractClientError::ManifestUnidentifiedSubAccount {
+                    parent: path.to_owned(),
+                })
+            }
+        };
+        Ok(match parent_path {
+            Some(path) => format!("{path}/{label}"),
+            None => label.to_owned(),
+        })
+    }
+
+    fn module_ids(&self) -> Vec<String> {
+        self.modules
+            .iter()
+            .map(|module| module.id.clone())
+            .collect()
+    }
+
+    fn install_configs(&self) -> AbstractClientResult<Vec<ModuleInstallConfig>> {
+        self.modules
+            .iter()
+            .map(ModuleManifest::install_config)
+            .collect()
+    }
+}
+
+impl ModuleManifest {
+    fn install_config(&self) -> AbstractClientResult<ModuleInstallConfig> {
+        let version = match &self.version {
+            Some(version) => ModuleVersion::Version(version.clone()),
+            None => ModuleVersion::Latest,
+        };
+        Ok(ModuleInstallConfig::new(
+            ModuleInfo::from_id(&self.id, version)?,
+            to_binary(&self.init_msg)?,
+        ))
+    }
+}
+
+fn to_binary(msg: &Option<Value>) -> AbstractClientResult<Option<Binary>> {
+    msg.as_ref()
+        .map(|msg| to_json_binary(msg).map_err(|e| CwOrchError::from(e).into()))
+        .transpose()
+}
+
+impl<Chain: CwEnv> AbstractClient<Chain> {
+    /// Actions needed to bring the chain state in line with the manifest, nothing is executed.
+    pub fn plan_manifest(
+        &self,
+        manifest: &AccountManifest,
+    ) -> AbstractClientResult<Vec<ManifestAction>> {
+        let mut actions = vec![];
+        self.reconcile(manifest, None, None, false, &mut actions)?;
+        Ok(actions)
+    }
+
+    /// Create or update the account tree of the manifest and return the top-level account.
+    /// Only the differences with the chain state are executed.
+    pub fn apply_manifest(
+        &self,
+        manifest: &AccountManifest,
+    ) -> AbstractClientResult<Account<Chain>> {
+        let mut actions = vec![];
+        let account = self.reconcile(manifest, None, None, true, &mut actions)?;
+        Ok(account.expect("applied manifest creates the account"))
+    }
+
+    /// Reconciles the account and its sub-accounts with the manifest.
+    /// Returns `None` if the account doesn't exist and `apply` is false.
+    fn reconcile(
+        &self,
+        manifest: &AccountManifest,
+        parent: Option<&Account<Chain>>,
+        parent_path: Option<&str>,
+        apply: bool,
+        actions: &mut Vec<ManifestAction>,
+    ) -> AbstractClientResult<Option<Account<Chain>>> {
+        let path = manifest.path(parent_path)?;
+
+        let existing = match parent_path {
+            // Parent doesn't exist yet, so neither does its sub-account
+            Some(_) if parent.is_none() => None,
+            _ => self.find_manifest_account(manifest, parent)?,
+        };
+        let account = match existing {
+            Some(account) => Some(account),
+            None => {
+                actions.push(ManifestAction::CreateAccount {
+                    path: path.clone(),
+                    modules: manifest.module_ids(),
+                });
+                if apply {
+                    Some(self.build_manifest_account(manifest, parent)?)
+                } else {
+                    None
+                }
+            }
+        };
+
+        match &account {
+            Some(account) => self.reconcile_account(account, manifest, &path, apply, actions)?,
+            // Modules and ownership are set on creation
+            None if !manifest.whitelist.is_empty() => actions.push(ManifestAction::Whitelist {
+                path: path.clone(),
+                addresses: manifest.whitelist.clone(),
+            }),
+            None => (),
+        }
+
+        for sub_account in &manifest.sub_accounts {
+            self.reconcile(sub_account, account.as_ref(), Some(&path), apply, actions)?;
+        }
+        Ok(account)
+    }
+
+    fn find_manifest_account(
+        &self,
+        manifest: &AccountManifest,
+        parent: Option<&Account<Chain>>,
+    ) -> AbstractClientResult<Option<Account<Chain>>> {
+        if let Some(namespace) = &manifest.namespace {
+            return Account::maybe_from_namespace(&self.abstr, Namespace::new(namespace)?);
+        }
+        let (Some(parent), Some(name)) = (parent, &manifest.name) else {
+            return Ok(None);
+        };
+        for sub_account in parent.sub_accounts()? {
+            if sub_account.info()?.name.as_ref() == Some(name) {
+                return Ok(Some(sub_account));
+            }
+        }
+        Ok(None)
+    }
+
+    fn build_manifest_account(
+        &self,
+        manifest: &AccountManifest,
+        parent: Option<&Account<Chain>>,
+    ) -> AbstractClientResult<Account<Chain>> {
+        let mut builder = self.account_builder();
+        if let Some(namespace) = &manifest.namespace {
+            builder.namespace(Namespace::new(namespace)?);
+        }
+        if let Some(name) = &manifest.name {
+            builder.name(name);
+        }
+        if let Some(description) = &manifest.description {
+            builder.description(description);
+        }
+        if let Some(link) = &manifest.link {
+            builder.link(link);
+        }
+        if let Some(parent) = parent {
+            builder.sub_account(parent);
+        }
+        if let Some(ownership) = &manifest.ownership {
+            builder.ownership(ownership.clone());
+        }
+        builder
+            .with_modules(manifest.install_configs()?)
+            .auto_fund()
+            .build()
+    }
+
+    fn reconcile_account(
+        &self,
+        account: &Account<Chain>,
+        manifest: &AccountManifest,
+        path: &str,
+        apply: bool,
+        actions: &mut Vec<ManifestAction>,
+    ) -> AbstractClientResult<()> {
+        // Modules
+        let installed = account.module_infos()?.module_infos;
+        let mut to_install = vec![];
+        for module in &manifest.modules {
+            let Some(installed) = installed.iter().find(|info| info.id == module.id) else {
+                to_install.push(module.install_config()?);
+                continue;
+            };
+            let Some(version) = &module.version else {
+                continue;
+            };
+            if &installed.version.version != version {
+                actions.push(ManifestAction::UpgradeModule {
+                    path: path.to_owned(),
+                    module: module.id.clone(),
+                    version: version.clone(),
+                });
+                if apply {
+                    let module_info =
+                        ModuleInfo::from_id(&module.id, ModuleVersion::Version(version.clone()))?;
+                    account
+                        .abstr_account
+                        .upgrade(vec![(module_info, to_binary(&module.migrate_msg)?)])?;
+                }
+            }
+        }
+        if !to_install.is_empty() {
+            actions.push(ManifestAction::InstallModules {
+                path: path.to_owned(),
+                modules: to_install.iter().map(|m| m.module.id()).collect(),
+            });
+            if apply {
+                let funds = self
+                    .abstr
+                    .module_factory
+                    .simulate_install_modules(
+                        to_install.iter().map(|m| m.module.clone()).collect(),
+                    )?
+                    .total_required_funds;
+                account.abstr_account.install_modules(to_install, &funds)?;
+            }
+        }
+
+        // Whitelist
+        let whitelisted = account.abstr_account.config()?.whitelisted_addresses;
+        let to_add: Vec<String> = manifest
+            .whitelist
+            .iter()
+            .filter(|address| {
+                !whitelisted
+                    .iter()
+                    .any(|addr| addr.as_str() == address.as_str())
+            })
+            .cloned()
+            .collect();
+        // Modules are whitelisted on install, so they are kept
+        let module_addrs: Vec<Addr> = account
+            .module_infos()?
+            .module_infos
+            .into_iter()
+            .map(|info| info.address)
+            .collect();
+        let to_remove: Vec<String> = whitelisted
+            .into_iter()
+            .filter(|addr| {
+                !module_addrs.contains(addr) && !manifest.whitelist.contains(&addr.to_string())
+            })
+            .map(String::from)
+            .collect();
+        if !to_add.is_empty() {
+            actions.push(ManifestAction::Whitelist {
+                path: path.to_owned(),
+                addresses: to_add.clone(),
+            });
+        }
+        if !to_remove.is_empty() {
+            actions.push(ManifestAction::RemoveFromWhitelist {
+                path: path.to_owned(),
+                addresses: to_remove.clone(),
+            });
+        }
+        if apply && !(to_add.is_empty() && to_remove.is_empty()) {
+            account.configure(
+                &account::ExecuteMsg::UpdateInternalConfig(InternalConfigAction::UpdateWhitelist {
+                    to_add,
+                    to_remove,
+                }),
+                &[],
+            )?;
+        }
+
+        // Ownership
+        if let Some(new_owner) = &manifest.ownership {
+            let ownership = account.ownership()?;
+            if &ownership.owner != new_owner && ownership.pending_owner.as_ref() != Some(new_owner)
+            {
+                actions.push(ManifestAction::TransferOwnership {
+                    path: path.to_owned(),
+                    new_owner: new_owner.clone(),
+                });
+                if apply {
+                    account.configure(
+                        &account::ExecuteMsg::UpdateOwnership(GovAction::TransferOwnership {
+                            new_owner: new_owner.clone(),
+                            expiry: None,
+                        }),
+                        &[],
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
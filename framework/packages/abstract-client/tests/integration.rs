@@ -14,6 +14,8 @@ use abstract_app::{
 };
 use abstract_client::{
     builder::cw20_builder::{self, ExecuteMsgInterfaceFns, QueryMsgInterfaceFns},
+    events::{AbstractEvent, AbstractEvents},
+    manifest::{AccountManifest, ManifestAction},
     AbstractClient, AbstractClientError, Account, AccountSource, Application, Environment,
     Publisher,
 };
@@ -1605,3 +1607,215 @@ fn account_fetcher_shouldnt_install_module_on_existing_account() -> anyhow::Resu
     assert!(!account.module_installed(TEST_MODULE_ID)?);
     Ok(())
 }
+
+#[test]
+fn can_batch_account_operations() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let publisher: Publisher<MockBech32> = client
+        .account_builder()
+        .namespace(Namespace::new(TEST_NAMESPACE)?)
+        .build()?
+        .publisher()?;
+    publisher.publish_app::<MockAppI<MockBech32>>()?;
+
+    let account = client.account_builder().build()?;
+    account.set_balance(&coins(100, TTOKEN))?;
+    let receiver = chain.addr_make("receiver");
+
+    let mut batch = account.batch();
+    batch
+        .install_app::<MockAppI<MockBech32>>(&MockInitMsg {}, &[])?
+        .execute(
+            vec![BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: coins(100, TTOKEN),
+            }],
+            &[],
+        )?;
+    assert_eq!(batch.msgs().len(), 2);
+
+    // Simulation doesn't change the state
+    let simulation = batch.simulate()?;
+    assert!(simulation.is_ok());
+    assert!(!simulation.events.is_empty());
+    assert!(!account.module_installed(MockAppI::<MockBech32>::module_id())?);
+    assert_eq!(chain.query_balance(&receiver, TTOKEN)?, Uint128::zero());
+
+    batch.commit()?;
+    assert!(account.module_installed(MockAppI::<MockBech32>::module_id())?);
+    assert_eq!(chain.query_balance(&receiver, TTOKEN)?, Uint128::new(100));
+
+    // A failing operation reverts the whole batch
+    let mut batch = account.batch();
+    batch
+        .configure(
+            &abstract_std::account::ExecuteMsg::UpdateInfo {
+                name: Some(String::from("Batched")),
+                description: None,
+                link: None,
+            },
+            &[],
+        )?
+        .execute(
+            vec![BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: coins(1, TTOKEN),
+            }],
+            &[],
+        )?;
+
+    let simulation = batch.simulate()?;
+    assert!(simulation.error.is_some());
+    assert!(batch.commit().is_err());
+    assert_eq!(
+        account.info()?.name,
+        Some(String::from("Default Abstract Account"))
+    );
+    Ok(())
+}
+
+#[test]
+fn can_apply_account_manifest() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let publisher: Publisher<MockBech32> = client
+        .account_builder()
+        .namespace(Namespace::new(TEST_NAMESPACE)?)
+        .build()?
+        .publisher()?;
+    publisher.publish_app::<MockAppI<MockBech32>>()?;
+
+    let app_id = MockAppI::<MockBech32>::module_id();
+    let bot = chain.addr_make("bot");
+    let manifest = AccountManifest::from_json(&format!(
+        r#"{{
+            "namespace": "treasury",
+            "name": "Treasury",
+            "whitelist": ["{bot}"],
+            "sub_accounts": [{{
+                "name": "Payroll",
+                "modules": [{{ "id": "{app_id}", "init_msg": {{}} }}]
+            }}]
+        }}"#
+    ))?;
+
+    let plan = client.plan_manifest(&manifest)?;
+    assert_eq!(
+        plan,
+        vec![
+            ManifestAction::CreateAccount {
+                path: "treasury".to_owned(),
+                modules: vec![],
+            },
+            ManifestAction::Whitelist {
+                path: "treasury".to_owned(),
+                addresses: vec![bot.to_string()],
+            },
+            ManifestAction::CreateAccount {
+                path: "treasury/Payroll".to_owned(),
+                modules: vec![app_id.to_owned()],
+            },
+        ]
+    );
+
+    let treasury = client.apply_manifest(&manifest)?;
+    assert_eq!(treasury.info()?.name, Some(String::from("Treasury")));
+    let sub_accounts = treasury.sub_accounts()?;
+    assert_eq!(sub_accounts.len(), 1);
+    assert!(sub_accounts[0].module_installed(app_id)?);
+
+    // Applying the manifest again is a no-op
+    assert!(client.plan_manifest(&manifest)?.is_empty());
+    client.apply_manifest(&manifest)?;
+    assert_eq!(treasury.sub_accounts()?.len(), 1);
+
+    // Only the difference with the chain state is planned
+    let mut manifest = manifest;
+    manifest.sub_accounts[0].name = Some(String::from("Grants"));
+    assert_eq!(
+        client.plan_manifest(&manifest)?,
+        vec![ManifestAction::CreateAccount {
+            path: "treasury/Grants".to_owned(),
+            modules: vec![app_id.to_owned()],
+        }]
+    );
+
+    // Addresses dropped from the manifest are removed from the whitelist, installed modules are kept
+    manifest.sub_accounts[0].name = Some(String::from("Payroll"));
+    manifest.whitelist = vec![];
+    assert_eq!(
+        client.plan_manifest(&manifest)?,
+        vec![ManifestAction::RemoveFromWhitelist {
+            path: "treasury".to_owned(),
+            addresses: vec![bot.to_string()],
+        }]
+    );
+    client.apply_manifest(&manifest)?;
+    let treasury_account: &abstract_interface::AccountI<MockBech32> = treasury.as_ref();
+    let config = abstract_interface::AccountQueryFns::config(treasury_account)?;
+    assert!(config.whitelisted_addresses.is_empty());
+    assert!(client.plan_manifest(&manifest)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn can_decode_abstract_events() -> anyhow::Result<()> {
+    let chain = MockBech32::new("mock");
+    let client = AbstractClient::builder(chain.clone()).build()?;
+
+    let publisher: Publisher<MockBech32> = client
+        .account_builder()
+        .namespace(Namespace::new(TEST_NAMESPACE)?)
+        .build()?
+        .publisher()?;
+    publisher.publish_app::<MockAppI<MockBech32>>()?;
+
+    let account = client.account_builder().build()?;
+    let mut batch = account.batch();
+    batch
+        .install_app::<MockAppI<MockBech32>>(&MockInitMsg {}, &[])?
+        .configure(
+            &abstract_std::account::ExecuteMsg::CreateSubAccount {
+                name: None,
+                description: None,
+                link: None,
+                namespace: None,
+                install_modules: vec![],
+                account_id: None,
+            },
+            &[],
+        )?;
+    let events = batch.commit()?.abstract_events()?;
+
+    let Some(AbstractEvent::ModulesInstalled {
+        account: installed_on,
+        modules,
+    }) = events
+        .iter()
+        .find(|event| matches!(event, AbstractEvent::ModulesInstalled { .. }))
+    else {
+        panic!("modules installed event not found in {events:?}");
+    };
+    assert_eq!(installed_on, &account.address()?);
+    assert_eq!(
+        modules.iter().map(ModuleInfo::id).collect::<Vec<_>>(),
+        vec![MockAppI::<MockBech32>::module_id().to_owned()]
+    );
+
+    let sub_accounts = account.sub_accounts()?;
+    assert_eq!(sub_accounts.len(), 1);
+    let sub_account_id = sub_accounts[0].id()?;
+    assert!(events.contains(&AbstractEvent::SubAccountCreated {
+        parent: account.address()?,
+        sub_account_id: sub_account_id.clone(),
+    }));
+    assert!(events.contains(&AbstractEvent::AccountCreated {
+        account_id: sub_account_id,
+        account: sub_accounts[0].address()?,
+        namespace: None,
+    }));
+    Ok(())
+}
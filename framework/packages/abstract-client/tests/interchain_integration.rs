@@ -1,7 +1,10 @@
 #![cfg(feature = "interchain")]
 use abstract_client::AbstractClient;
+use abstract_client::AbstractClientError;
 use abstract_client::GovernanceDetails;
+use abstract_client::Namespace;
 use abstract_interface::IbcClient;
+use abstract_std::IBC_CLIENT;
 use cw_orch::mock::MockBase;
 use cw_orch_interchain::prelude::*;
 
@@ -38,3 +41,65 @@ fn create_remote_account() -> anyhow::Result<()> {
     assert_eq!(governance_type, "abstract-ibc");
     Ok(())
 }
+
+#[test]
+fn remote_account_operations() -> anyhow::Result<()> {
+    let mock_interchain =
+        MockBech32InterchainEnv::new(vec![("juno-1", "juno"), ("osmo-1", "osmo")]);
+
+    let mock_juno = mock_interchain.get_chain("juno-1")?;
+    let mock_osmo = mock_interchain.get_chain("osmo-1")?;
+
+    let juno_abstr = AbstractClient::builder(mock_juno.clone()).build()?;
+    let osmo_abstr = AbstractClient::builder(mock_osmo.clone()).build()?;
+
+    juno_abstr.connect_to(&osmo_abstr, &mock_interchain)?;
+
+    let juno_account = juno_abstr
+        .account_builder()
+        .install_adapter::<IbcClient<MockBase>>()
+        .build()?;
+    let remote_account = juno_account
+        .remote_account_builder(mock_interchain.clone(), &osmo_abstr)
+        .namespace(Namespace::new("remote")?)
+        .build()?;
+
+    // Remote account is owned by the IBC host, namespace can only be claimed on creation
+    let namespace_account = osmo_abstr.account_from(Namespace::new("remote")?)?;
+    assert_eq!(namespace_account.id()?, remote_account.id());
+    assert!(matches!(
+        remote_account.claim_namespace("remote-other"),
+        Err(AbstractClientError::RemoteNamespaceClaim { .. })
+    ));
+
+    // Sub-account is created on the host chain and controlled through its parent
+    let sub_account = remote_account
+        .sub_account_builder()
+        .name("remote-sub")
+        .build()?;
+    let sub_accounts = remote_account.sub_accounts()?;
+    assert_eq!(sub_accounts.len(), 1);
+    assert_eq!(sub_accounts[0].id(), sub_account.id());
+    assert_eq!(sub_account.info()?.name, Some("remote-sub".to_owned()));
+
+    // Namespace of the sub-account is claimed by its parent, the remote account
+    sub_account.claim_namespace("remote-sub")?;
+    let namespace_account = osmo_abstr.account_from(Namespace::new("remote-sub")?)?;
+    assert_eq!(namespace_account.id()?, sub_account.id());
+
+    let new_owner = mock_osmo.addr_make("new_owner").to_string();
+    sub_account.transfer_ownership(
+        GovernanceDetails::Monarchy {
+            monarch: new_owner.clone(),
+        },
+        None,
+    )?;
+    assert_eq!(
+        sub_account.ownership()?.pending_owner,
+        Some(GovernanceDetails::Monarchy { monarch: new_owner })
+    );
+
+    remote_account.uninstall_module(IBC_CLIENT)?;
+    assert!(!remote_account.module_installed(IBC_CLIENT)?);
+    Ok(())
+}
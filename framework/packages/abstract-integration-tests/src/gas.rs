@@ -0,0 +1,63 @@
+//! # Gas benchmark scenarios
+//!
+//! Fixed set of Account scenarios that are recorded in a [`GasBenchmark`].
+//! Keep the scenarios stable, changing them invalidates the reports of previous commits.
+
+use abstract_account::contract::CONTRACT_VERSION;
+use abstract_adapter::mock::MockExecMsg as AdapterExecMsg;
+use abstract_app::mock::{self, MockExecMsg as AppExecMsg, MockInitMsg};
+use abstract_interface::*;
+use abstract_std::adapter;
+use abstract_testing::prelude::*;
+use cw_orch::prelude::*;
+
+use crate::{account::mock_app::*, create_default_account, init_mock_adapter};
+
+/// Name of the Account benchmark suite
+pub const ACCOUNT_SUITE: &str = "account";
+
+pub const INSTALL_APP: &str = "install_app";
+pub const INSTALL_ADAPTER: &str = "install_adapter";
+pub const EXECUTE_ON_APP: &str = "execute_on_module";
+pub const EXECUTE_ON_ADAPTER: &str = "adapter_execute";
+pub const UNINSTALL_MODULE: &str = "uninstall_module";
+
+/// Record the cost of the Account scenarios.
+/// Expects Abstract to be deployed on `chain`.
+pub fn account_benchmark<T: GasMeter>(chain: T) -> anyhow::Result<GasBenchmark> {
+    let deployment = Abstract::load_from(chain.clone())?;
+    let account = create_default_account(&chain.sender_addr(), &deployment)?;
+    // Claims the "tester" namespace for the account
+    init_mock_adapter(chain.clone(), &deployment, None, account.id()?)?;
+    let app = MockApp::new_test(chain.clone());
+    MockApp::deploy(&app, APP_VERSION.parse().unwrap(), DeployStrategy::Try)?;
+
+    let mut benchmark = GasBenchmark::new(ACCOUNT_SUITE, CONTRACT_VERSION);
+    benchmark.record(&chain, INSTALL_APP, || {
+        account.install_module(APP_ID, Some(&MockInitMsg {}), &[])
+    })?;
+    benchmark.record(&chain, INSTALL_ADAPTER, || {
+        account.install_module::<Empty>(TEST_MODULE_ID, None, &[])
+    })?;
+    benchmark.record(&chain, EXECUTE_ON_APP, || {
+        account.execute_on_module(
+            APP_ID,
+            mock::ExecuteMsg::Module(AppExecMsg::DoSomething {}),
+            vec![],
+        )
+    })?;
+    benchmark.record(&chain, EXECUTE_ON_ADAPTER, || {
+        account.execute_on_module(
+            TEST_MODULE_ID,
+            adapter::ExecuteMsg::<AdapterExecMsg>::from(AdapterExecMsg {}),
+            vec![],
+        )
+    })?;
+    benchmark.record(&chain, UNINSTALL_MODULE, || {
+        account
+            .uninstall_module(APP_ID.to_owned())
+            .map_err(Into::into)
+    })?;
+
+    Ok(benchmark)
+}
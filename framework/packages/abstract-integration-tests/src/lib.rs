@@ -4,6 +4,7 @@
 
 pub mod account;
 pub mod create;
+pub mod gas;
 pub mod mock_modules;
 
 use abstract_adapter::mock::{interface::MockAdapterI, MockInitMsg};
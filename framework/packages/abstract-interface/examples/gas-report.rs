@@ -0,0 +1,64 @@
+//! Records the gas used by Account transactions on a local node, executed by the chain's wasm VM.
+//! Set `GAS_REPORT_DIR` to write the report, see `just gas-report-daemon`.
+use abstract_interface::{Abstract, AccountExecFns, AccountI, GasBenchmark};
+use abstract_std::objects::gov_type::GovernanceDetails;
+use cw_orch::{daemon::networks::LOCAL_JUNO, prelude::*};
+
+pub const ABSTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+fn gas_report() -> cw_orch::anyhow::Result<()> {
+    let chain = DaemonBuilder::new(LOCAL_JUNO).build()?;
+
+    // Addresses are predictable, so the deployment can only be instantiated once
+    let deployment = match Abstract::load_from(chain.clone()) {
+        Ok(deployed) => deployed,
+        Err(_) => Abstract::deploy_on(chain.clone(), ())?,
+    };
+    let account = AccountI::create_default_account(
+        &deployment,
+        GovernanceDetails::Monarchy {
+            monarch: chain.sender_addr().to_string(),
+        },
+    )?;
+
+    let mut benchmark = GasBenchmark::new("account-daemon", ABSTRACT_VERSION);
+    benchmark.record(&chain, "create_sub_account", || {
+        account
+            .create_sub_account(
+                vec![],
+                None,
+                None,
+                None,
+                Some("sub-account".to_owned()),
+                None,
+                &[],
+            )
+            .map_err(Into::into)
+    })?;
+    benchmark.record(&chain, "update_info", || {
+        account
+            .update_info(None, None, Some("gas report".to_owned()))
+            .map_err(Into::into)
+    })?;
+    // Suspended accounts can't execute, keep it last
+    benchmark.record(&chain, "suspend_account", || {
+        account.update_status(Some(true)).map_err(Into::into)
+    })?;
+
+    match std::env::var("GAS_REPORT_DIR") {
+        Ok(dir) => {
+            benchmark.write(dir)?;
+        }
+        Err(_) => println!("{}", benchmark.report()),
+    }
+    Ok(())
+}
+
+fn main() {
+    dotenv().ok();
+    env_logger::init();
+
+    use dotenv::dotenv;
+
+    gas_report().unwrap();
+}
@@ -642,6 +642,7 @@ impl<Chain: CwEnv> AccountI<Chain> {
                     name: Some(module.name.clone()),
                     version: None,
                     status: Some(ModuleStatus::Registered),
+                    attested_by: None,
                 }),
                 None,
                 Some(module.clone()),
@@ -732,6 +733,18 @@ impl<Chain: CwEnv> AccountI<Chain> {
         self.update_internal_config(InternalConfigAction::UpdateWhitelist { to_add, to_remove })?;
         Ok(())
     }
+
+    pub fn update_trusted_auditors(
+        &self,
+        to_add: Vec<AccountId>,
+        to_remove: Vec<AccountId>,
+    ) -> Result<(), AbstractInterfaceError> {
+        self.update_internal_config(InternalConfigAction::UpdateTrustedAuditors {
+            to_add,
+            to_remove,
+        })?;
+        Ok(())
+    }
 }
 
 impl<Chain: CwEnv> Uploadable for AccountI<Chain> {
@@ -29,6 +29,32 @@ pub enum AbstractInterfaceError {
 
     #[error("No matching module deployed {0:?}")]
     NoMatchingModule(StaticDependency),
+
+    #[error("Invalid ANS snapshot: {0}")]
+    InvalidSnapshot(String),
+
+    #[error("Failed to load release {release}: {reason}")]
+    ReleaseSchema { release: String, reason: String },
+
+    #[error("Upgrade of {module} from {previous_version} to {version} is unsafe, publish with `UpgradeCheck::allow_unsafe` to override:\n{report}")]
+    UnsafeUpgrade {
+        module: String,
+        previous_version: String,
+        version: String,
+        report: crate::UpgradeReport,
+    },
+
+    #[cfg(feature = "interchain")]
+    #[error("Abstract on {0} is not connected to {1}")]
+    NotConnected(String, String),
+
+    #[cfg(feature = "interchain")]
+    #[error("No ICS20 channel was opened on {0}")]
+    ChannelNotOpened(String),
+
+    #[cfg(feature = "interchain")]
+    #[error("Expected the packets to {0}, but they were relayed successfully")]
+    FaultNotInjected(String),
 }
 
 impl AbstractInterfaceError {
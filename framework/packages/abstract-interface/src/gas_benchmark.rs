@@ -0,0 +1,251 @@
+//! Gas benchmarks of Abstract transactions.
+//!
+//! [`GasBenchmark`] records the cost of a fixed set of scenarios and writes it to a markdown report.
+//! Reports are deterministic, so the report of two commits can be compared with `git diff`.
+//!
+//! cw-multi-test doesn't meter gas, the mock environments record the amount of contract calls and storage writes instead.
+//! Environments that meter gas, like [`Daemon`](cw_orch::daemon::Daemon), record the gas used by the transaction.
+//! The `gas-report` example records it on a local node, see `just gas-report-daemon`.
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use cosmwasm_std::{Api, Order};
+use cw_orch::{environment::StateInterface, mock::MockBase, prelude::*};
+
+use crate::AbstractInterfaceError;
+
+/// Events emitted once per contract entry point call.
+const CONTRACT_CALL_EVENTS: [&str; 5] = ["execute", "instantiate", "migrate", "reply", "sudo"];
+
+/// Cost of a transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxCost {
+    /// Gas used, `None` for environments without gas metering
+    pub gas_used: Option<u64>,
+    /// Amount of contract entry points that were called
+    pub contract_calls: u64,
+    /// Amount of storage entries that were written or removed, `None` if the storage isn't accessible
+    pub storage_writes: Option<u64>,
+    /// Size of the written keys and values
+    pub storage_bytes_written: Option<u64>,
+}
+
+/// Environment that can measure the cost of its transactions.
+pub trait GasMeter: CwEnv {
+    /// Run `tx` and measure the cost of the transaction it returns.
+    fn measure(
+        &self,
+        tx: impl FnOnce() -> Result<Self::Response, AbstractInterfaceError>,
+    ) -> Result<TxCost, AbstractInterfaceError>;
+}
+
+impl<A: Api, S: StateInterface> GasMeter for MockBase<A, S> {
+    fn measure(
+        &self,
+        tx: impl FnOnce() -> Result<Self::Response, AbstractInterfaceError>,
+    ) -> Result<TxCost, AbstractInterfaceError> {
+        let before: BTreeMap<Vec<u8>, Vec<u8>> = self
+            .app
+            .borrow()
+            .storage()
+            .range(None, None, Order::Ascending)
+            .collect();
+        let response = tx()?;
+        let after: BTreeMap<Vec<u8>, Vec<u8>> = self
+            .app
+            .borrow()
+            .storage()
+            .range(None, None, Order::Ascending)
+            .collect();
+
+        let written = after
+            .iter()
+            .filter(|(key, value)| before.get(*key) != Some(*value));
+        let removed = before.keys().filter(|key| !after.contains_key(*key));
+        let storage_bytes_written = written
+            .clone()
+            .map(|(key, value)| (key.len() + value.len()) as u64)
+            .sum();
+
+        Ok(TxCost {
+            gas_used: None,
+            contract_calls: contract_calls(&response.events),
+            storage_writes: Some((written.count() + removed.count()) as u64),
+            storage_bytes_written: Some(storage_bytes_written),
+        })
+    }
+}
+
+#[cfg(feature = "daemon")]
+impl GasMeter for cw_orch::daemon::Daemon {
+    fn measure(
+        &self,
+        tx: impl FnOnce() -> Result<Self::Response, AbstractInterfaceError>,
+    ) -> Result<TxCost, AbstractInterfaceError> {
+        let response = tx()?;
+        Ok(TxCost {
+            gas_used: Some(response.gas_used),
+            contract_calls: contract_calls(&response.events()),
+            storage_writes: None,
+            storage_bytes_written: None,
+        })
+    }
+}
+
+fn contract_calls(events: &[Event]) -> u64 {
+    events
+        .iter()
+        .filter(|event| CONTRACT_CALL_EVENTS.contains(&event.ty.as_str()))
+        .count() as u64
+}
+
+/// Costs of the scenarios of a benchmark suite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasBenchmark {
+    suite: String,
+    version: String,
+    results: BTreeMap<String, TxCost>,
+}
+
+impl GasBenchmark {
+    /// Benchmark `suite` of a contract `version`, the version is part of the report.
+    pub fn new(suite: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            suite: suite.into(),
+            version: version.into(),
+            results: BTreeMap::new(),
+        }
+    }
+
+    /// Measure and record the transaction of a scenario.
+    pub fn record<Chain: GasMeter>(
+        &mut self,
+        chain: &Chain,
+        scenario: impl Into<String>,
+        tx: impl FnOnce() -> Result<Chain::Response, AbstractInterfaceError>,
+    ) -> Result<TxCost, AbstractInterfaceError> {
+        let cost = chain.measure(tx)?;
+        self.results.insert(scenario.into(), cost.clone());
+        Ok(cost)
+    }
+
+    /// Recorded cost of a scenario
+    pub fn cost(&self, scenario: &str) -> Option<&TxCost> {
+        self.results.get(scenario)
+    }
+
+    /// Markdown report, with the scenarios sorted by name.
+    pub fn report(&self) -> String {
+        let optional = |value: Option<u64>| value.map_or_else(|| "-".to_owned(), |v| v.to_string());
+
+        let mut report = format!(
+            "# {} gas report\n\nVersion: {}\n\n",
+            self.suite, self.version
+        );
+        report.push_str(
+            "| Scenario | Gas used | Contract calls | Storage writes | Bytes written |\n",
+        );
+        report.push_str("| --- | --- | --- | --- | --- |\n");
+        for (scenario, cost) in &self.results {
+            writeln!(
+                report,
+                "| {scenario} | {} | {} | {} | {} |",
+                optional(cost.gas_used),
+                cost.contract_calls,
+                optional(cost.storage_writes),
+                optional(cost.storage_bytes_written),
+            )
+            .unwrap();
+        }
+        report
+    }
+
+    /// Write the report to `<dir>/<suite>.md`.
+    pub fn write(&self, dir: impl AsRef<Path>) -> Result<PathBuf, AbstractInterfaceError> {
+        let path = dir.as_ref().join(format!("{}.md", self.suite));
+        fs::create_dir_all(dir.as_ref())
+            .and_then(|_| fs::write(&path, self.report()))
+            .map_err(|e| {
+                cosmwasm_std::StdError::generic_err(format!(
+                    "Failed to write gas report {}: {e}",
+                    path.display()
+                ))
+            })?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use abstract_std::objects::gov_type::GovernanceDetails;
+
+    use super::*;
+    use crate::{Abstract, AccountI, RegistryExecFns};
+
+    #[coverage_helper::test]
+    fn mock_measures_contract_calls() -> Result<(), AbstractInterfaceError> {
+        let chain = MockBech32::new("mock");
+        let abstr = Abstract::deploy_on(chain.clone(), ())?;
+        let account = AccountI::create_default_account(
+            &abstr,
+            GovernanceDetails::Monarchy {
+                monarch: chain.sender_addr().to_string(),
+            },
+        )?;
+        let account_id = account.id()?;
+
+        let mut benchmark = GasBenchmark::new("registry", "1.0.0");
+        let cost = benchmark.record(&chain, "claim_namespace", || {
+            abstr
+                .registry
+                .claim_namespace(account_id, "tester".to_owned())
+                .map_err(Into::into)
+        })?;
+
+        assert_eq!(cost.gas_used, None);
+        assert_eq!(cost.contract_calls, 1);
+        assert!(cost.storage_writes.unwrap() > 0);
+        assert!(cost.storage_bytes_written.unwrap() > 0);
+        assert_eq!(benchmark.cost("claim_namespace"), Some(&cost));
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn report_is_sorted() {
+        let mut benchmark = GasBenchmark::new("account", "0.1.0");
+        benchmark.results.insert(
+            "uninstall".to_owned(),
+            TxCost {
+                gas_used: Some(1000),
+                contract_calls: 2,
+                storage_writes: None,
+                storage_bytes_written: None,
+            },
+        );
+        benchmark.results.insert(
+            "install".to_owned(),
+            TxCost {
+                gas_used: None,
+                contract_calls: 5,
+                storage_writes: Some(10),
+                storage_bytes_written: Some(300),
+            },
+        );
+
+        assert_eq!(
+            benchmark.report(),
+            "# account gas report\n\
+            \n\
+            Version: 0.1.0\n\
+            \n\
+            | Scenario | Gas used | Contract calls | Storage writes | Bytes written |\n\
+            | --- | --- | --- | --- | --- |\n\
+            | install | - | 5 | 10 | 300 |\n\
+            | uninstall | 1000 | 2 | - | - |\n"
+        );
+    }
+}
@@ -63,9 +63,11 @@ pub mod connection {
     use crate::Abstract;
 
     use super::*;
+    use abstract_std::ans_host::ExecuteMsgFns as _;
     use abstract_std::ibc_client::{ExecuteMsgFns, QueryMsgFns};
     use abstract_std::ibc_host::ExecuteMsgFns as _;
-    use abstract_std::objects::TruncatedChainId;
+    use abstract_std::objects::{TruncatedChainId, UncheckedChannelEntry};
+    use abstract_std::ICS20;
     use cw_orch::environment::Environment;
     use cw_orch_interchain::prelude::*;
     use cw_orch_polytone::interchain::PolytoneConnection;
@@ -86,6 +88,76 @@ pub mod connection {
             Ok(())
         }
 
+        /// Deploys Abstract on each of the provided chains and connects every pair of deployments in both directions.
+        ///
+        /// This is mostly useful to set up multi-chain tests in a single call. The deployments are returned in the order of `chain_ids`.
+        pub fn deploy_and_connect_all<IBC: InterchainEnv<Chain>>(
+            interchain: &IBC,
+            chain_ids: &[&str],
+        ) -> Result<Vec<Abstract<Chain>>, AbstractInterfaceError> {
+            let deployments = chain_ids
+                .iter()
+                .map(|chain_id| Abstract::deploy_on(interchain.get_chain(*chain_id)?, ()))
+                .collect::<Result<Vec<_>, AbstractInterfaceError>>()?;
+
+            for (i, abstr) in deployments.iter().enumerate() {
+                for remote_abstr in &deployments[i + 1..] {
+                    abstr.connect_to(remote_abstr, interchain)?;
+                }
+            }
+            Ok(deployments)
+        }
+
+        /// Creates an ICS20 channel between the transfer ports of both chains and registers it in the ans host of each deployment.
+        ///
+        /// Returns the channel id on this chain and the channel id on the remote chain.
+        pub fn create_ics20_channel<IBC: InterchainEnv<Chain>>(
+            &self,
+            remote_abstr: &Abstract<Chain>,
+            interchain: &IBC,
+        ) -> Result<(String, String), AbstractInterfaceError> {
+            let chain_id = self.ans_host.environment().chain_id();
+            let remote_chain_id = remote_abstr.ans_host.environment().chain_id();
+
+            let channel = interchain
+                .create_channel(
+                    &chain_id,
+                    &remote_chain_id,
+                    &PortId::transfer(),
+                    &PortId::transfer(),
+                    "ics20-1",
+                    Some(cosmwasm_std::IbcOrder::Unordered),
+                )?
+                .interchain_channel;
+            let channel_id = |chain_id: &str| -> Result<String, AbstractInterfaceError> {
+                channel
+                    .get_chain(chain_id)?
+                    .channel
+                    .map(|channel_id| channel_id.to_string())
+                    .ok_or_else(|| AbstractInterfaceError::ChannelNotOpened(chain_id.to_owned()))
+            };
+            let local_channel = channel_id(&chain_id)?;
+            let remote_channel = channel_id(&remote_chain_id)?;
+
+            for (abstr, connected_chain_id, channel_id) in [
+                (self, &remote_chain_id, &local_channel),
+                (remote_abstr, &chain_id, &remote_channel),
+            ] {
+                abstr.ans_host.update_channels(
+                    vec![(
+                        UncheckedChannelEntry {
+                            connected_chain: TruncatedChainId::from_chain_id(connected_chain_id)
+                                .to_string(),
+                            protocol: ICS20.to_string(),
+                        },
+                        channel_id.clone(),
+                    )],
+                    vec![],
+                )?;
+            }
+            Ok((local_channel, remote_channel))
+        }
+
         /// This is used for completely removing a connection between two Abstract connections.
         pub fn disconnect_from(
             &self,
@@ -206,6 +278,134 @@ pub mod connection {
     }
 }
 
+#[cfg(feature = "interchain")]
+// Helpers to make IBC packets fail on demand in tests
+pub mod fault_injection {
+    use crate::Abstract;
+
+    use super::*;
+    use abstract_std::ibc_client::QueryMsgFns;
+    use abstract_std::ibc_host::ExecuteMsgFns;
+    use abstract_std::objects::TruncatedChainId;
+    use cw_orch::environment::Environment;
+    use cw_orch_interchain::prelude::*;
+
+    /// Time to wait for a packet to time out, longer than the lifetime of any packet sent by Abstract
+    pub const TIMEOUT_DELAY_SECONDS: u64 = 60 * 60 * 24;
+
+    /// Relays the packets sent from one Abstract deployment to another with injected faults.
+    ///
+    /// Packets of the mock interchain environment are only relayed once they are awaited,
+    /// which lets every transaction be resolved with a different outcome.
+    pub struct IbcFaultInjector<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> {
+        interchain: &'a IBC,
+        origin: &'a Abstract<Chain>,
+        remote: &'a Abstract<Chain>,
+    }
+
+    impl<'a, Chain: IbcQueryHandler, IBC: InterchainEnv<Chain>> IbcFaultInjector<'a, Chain, IBC> {
+        pub fn new(
+            interchain: &'a IBC,
+            origin: &'a Abstract<Chain>,
+            remote: &'a Abstract<Chain>,
+        ) -> Self {
+            Self {
+                interchain,
+                origin,
+                remote,
+            }
+        }
+
+        /// Relays the packets sent by `tx` after they expired, so the origin chain receives a timeout.
+        pub fn timeout(
+            &self,
+            tx: <Chain as TxHandler>::Response,
+        ) -> Result<(), AbstractInterfaceError> {
+            self.remote
+                .ibc
+                .host
+                .environment()
+                .wait_seconds(TIMEOUT_DELAY_SECONDS)
+                .map_err(Into::<CwOrchError>::into)?;
+
+            let flow = self.interchain.await_packets(&self.origin_chain_id(), tx)?;
+            if flow.packets.is_empty()
+                || !flow
+                    .packets
+                    .iter()
+                    .all(|packet| matches!(packet, IbcPacketOutcome::Timeout { .. }))
+            {
+                return Err(AbstractInterfaceError::FaultNotInjected(
+                    "time out".to_string(),
+                ));
+            }
+            Ok(())
+        }
+
+        /// Relays the packets sent by `tx` while the remote host rejects messages of the origin chain,
+        /// so the origin chain receives an error acknowledgement.
+        ///
+        /// The connection is restored afterwards. Fails with [`AbstractInterfaceError::FaultNotInjected`]
+        /// if no packet was rejected with an error acknowledgement.
+        pub fn error_ack(
+            &self,
+            tx: <Chain as TxHandler>::Response,
+        ) -> Result<(), AbstractInterfaceError> {
+            let origin_chain_id = self.origin_chain_id();
+            let origin_name = TruncatedChainId::from_chain_id(&origin_chain_id);
+            let remote_chain_id = self.remote.ibc.host.environment().chain_id();
+            let remote_proxy = self
+                .origin
+                .ibc
+                .client
+                .host(TruncatedChainId::from_chain_id(&remote_chain_id))?
+                .remote_polytone_proxy
+                .ok_or_else(|| {
+                    AbstractInterfaceError::NotConnected(origin_chain_id.clone(), remote_chain_id)
+                })?;
+
+            self.remote
+                .ibc
+                .host
+                .remove_chain_proxy(origin_name.clone())?;
+            let relayed = self.interchain.await_packets(&origin_chain_id, tx);
+            self.remote
+                .ibc
+                .host
+                .register_chain_proxy(origin_name, remote_proxy)?;
+
+            let flow = relayed?;
+            let timed_out = flow
+                .packets
+                .iter()
+                .any(|packet| matches!(packet, IbcPacketOutcome::Timeout { .. }));
+            if flow.packets.is_empty() || timed_out || flow.assert().is_ok() {
+                return Err(AbstractInterfaceError::FaultNotInjected(
+                    "fail with an error acknowledgement".to_string(),
+                ));
+            }
+            Ok(())
+        }
+
+        /// Relays the packets sent by the transactions in the reverse order of their submission.
+        pub fn reversed(
+            &self,
+            txs: Vec<<Chain as TxHandler>::Response>,
+        ) -> Result<(), AbstractInterfaceError> {
+            let origin_chain_id = self.origin_chain_id();
+            for tx in txs.into_iter().rev() {
+                self.interchain
+                    .await_and_check_packets(&origin_chain_id, tx)?;
+            }
+            Ok(())
+        }
+
+        fn origin_chain_id(&self) -> String {
+            self.origin.ibc.client.environment().chain_id()
+        }
+    }
+}
+
 #[cfg(feature = "interchain")]
 #[cfg(test)]
 mod test {
@@ -22,9 +22,11 @@ mod daemon_state;
 mod deployers;
 mod deployment;
 mod error;
+mod gas_benchmark;
+mod upgrade_check;
 
 pub use error::AbstractInterfaceError;
 
-pub use crate::{deployers::*, deployment::*};
+pub use crate::{deployers::*, deployment::*, gas_benchmark::*, upgrade_check::*};
 
 pub use daemon_state::AbstractDaemonState;
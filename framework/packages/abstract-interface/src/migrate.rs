@@ -1,4 +1,4 @@
-use crate::{Abstract, AbstractIbc};
+use crate::{Abstract, AbstractIbc, UpgradeCheck};
 use abstract_std::registry::QueryMsgFns;
 use abstract_std::{
     ans_host, ibc_client, ibc_host, module_factory, objects::module::ModuleInfo, registry, ACCOUNT,
@@ -39,13 +39,28 @@ impl<T: CwEnv> Abstract<T> {
     }
 
     /// Migrate the deployment based on version changes. If the registered contracts have the right version, we don't migrate them
+    /// Migrations are checked with the default [`UpgradeCheck`], which skips releases without a schema in the repository.
+    /// See [`Self::migrate_if_version_changed_with_check`] to override it.
     pub fn migrate_if_version_changed(&self) -> Result<bool, crate::AbstractInterfaceError> {
+        self.migrate_if_version_changed_with_check(&UpgradeCheck::default())
+    }
+
+    /// Same as [`Self::migrate_if_version_changed`], with a custom upgrade `check`.
+    /// Use [`UpgradeCheck::allow_unsafe`] to migrate even if the check fails.
+    pub fn migrate_if_version_changed_with_check(
+        &self,
+        check: &UpgradeCheck,
+    ) -> Result<bool, crate::AbstractInterfaceError> {
         let mut has_migrated = false;
         let mut natives_to_register = vec![];
 
-        if ::module_factory::contract::CONTRACT_VERSION
-            != contract_version(&self.module_factory)?.version
-        {
+        let module_factory_version = contract_version(&self.module_factory)?.version;
+        if ::module_factory::contract::CONTRACT_VERSION != module_factory_version {
+            check.ensure_safe(
+                "module-factory",
+                &module_factory_version,
+                ::module_factory::contract::CONTRACT_VERSION,
+            )?;
             let migration_result = self
                 .module_factory
                 .upload_and_migrate_if_needed(&module_factory::MigrateMsg::Migrate {})?;
@@ -58,7 +73,13 @@ impl<T: CwEnv> Abstract<T> {
             ));
         }
 
-        if ::registry::contract::CONTRACT_VERSION != contract_version(&self.registry)?.version {
+        let registry_version = contract_version(&self.registry)?.version;
+        if ::registry::contract::CONTRACT_VERSION != registry_version {
+            check.ensure_safe(
+                "registry",
+                &registry_version,
+                ::registry::contract::CONTRACT_VERSION,
+            )?;
             let migration_result = self
                 .registry
                 .upload_and_migrate_if_needed(&registry::MigrateMsg::Migrate {})?;
@@ -71,7 +92,13 @@ impl<T: CwEnv> Abstract<T> {
             ));
         }
 
-        if ::ans_host::contract::CONTRACT_VERSION != contract_version(&self.ans_host)?.version {
+        let ans_host_version = contract_version(&self.ans_host)?.version;
+        if ::ans_host::contract::CONTRACT_VERSION != ans_host_version {
+            check.ensure_safe(
+                "ans-host",
+                &ans_host_version,
+                ::ans_host::contract::CONTRACT_VERSION,
+            )?;
             let migration_result = self
                 .ans_host
                 .upload_and_migrate_if_needed(&ans_host::MigrateMsg::Migrate {})?;
@@ -104,7 +131,7 @@ impl<T: CwEnv> Abstract<T> {
             has_migrated = true
         }
 
-        if self.ibc.deploy_or_migrate_if_version_changed()? {
+        if self.ibc.deploy_or_migrate_if_version_changed(check)? {
             has_migrated = true;
 
             natives_to_register.push((
@@ -230,9 +257,10 @@ impl<Chain: CwEnv> AbstractIbc<Chain> {
     /// Deploys or Migrates IBC Client and IBC Host
     /// - If no version changes - don't do anything
     /// - If version change is breaking - new version is deployed
-    /// - If version change is non-breaking - ibc contracts migrated instead
+    /// - If version change is non-breaking - ibc contracts migrated instead, if the upgrade passes the `check`
     pub fn deploy_or_migrate_if_version_changed(
         &self,
+        check: &UpgradeCheck,
     ) -> Result<bool, crate::AbstractInterfaceError> {
         let ibc_client_cw2_version = contract_version(&self.client)?.version;
         // Check if any version changes
@@ -242,12 +270,28 @@ impl<Chain: CwEnv> AbstractIbc<Chain> {
             return Ok(false);
         }
 
-        self.client.upload_if_needed()?;
-        self.host.upload_if_needed()?;
-        if is_upgrade_breaking(
+        let is_breaking = is_upgrade_breaking(
             &ibc_client_cw2_version,
             ::ibc_client::contract::CONTRACT_VERSION,
-        ) {
+        );
+        // New instances don't inherit the state, so only migrations are checked
+        if !is_breaking {
+            let ibc_host_cw2_version = contract_version(&self.host)?.version;
+            check.ensure_safe(
+                "ibc-client",
+                &ibc_client_cw2_version,
+                ::ibc_client::contract::CONTRACT_VERSION,
+            )?;
+            check.ensure_safe(
+                "ibc-host",
+                &ibc_host_cw2_version,
+                ::ibc_host::contract::CONTRACT_VERSION,
+            )?;
+        }
+
+        self.client.upload_if_needed()?;
+        self.host.upload_if_needed()?;
+        if is_breaking {
             // Version change is breaking, need to instantiate new version
 
             self.instantiate(&self.client.environment().sender_addr())?;
@@ -0,0 +1,655 @@
+//! # ANS snapshots
+//! Deterministic JSON representation of the entire state of an ans-host.
+//! Used to export the ANS of a chain, compare it with another one and bootstrap a new chain from it.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use abstract_std::{
+    ans_host::*,
+    objects::{
+        pool_id::UncheckedPoolAddress, AssetEntry, ChannelEntry, ContractEntry, PoolMetadata,
+        UncheckedChannelEntry, UncheckedContractEntry, UniquePoolId,
+    },
+};
+use cosmwasm_std::Addr;
+use cw_asset::{AssetInfo, AssetInfoUnchecked};
+use cw_orch::prelude::*;
+
+use super::AnsHost;
+use crate::AbstractInterfaceError;
+
+/// Page size used when listing the ans-host state. Equal to the max limit of the ans-host queries.
+const PAGE_LIMIT: u8 = 25;
+
+/// Default amount of entries updated per transaction when applying a snapshot.
+pub const DEFAULT_SNAPSHOT_BATCH_SIZE: usize = 25;
+
+/// A pool registered in the ANS.
+#[cosmwasm_schema::cw_serde]
+pub struct PoolSnapshot {
+    /// Id of the pool on the chain the snapshot was taken from.
+    /// Ids are assigned by the ans-host, so they are not preserved when the pool is registered on another chain.
+    pub unique_id: UniquePoolId,
+    pub address: UncheckedPoolAddress,
+    pub metadata: PoolMetadata,
+}
+
+impl PoolSnapshot {
+    /// Key identifying the pool independently of its id.
+    fn key(&self) -> (String, UncheckedPoolAddress) {
+        (self.metadata.to_string(), self.address.clone())
+    }
+}
+
+/// Entire state of an ans-host. All entries are sorted by key so equal states serialize to equal JSON.
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct AnsSnapshot {
+    pub assets: Vec<(AssetEntry, AssetInfo)>,
+    pub contracts: Vec<(ContractEntry, Addr)>,
+    pub channels: Vec<(ChannelEntry, String)>,
+    pub dexes: Vec<String>,
+    pub pools: Vec<PoolSnapshot>,
+    /// Note that [`PriceSource::Pool`] refers to pool ids of the chain the snapshot was taken from.
+    pub asset_metadata: Vec<(AssetEntry, AssetMetadata)>,
+}
+
+impl AnsSnapshot {
+    /// Sort all the entries by key.
+    pub fn sorted(mut self) -> Self {
+        self.assets.sort_by(|a, b| a.0.cmp(&b.0));
+        self.contracts.sort_by(|a, b| a.0.cmp(&b.0));
+        self.channels.sort_by(|a, b| a.0.cmp(&b.0));
+        self.dexes.sort();
+        self.pools.sort_by_key(PoolSnapshot::key);
+        self.asset_metadata.sort_by(|a, b| a.0.cmp(&b.0));
+        self
+    }
+
+    /// Parse a snapshot from JSON.
+    pub fn from_json(json: &str) -> Result<Self, AbstractInterfaceError> {
+        let snapshot: Self = serde_json::from_str(json)
+            .map_err(|e| cosmwasm_std::StdError::parse_err("AnsSnapshot", e))?;
+        Ok(snapshot.sorted())
+    }
+
+    /// Serialize the snapshot to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, AbstractInterfaceError> {
+        serde_json::to_string_pretty(&self.clone().sorted())
+            .map_err(|e| cosmwasm_std::StdError::serialize_err("AnsSnapshot", e).into())
+    }
+
+    /// Check that all the entries only depend on entries of the snapshot, so it can be applied to an empty ans-host.
+    pub fn validate(&self) -> Result<(), AbstractInterfaceError> {
+        let invalid = |reason: String| Err(AbstractInterfaceError::InvalidSnapshot(reason));
+        let assets: BTreeSet<&AssetEntry> = self.assets.iter().map(|(k, _)| k).collect();
+        let contracts: BTreeSet<&ContractEntry> = self.contracts.iter().map(|(k, _)| k).collect();
+        let pool_ids: BTreeSet<UniquePoolId> = self.pools.iter().map(|p| p.unique_id).collect();
+
+        for pool in &self.pools {
+            if !self.dexes.contains(&pool.metadata.dex) {
+                return invalid(format!("dex of pool {} is not registered", pool.unique_id));
+            }
+            if let Some(asset) = pool.metadata.assets.iter().find(|a| !assets.contains(a)) {
+                return invalid(format!(
+                    "asset {asset} of pool {} is not registered",
+                    pool.unique_id
+                ));
+            }
+        }
+        for (asset, metadata) in &self.asset_metadata {
+            if !assets.contains(asset) {
+                return invalid(format!("metadata of unregistered asset {asset}"));
+            }
+            match &metadata.price_source {
+                Some(PriceSource::Pool(pool_id)) if !pool_ids.contains(pool_id) => {
+                    return invalid(format!("price source of {asset} is unknown pool {pool_id}"))
+                }
+                Some(PriceSource::Oracle(contract)) if !contracts.contains(contract) => {
+                    return invalid(format!(
+                        "price source of {asset} is unknown oracle {contract}"
+                    ))
+                }
+                Some(PriceSource::Peg { asset: peg, .. }) if !assets.contains(peg) => {
+                    return invalid(format!("price source of {asset} is unknown asset {peg}"))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Asset metadata with the [`PriceSource::Pool`] ids replaced according to `pool_ids`.
+    /// Errors if a price source refers to a pool that is not in `pool_ids`.
+    fn remapped_asset_metadata(
+        &self,
+        pool_ids: &BTreeMap<UniquePoolId, UniquePoolId>,
+    ) -> Result<Vec<(AssetEntry, AssetMetadata)>, AbstractInterfaceError> {
+        self.asset_metadata
+            .iter()
+            .cloned()
+            .map(|(asset, mut metadata)| {
+                if let Some(PriceSource::Pool(pool_id)) = &mut metadata.price_source {
+                    *pool_id = *pool_ids.get(pool_id).ok_or_else(|| {
+                        AbstractInterfaceError::InvalidSnapshot(format!(
+                            "price source of {asset} is unknown pool {pool_id}"
+                        ))
+                    })?;
+                }
+                Ok((asset, metadata))
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+            && self.contracts.is_empty()
+            && self.channels.is_empty()
+            && self.dexes.is_empty()
+            && self.pools.is_empty()
+            && self.asset_metadata.is_empty()
+    }
+
+    /// Changes required to go from `self` to `target`.
+    /// Entries with a changed value are only part of [`AnsSnapshotDiff::to_add`], as adding them overwrites the old value.
+    /// Pools are identified by their address and metadata, so a changed pool is removed and added again.
+    pub fn diff(&self, target: &AnsSnapshot) -> AnsSnapshotDiff {
+        AnsSnapshotDiff {
+            to_add: AnsSnapshot {
+                assets: added(&self.assets, &target.assets),
+                contracts: added(&self.contracts, &target.contracts),
+                channels: added(&self.channels, &target.channels),
+                dexes: target
+                    .dexes
+                    .iter()
+                    .filter(|dex| !self.dexes.contains(dex))
+                    .cloned()
+                    .collect(),
+                pools: removed_pools(&target.pools, &self.pools),
+                asset_metadata: added(&self.asset_metadata, &target.asset_metadata),
+            }
+            .sorted(),
+            to_remove: AnsSnapshot {
+                assets: removed(&self.assets, &target.assets),
+                contracts: removed(&self.contracts, &target.contracts),
+                channels: removed(&self.channels, &target.channels),
+                dexes: self
+                    .dexes
+                    .iter()
+                    .filter(|dex| !target.dexes.contains(dex))
+                    .cloned()
+                    .collect(),
+                pools: removed_pools(&self.pools, &target.pools),
+                asset_metadata: removed(&self.asset_metadata, &target.asset_metadata),
+            }
+            .sorted(),
+        }
+    }
+}
+
+/// Entries of `target` that are not in `current` or have a different value.
+fn added<K: Ord + Clone, V: PartialEq + Clone>(
+    current: &[(K, V)],
+    target: &[(K, V)],
+) -> Vec<(K, V)> {
+    let current: BTreeMap<&K, &V> = current.iter().map(|(k, v)| (k, v)).collect();
+    target
+        .iter()
+        .filter(|(k, v)| current.get(k) != Some(&v))
+        .cloned()
+        .collect()
+}
+
+/// Entries of `current` whose key is not in `target`.
+fn removed<K: Ord + Clone, V: Clone>(current: &[(K, V)], target: &[(K, V)]) -> Vec<(K, V)> {
+    let target: BTreeSet<&K> = target.iter().map(|(k, _)| k).collect();
+    current
+        .iter()
+        .filter(|(k, _)| !target.contains(k))
+        .cloned()
+        .collect()
+}
+
+/// Pools of `current` that are not in `target`.
+fn removed_pools(current: &[PoolSnapshot], target: &[PoolSnapshot]) -> Vec<PoolSnapshot> {
+    let target: BTreeSet<_> = target.iter().map(PoolSnapshot::key).collect();
+    current
+        .iter()
+        .filter(|pool| !target.contains(&pool.key()))
+        .cloned()
+        .collect()
+}
+
+/// Difference between two [`AnsSnapshot`]s.
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct AnsSnapshotDiff {
+    pub to_add: AnsSnapshot,
+    pub to_remove: AnsSnapshot,
+}
+
+impl AnsSnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.to_add.is_empty() && self.to_remove.is_empty()
+    }
+
+    /// ans-host messages that apply the diff, each updating at most `batch_size` entries.
+    /// Removals come first, and entries are added after the entries they depend on (dexes and assets before pools and metadata).
+    pub fn into_msgs(self, batch_size: usize) -> Vec<ExecuteMsg> {
+        let batch_size = batch_size.max(1);
+        let AnsSnapshotDiff { to_add, to_remove } = self;
+        let mut msgs = vec![];
+
+        let asset_name = |entry: AssetEntry| entry.to_string();
+        let contract_entry =
+            |entry: ContractEntry| UncheckedContractEntry::new(entry.protocol, entry.contract);
+        let channel_entry = |entry: ChannelEntry| {
+            UncheckedChannelEntry::new(entry.connected_chain.to_string(), entry.protocol)
+        };
+
+        // Removals
+        for chunk in to_remove.asset_metadata.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateAssetMetadata {
+                to_add: vec![],
+                to_remove: chunk.iter().map(|(k, _)| asset_name(k.clone())).collect(),
+            });
+        }
+        for chunk in to_remove.pools.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdatePools {
+                to_add: vec![],
+                to_remove: chunk.iter().map(|pool| pool.unique_id).collect(),
+            });
+        }
+        for chunk in to_remove.contracts.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateContractAddresses {
+                to_add: vec![],
+                to_remove: chunk
+                    .iter()
+                    .map(|(k, _)| contract_entry(k.clone()))
+                    .collect(),
+            });
+        }
+        for chunk in to_remove.channels.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateChannels {
+                to_add: vec![],
+                to_remove: chunk
+                    .iter()
+                    .map(|(k, _)| channel_entry(k.clone()))
+                    .collect(),
+            });
+        }
+        for chunk in to_remove.assets.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateAssetAddresses {
+                to_add: vec![],
+                to_remove: chunk.iter().map(|(k, _)| asset_name(k.clone())).collect(),
+            });
+        }
+        for chunk in to_remove.dexes.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateDexes {
+                to_add: vec![],
+                to_remove: chunk.to_vec(),
+            });
+        }
+
+        // Additions
+        for chunk in to_add.dexes.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateDexes {
+                to_add: chunk.to_vec(),
+                to_remove: vec![],
+            });
+        }
+        for chunk in to_add.assets.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateAssetAddresses {
+                to_add: chunk
+                    .iter()
+                    .map(|(k, v)| (asset_name(k.clone()), AssetInfoUnchecked::from(v.clone())))
+                    .collect(),
+                to_remove: vec![],
+            });
+        }
+        for chunk in to_add.contracts.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateContractAddresses {
+                to_add: chunk
+                    .iter()
+                    .map(|(k, v)| (contract_entry(k.clone()), v.to_string()))
+                    .collect(),
+                to_remove: vec![],
+            });
+        }
+        for chunk in to_add.channels.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateChannels {
+                to_add: chunk
+                    .iter()
+                    .map(|(k, v)| (channel_entry(k.clone()), v.clone()))
+                    .collect(),
+                to_remove: vec![],
+            });
+        }
+        for chunk in to_add.pools.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdatePools {
+                to_add: chunk
+                    .iter()
+                    .map(|pool| (pool.address.clone(), pool.metadata.clone()))
+                    .collect(),
+                to_remove: vec![],
+            });
+        }
+        for chunk in to_add.asset_metadata.chunks(batch_size) {
+            msgs.push(ExecuteMsg::UpdateAssetMetadata {
+                to_add: chunk
+                    .iter()
+                    .map(|(k, v)| (asset_name(k.clone()), v.clone()))
+                    .collect(),
+                to_remove: vec![],
+            });
+        }
+
+        msgs
+    }
+}
+
+impl<Chain: CwEnv> AnsHost<Chain> {
+    /// Export the entire state of the ans-host.
+    pub fn export_snapshot(&self) -> Result<AnsSnapshot, AbstractInterfaceError> {
+        let mut snapshot = AnsSnapshot::default();
+
+        loop {
+            let AssetListResponse { assets } = self.query(&QueryMsg::AssetList {
+                filter: None,
+                start_after: snapshot.assets.last().map(|(k, _)| k.to_string()),
+                limit: Some(PAGE_LIMIT),
+            })?;
+            if assets.is_empty() {
+                break;
+            }
+            snapshot.assets.extend(assets);
+        }
+
+        loop {
+            let ContractListResponse { contracts } = self.query(&QueryMsg::ContractList {
+                filter: None,
+                start_after: snapshot.contracts.last().map(|(k, _)| k.clone()),
+                limit: Some(PAGE_LIMIT),
+            })?;
+            if contracts.is_empty() {
+                break;
+            }
+            snapshot.contracts.extend(contracts);
+        }
+
+        loop {
+            let ChannelListResponse { channels } = self.query(&QueryMsg::ChannelList {
+                filter: None,
+                start_after: snapshot.channels.last().map(|(k, _)| k.clone()),
+                limit: Some(PAGE_LIMIT),
+            })?;
+            if channels.is_empty() {
+                break;
+            }
+            snapshot.channels.extend(channels);
+        }
+
+        let RegisteredDexesResponse { dexes } = self.query(&QueryMsg::RegisteredDexes {})?;
+        snapshot.dexes = dexes;
+
+        // Pool addresses are only stored in the pairings that reference the pool
+        let mut pool_addresses = BTreeMap::new();
+        let mut start_after = None;
+        loop {
+            let PoolAddressListResponse { pools } = self.query(&QueryMsg::PoolList {
+                filter: None,
+                start_after: start_after.take(),
+                limit: Some(PAGE_LIMIT),
+            })?;
+            let Some((last, _)) = pools.last() else {
+                break;
+            };
+            start_after = Some(last.clone());
+            for pool_reference in pools.into_iter().flat_map(|(_, references)| references) {
+                pool_addresses.insert(pool_reference.unique_id, pool_reference.pool_address);
+            }
+        }
+
+        let mut start_after = None;
+        loop {
+            let PoolMetadataListResponse { metadatas } =
+                self.query(&QueryMsg::PoolMetadataList {
+                    filter: None,
+                    start_after: start_after.take(),
+                    limit: Some(PAGE_LIMIT),
+                })?;
+            let Some((last, _)) = metadatas.last() else {
+                break;
+            };
+            start_after = Some(*last);
+            for (unique_id, metadata) in metadatas {
+                let address = pool_addresses.get(&unique_id).ok_or_else(|| {
+                    cosmwasm_std::StdError::not_found(format!("address of pool {unique_id}"))
+                })?;
+                snapshot.pools.push(PoolSnapshot {
+                    unique_id,
+                    address: address.into(),
+                    metadata,
+                });
+            }
+        }
+
+        loop {
+            let AssetMetadataListResponse { metadatas } =
+                self.query(&QueryMsg::AssetMetadataList {
+                    start_after: snapshot.asset_metadata.last().map(|(k, _)| k.to_string()),
+                    limit: Some(PAGE_LIMIT),
+                })?;
+            if metadatas.is_empty() {
+                break;
+            }
+            snapshot.asset_metadata.extend(metadatas);
+        }
+
+        Ok(snapshot.sorted())
+    }
+
+    /// Update the ans-host so its state matches `snapshot`.
+    /// The snapshot is validated before anything is written.
+    /// Pools get new ids when they are registered, so the pool price sources of the asset metadata are remapped to them.
+    /// Each transaction updates at most `batch_size` entries to stay within the block gas limit.
+    /// Returns the diff that was applied, with the pool ids of this ans-host.
+    pub fn apply_snapshot(
+        &self,
+        snapshot: &AnsSnapshot,
+        batch_size: usize,
+    ) -> Result<AnsSnapshotDiff, AbstractInterfaceError> {
+        snapshot.validate()?;
+        let current = self.export_snapshot()?;
+
+        // Asset metadata is applied once all the pools have an id
+        let target = AnsSnapshot {
+            asset_metadata: current.asset_metadata.clone(),
+            ..snapshot.clone()
+        };
+        let mut diff = current.diff(&target);
+        for msg in diff.clone().into_msgs(batch_size) {
+            self.execute(&msg, &[])?;
+        }
+
+        let current = self.export_snapshot()?;
+        let registered_ids: BTreeMap<_, _> = current
+            .pools
+            .iter()
+            .map(|pool| (pool.key(), pool.unique_id))
+            .collect();
+        let mut pool_ids = BTreeMap::new();
+        for pool in &snapshot.pools {
+            let registered_id = registered_ids.get(&pool.key()).ok_or_else(|| {
+                cosmwasm_std::StdError::not_found(format!("id of pool {}", pool.unique_id))
+            })?;
+            pool_ids.insert(pool.unique_id, *registered_id);
+        }
+        for pool in diff.to_add.pools.iter_mut() {
+            pool.unique_id = pool_ids[&pool.unique_id];
+        }
+
+        let target = AnsSnapshot {
+            asset_metadata: snapshot.remapped_asset_metadata(&pool_ids)?,
+            ..current.clone()
+        };
+        let metadata_diff = current.diff(&target);
+        for msg in metadata_diff.clone().into_msgs(batch_size) {
+            self.execute(&msg, &[])?;
+        }
+        diff.to_add.asset_metadata = metadata_diff.to_add.asset_metadata;
+        diff.to_remove.asset_metadata = metadata_diff.to_remove.asset_metadata;
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Abstract;
+    use abstract_std::objects::PoolType;
+
+    fn mock_snapshot(chain: &MockBech32) -> AnsSnapshot {
+        AnsSnapshot {
+            assets: vec![
+                ("juno>juno".into(), AssetInfo::native("ujuno")),
+                (
+                    "juno>stars".into(),
+                    AssetInfo::cw20(chain.addr_make("stars")),
+                ),
+            ],
+            contracts: vec![(
+                ContractEntry {
+                    protocol: "junoswap".to_owned(),
+                    contract: "staking".to_owned(),
+                },
+                chain.addr_make("staking"),
+            )],
+            channels: vec![(
+                UncheckedChannelEntry::new("osmosis", "ics20")
+                    .check()
+                    .unwrap(),
+                "channel-0".to_owned(),
+            )],
+            dexes: vec!["junoswap".to_owned()],
+            pools: vec![PoolSnapshot {
+                unique_id: UniquePoolId::new(1),
+                address: UncheckedPoolAddress::contract(chain.addr_make("pool")),
+                metadata: PoolMetadata::new(
+                    "junoswap",
+                    PoolType::ConstantProduct,
+                    vec!["juno>juno", "juno>stars"],
+                ),
+            }],
+            asset_metadata: vec![(
+                "juno>juno".into(),
+                AssetMetadata {
+                    decimals: 6,
+                    display_name: "JUNO".to_owned(),
+                    coingecko_id: Some("juno-network".to_owned()),
+                    price_source: None,
+                },
+            )],
+        }
+        .sorted()
+    }
+
+    #[coverage_helper::test]
+    fn apply_and_export_snapshot() -> Result<(), AbstractInterfaceError> {
+        let chain = MockBech32::new("mock");
+        let abstr = Abstract::deploy_on(chain.clone(), ())?;
+        let snapshot = mock_snapshot(&chain);
+
+        let diff = abstr.ans_host.apply_snapshot(&snapshot, 1)?;
+        assert!(diff.to_remove.is_empty());
+        assert_eq!(diff.to_add, snapshot);
+
+        // Exported state matches the snapshot and serializes deterministically
+        let exported = abstr.ans_host.export_snapshot()?;
+        assert_eq!(exported, snapshot);
+        assert_eq!(exported.to_json()?, snapshot.to_json()?);
+        assert_eq!(AnsSnapshot::from_json(&exported.to_json()?)?, snapshot);
+
+        // Applying it again is a no-op
+        assert!(abstr.ans_host.apply_snapshot(&snapshot, 1)?.is_empty());
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn apply_snapshot_removes_entries() -> Result<(), AbstractInterfaceError> {
+        let chain = MockBech32::new("mock");
+        let abstr = Abstract::deploy_on(chain.clone(), ())?;
+        let snapshot = mock_snapshot(&chain);
+        abstr
+            .ans_host
+            .apply_snapshot(&snapshot, DEFAULT_SNAPSHOT_BATCH_SIZE)?;
+
+        let mut target = snapshot.clone();
+        target.pools = vec![];
+        target.asset_metadata = vec![];
+        target.assets.retain(|(k, _)| k.as_str() == "juno>juno");
+        target.channels[0].1 = "channel-1".to_owned();
+
+        let diff = abstr
+            .ans_host
+            .apply_snapshot(&target, DEFAULT_SNAPSHOT_BATCH_SIZE)?;
+        assert_eq!(diff.to_add.channels, target.channels);
+        assert_eq!(diff.to_remove.pools, snapshot.pools);
+        assert_eq!(diff.to_remove.assets.len(), 1);
+
+        assert_eq!(abstr.ans_host.export_snapshot()?, target);
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn apply_snapshot_remaps_pool_price_sources() -> Result<(), AbstractInterfaceError> {
+        let chain = MockBech32::new("mock");
+        let abstr = Abstract::deploy_on(chain.clone(), ())?;
+        let snapshot = mock_snapshot(&chain);
+        abstr
+            .ans_host
+            .apply_snapshot(&snapshot, DEFAULT_SNAPSHOT_BATCH_SIZE)?;
+
+        // Snapshot of another chain, where the pool priced with got id 1
+        let mut target = snapshot.clone();
+        target.pools[0].address = UncheckedPoolAddress::contract(chain.addr_make("other_pool"));
+        target.asset_metadata[0].1.price_source = Some(PriceSource::Pool(UniquePoolId::new(1)));
+
+        let diff = abstr
+            .ans_host
+            .apply_snapshot(&target, DEFAULT_SNAPSHOT_BATCH_SIZE)?;
+        let pool_id = UniquePoolId::new(2);
+        assert_eq!(diff.to_add.pools[0].unique_id, pool_id);
+        assert_eq!(
+            diff.to_add.asset_metadata[0].1.price_source,
+            Some(PriceSource::Pool(pool_id))
+        );
+
+        let exported = abstr.ans_host.export_snapshot()?;
+        assert_eq!(exported.pools[0].unique_id, pool_id);
+        assert_eq!(
+            exported.asset_metadata[0].1.price_source,
+            Some(PriceSource::Pool(pool_id))
+        );
+        Ok(())
+    }
+
+    #[coverage_helper::test]
+    fn invalid_snapshot_is_not_applied() -> Result<(), AbstractInterfaceError> {
+        let chain = MockBech32::new("mock");
+        let abstr = Abstract::deploy_on(chain.clone(), ())?;
+        let mut snapshot = mock_snapshot(&chain);
+        snapshot.asset_metadata[0].1.price_source = Some(PriceSource::Pool(UniquePoolId::new(7)));
+
+        let before = abstr.ans_host.export_snapshot()?;
+        let res = abstr
+            .ans_host
+            .apply_snapshot(&snapshot, DEFAULT_SNAPSHOT_BATCH_SIZE);
+        assert!(matches!(
+            res,
+            Err(AbstractInterfaceError::InvalidSnapshot(_))
+        ));
+        assert_eq!(abstr.ans_host.export_snapshot()?, before);
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 mod ans_host;
+mod ans_snapshot;
 mod ibc_client;
 mod ibc_host;
 mod module_factory;
 mod registry;
 
-pub use self::{ans_host::*, ibc_client::*, ibc_host::*, module_factory::*, registry::*};
+pub use self::{
+    ans_host::*, ans_snapshot::*, ibc_client::*, ibc_host::*, module_factory::*, registry::*,
+};
@@ -66,6 +66,7 @@ impl<Chain: CwEnv> Registry<Chain> {
                 name: Some(info.name.clone()),
                 version: Some(info.version.to_string()),
                 status: Some(ModuleStatus::Registered),
+                attested_by: None,
             }),
             None,
             None,
@@ -81,6 +82,7 @@ impl<Chain: CwEnv> Registry<Chain> {
                     name: Some(info.name),
                     version: Some(info.version.to_string()),
                     status: Some(ModuleStatus::Pending),
+                    attested_by: None,
                 }),
                 None,
                 None,
@@ -108,6 +110,7 @@ impl<Chain: CwEnv> Registry<Chain> {
                         name: Some(info.name.clone()),
                         version: Some(info.version.to_string()),
                         status: Some(m),
+                        attested_by: None,
                     }),
                     None,
                     None,
@@ -154,6 +157,7 @@ impl<Chain: CwEnv> Registry<Chain> {
                         name: Some(parts[1].to_owned()),
                         version: None,
                         status: Some(ModuleStatus::Registered),
+                        attested_by: None,
                     }),
                     None,
                     start_after,
@@ -285,6 +289,31 @@ impl<Chain: CwEnv> Registry<Chain> {
         Ok(())
     }
 
+    /// Propose modules after checking that they can safely replace their latest registered version.
+    /// Errors with [`crate::AbstractInterfaceError::UnsafeUpgrade`] unless the check allows unsafe upgrades.
+    pub fn propose_modules_checked(
+        &self,
+        modules: Vec<(ModuleInfo, ModuleReference)>,
+        check: &crate::UpgradeCheck,
+    ) -> Result<(), crate::AbstractInterfaceError> {
+        for (info, _) in &modules {
+            let ModuleVersion::Version(version) = &info.version else {
+                continue;
+            };
+            // First release of the module, nothing to compare with
+            let Some(registered_version) = self.module_versions(&info.id())?.into_iter().max()
+            else {
+                continue;
+            };
+            let registered_version = registered_version.to_string();
+            if &registered_version != version {
+                check.ensure_safe(&info.name, &registered_version, version)?;
+            }
+        }
+        self.propose_modules(modules)?;
+        Ok(())
+    }
+
     /// Approve any abstract-namespaced pending modules.
     pub fn approve_any_abstract_modules(&self) -> Result<(), crate::AbstractInterfaceError> {
         self.approve_all_modules_for_namespace(Namespace::unchecked(ABSTRACT_NAMESPACE))
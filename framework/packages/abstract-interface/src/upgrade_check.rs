@@ -0,0 +1,1081 @@
+//! Upgrade-safety checks for module releases.
+//!
+//! A release is described by the JSON schema generated with `just schema`, stored in `schema/<module-name>/<version>/`,
+//! and optionally by its storage layout, stored next to it in [`STORAGE_LAYOUT_FILE`].
+//! [`UpgradeCheck`] compares a new release against the registered one and flags:
+//! - removed message variants or fields, changed field types and newly required fields of the execute and query messages.
+//! - storage keys that are reused by a different item, or declared twice.
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map as JsonMap, Value};
+
+use crate::AbstractInterfaceError;
+
+/// Name of the storage layout file in a release schema directory.
+pub const STORAGE_LAYOUT_FILE: &str = "storage-layout.json";
+
+/// Schema directory of this repository, used by the default [`UpgradeCheck`].
+pub const REPOSITORY_SCHEMA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../../schema");
+
+/// Messages that are checked for backwards compatibility.
+const CHECKED_MSGS: [&str; 2] = ["execute", "query"];
+
+/// Storage types that are declared as constants with a namespace.
+const STORAGE_TYPES: [&str; 5] = ["Item", "Map", "Deque", "SnapshotItem", "SnapshotMap"];
+
+/// Incompatibility between two releases of a module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpgradeIssue {
+    RemovedVariant {
+        location: String,
+        variant: String,
+    },
+    RemovedField {
+        location: String,
+        field: String,
+    },
+    ChangedFieldType {
+        location: String,
+        field: String,
+        previous: String,
+        current: String,
+    },
+    NewRequiredField {
+        location: String,
+        field: String,
+    },
+    /// The key was used by another item in the previous release
+    ReusedStorageKey {
+        key: String,
+        previous: String,
+        current: String,
+    },
+    DuplicateStorageKey {
+        key: String,
+        declarations: Vec<String>,
+    },
+}
+
+impl Display for UpgradeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpgradeIssue::RemovedVariant { location, variant } => {
+                write!(f, "variant `{variant}` was removed from `{location}`")
+            }
+            UpgradeIssue::RemovedField { location, field } => {
+                write!(f, "field `{field}` was removed from `{location}`")
+            }
+            UpgradeIssue::ChangedFieldType {
+                location,
+                field,
+                previous,
+                current,
+            } => write!(
+                f,
+                "type of field `{field}` in `{location}` changed from `{previous}` to `{current}`"
+            ),
+            UpgradeIssue::NewRequiredField { location, field } => {
+                write!(f, "field `{field}` of `{location}` is now required")
+            }
+            UpgradeIssue::ReusedStorageKey {
+                key,
+                previous,
+                current,
+            } => write!(
+                f,
+                "storage key \"{key}\" of `{previous}` is reused by `{current}`"
+            ),
+            UpgradeIssue::DuplicateStorageKey { key, declarations } => write!(
+                f,
+                "storage key \"{key}\" is declared by `{}`",
+                declarations.join("`, `")
+            ),
+        }
+    }
+}
+
+/// Result of an upgrade check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpgradeReport {
+    pub issues: Vec<UpgradeIssue>,
+}
+
+impl UpgradeReport {
+    /// Whether the upgrade is safe
+    pub fn is_safe(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for UpgradeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "- {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Storage item declared by a contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageEntry {
+    /// Storage namespace of the item
+    pub key: String,
+    /// Path of the constant, relative to its source file
+    pub name: String,
+    /// Declared type, like `Map<&Addr, Config>`
+    pub ty: String,
+}
+
+impl Display for StorageEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.ty)
+    }
+}
+
+/// Storage items of a release, collected from the `cw_storage_plus` constants in its source files.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageLayout {
+    pub entries: Vec<StorageEntry>,
+}
+
+impl StorageLayout {
+    /// Collect the storage items declared in the source files.
+    /// Namespaces declared as `&str` constants, like in `storage_namespaces.rs`, are resolved across all files.
+    pub fn from_sources(
+        sources: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Result<Self, AbstractInterfaceError> {
+        let sources = sources
+            .into_iter()
+            .map(|path| {
+                fs::read_to_string(path.as_ref())
+                    .map_err(|e| release_error(&path.as_ref().display().to_string(), e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::parse(sources.iter().map(String::as_str)))
+    }
+
+    /// Collect the storage items declared in the source code.
+    pub fn parse<'a>(sources: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut namespaces = HashMap::new();
+        let mut declarations = vec![];
+        for source in sources {
+            for declaration in const_declarations(source) {
+                if declaration.ty.ends_with("str") && is_string_literal(&declaration.value) {
+                    let namespace = declaration.value.trim_matches('"').to_owned();
+                    namespaces.insert(declaration.name, namespace);
+                } else {
+                    declarations.push(declaration);
+                }
+            }
+        }
+
+        let entries = declarations
+            .into_iter()
+            .filter_map(|declaration| {
+                let (constructor, args) = declaration.value.split_once("::new(")?;
+                if !STORAGE_TYPES.contains(&constructor) {
+                    return None;
+                }
+                let namespace = args.trim_end_matches(')').split(',').next()?.trim();
+                let key = if is_string_literal(namespace) {
+                    namespace.trim_matches('"').to_owned()
+                } else {
+                    resolve_namespace(&namespaces, namespace)?
+                };
+                Some(StorageEntry {
+                    key,
+                    name: declaration.name,
+                    ty: declaration.ty,
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Load the layout stored in a release schema directory, if any.
+    pub fn load(release_dir: &Path) -> Result<Option<Self>, AbstractInterfaceError> {
+        let path = release_dir.join(STORAGE_LAYOUT_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let file =
+            fs::read_to_string(&path).map_err(|e| release_error(&path.display().to_string(), e))?;
+        serde_json::from_str(&file)
+            .map(Some)
+            .map_err(|e| release_error(&path.display().to_string(), e))
+    }
+
+    /// Store the layout in a release schema directory, to check the next release against it.
+    pub fn save(&self, release_dir: &Path) -> Result<(), AbstractInterfaceError> {
+        let path = release_dir.join(STORAGE_LAYOUT_FILE);
+        let file = serde_json::to_string_pretty(self)
+            .map_err(|e| release_error(&path.display().to_string(), e))?;
+        fs::write(&path, file).map_err(|e| release_error(&path.display().to_string(), e))
+    }
+
+    fn by_key(&self) -> BTreeMap<&str, Vec<&StorageEntry>> {
+        let mut by_key: BTreeMap<&str, Vec<&StorageEntry>> = BTreeMap::new();
+        for entry in &self.entries {
+            by_key.entry(&entry.key).or_default().push(entry);
+        }
+        by_key
+    }
+
+    fn compare(&self, next: &StorageLayout, issues: &mut Vec<UpgradeIssue>) {
+        let previous = self.by_key();
+        for (key, entries) in next.by_key() {
+            if entries.len() > 1 {
+                issues.push(UpgradeIssue::DuplicateStorageKey {
+                    key: key.to_owned(),
+                    declarations: entries.iter().map(|entry| entry.name.clone()).collect(),
+                });
+                continue;
+            }
+            let current = entries[0];
+            let reused_by = previous.get(key).and_then(|previous_entries| {
+                previous_entries
+                    .iter()
+                    .find(|previous| previous.name != current.name || previous.ty != current.ty)
+            });
+            if let Some(previous) = reused_by {
+                issues.push(UpgradeIssue::ReusedStorageKey {
+                    key: key.to_owned(),
+                    previous: previous.to_string(),
+                    current: current.to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Schema and storage layout of a module version.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleRelease {
+    pub name: String,
+    pub version: String,
+    /// API schema, as generated by `cosmwasm_schema::write_api`
+    pub api: Value,
+    pub storage: Option<StorageLayout>,
+}
+
+impl ModuleRelease {
+    /// Load the release from `schema_dir/<name>/<version>`.
+    pub fn load(
+        schema_dir: &Path,
+        name: &str,
+        version: &str,
+    ) -> Result<Self, AbstractInterfaceError> {
+        let release = format!("{name} {version}");
+        let release_dir = schema_dir.join(name).join(version);
+        let files = fs::read_dir(&release_dir).map_err(|e| release_error(&release, e))?;
+
+        let mut api = None;
+        for file in files {
+            let path = file.map_err(|e| release_error(&release, e))?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json")
+                || path
+                    .file_name()
+                    .is_some_and(|file| file == STORAGE_LAYOUT_FILE)
+            {
+                continue;
+            }
+            let content = fs::read_to_string(&path).map_err(|e| release_error(&release, e))?;
+            let schema: Value =
+                serde_json::from_str(&content).map_err(|e| release_error(&release, e))?;
+            // The API file contains the schemas of all the messages
+            if schema.get("idl_version").is_some() {
+                api = Some(schema);
+                break;
+            }
+        }
+
+        Ok(Self {
+            name: name.to_owned(),
+            version: version.to_owned(),
+            api: api.ok_or_else(|| release_error(&release, "API schema not found"))?,
+            storage: StorageLayout::load(&release_dir)?,
+        })
+    }
+
+    /// Compare the release with the `next` one.
+    /// Storage keys are checked when the next release has a layout, reused keys can only be detected if the previous one has a layout too.
+    pub fn compare(&self, next: &ModuleRelease) -> UpgradeReport {
+        let mut issues = vec![];
+        for msg in CHECKED_MSGS {
+            let (Some(previous), Some(current)) = (self.api.get(msg), next.api.get(msg)) else {
+                continue;
+            };
+            if previous.is_null() {
+                continue;
+            }
+            SchemaDiff {
+                previous_definitions: definitions(previous),
+                current_definitions: definitions(current),
+                visited: HashSet::new(),
+                issues: &mut issues,
+            }
+            .compare(previous, current, msg);
+        }
+
+        if let Some(next_storage) = &next.storage {
+            self.storage
+                .clone()
+                .unwrap_or_default()
+                .compare(next_storage, &mut issues);
+        }
+        UpgradeReport { issues }
+    }
+}
+
+/// Checks releases in a schema directory before they are published.
+#[derive(Debug, Clone)]
+pub struct UpgradeCheck {
+    schema_dir: PathBuf,
+    storage_sources: HashMap<String, Vec<PathBuf>>,
+    allow_unsafe: bool,
+}
+
+impl Default for UpgradeCheck {
+    /// Check the releases of the framework, stored in [`REPOSITORY_SCHEMA_DIR`].
+    fn default() -> Self {
+        Self::new(REPOSITORY_SCHEMA_DIR)
+    }
+}
+
+impl UpgradeCheck {
+    /// Check releases stored in `schema_dir`, usually the `schema` directory of the repository.
+    pub fn new(schema_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            schema_dir: schema_dir.into(),
+            storage_sources: HashMap::new(),
+            allow_unsafe: false,
+        }
+    }
+
+    /// Source files to collect the storage layout of the new release of `module_name` from.
+    /// If not set, the layout stored in the release schema directory is used.
+    pub fn with_storage_sources(
+        mut self,
+        module_name: impl Into<String>,
+        sources: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        self.storage_sources.insert(
+            module_name.into(),
+            sources.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    /// Override: publish unsafe upgrades, only logging the issues.
+    pub fn allow_unsafe(mut self, allow_unsafe: bool) -> Self {
+        self.allow_unsafe = allow_unsafe;
+        self
+    }
+
+    /// Compare release `version` of the module with its `previous_version`.
+    pub fn check(
+        &self,
+        module_name: &str,
+        previous_version: &str,
+        version: &str,
+    ) -> Result<UpgradeReport, AbstractInterfaceError> {
+        let previous = ModuleRelease::load(&self.schema_dir, module_name, previous_version)?;
+        let mut next = ModuleRelease::load(&self.schema_dir, module_name, version)?;
+        if let Some(sources) = self.storage_sources.get(module_name) {
+            next.storage = Some(StorageLayout::from_sources(sources)?);
+        }
+        Ok(previous.compare(&next))
+    }
+
+    /// Check the upgrade and error if it's unsafe, unless unsafe upgrades are allowed.
+    /// The check is skipped with a warning if the schema of either release is missing,
+    /// e.g. when the crate is built outside of the repository.
+    pub fn ensure_safe(
+        &self,
+        module_name: &str,
+        previous_version: &str,
+        version: &str,
+    ) -> Result<UpgradeReport, AbstractInterfaceError> {
+        for release in [previous_version, version] {
+            let release_dir = self.schema_dir.join(module_name).join(release);
+            if !release_dir.is_dir() {
+                log::warn!(
+                    "Skipping upgrade check of {module_name} {version}: no schema of release {release} in {}",
+                    self.schema_dir.display()
+                );
+                return Ok(UpgradeReport::default());
+            }
+        }
+        let report = match self.check(module_name, previous_version, version) {
+            Ok(report) => report,
+            Err(error) if self.allow_unsafe => {
+                log::warn!("Skipping upgrade check of {module_name} {version}: {error}");
+                return Ok(UpgradeReport::default());
+            }
+            Err(error) => return Err(error),
+        };
+        if report.is_safe() {
+            return Ok(report);
+        }
+        if self.allow_unsafe {
+            log::warn!(
+                "Unsafe upgrade of {module_name} from {previous_version} to {version}:\n{report}"
+            );
+            return Ok(report);
+        }
+        Err(AbstractInterfaceError::UnsafeUpgrade {
+            module: module_name.to_owned(),
+            previous_version: previous_version.to_owned(),
+            version: version.to_owned(),
+            report,
+        })
+    }
+}
+
+fn release_error(release: &str, error: impl Display) -> AbstractInterfaceError {
+    AbstractInterfaceError::ReleaseSchema {
+        release: release.to_owned(),
+        reason: error.to_string(),
+    }
+}
+
+fn definitions(schema: &Value) -> JsonMap<String, Value> {
+    schema
+        .get("definitions")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default()
+}
+
+struct SchemaDiff<'a> {
+    previous_definitions: JsonMap<String, Value>,
+    current_definitions: JsonMap<String, Value>,
+    /// Definitions that were already compared, recursive types reference themselves
+    visited: HashSet<String>,
+    issues: &'a mut Vec<UpgradeIssue>,
+}
+
+impl SchemaDiff<'_> {
+    fn compare(&mut self, previous: &Value, current: &Value, location: &str) {
+        if let Some(name) = reference(previous) {
+            if !self.visited.insert(name.to_owned()) {
+                return;
+            }
+        }
+        let (Some(previous), Some(current)) = (
+            resolve(previous, &self.previous_definitions).cloned(),
+            resolve(current, &self.current_definitions).cloned(),
+        ) else {
+            return;
+        };
+
+        let previous_variants = variants(&previous);
+        if !previous_variants.is_empty() {
+            self.compare_variants(previous_variants, variants(&current), location);
+        } else if previous.get("properties").is_some() {
+            self.compare_fields(&previous, &current, location);
+        }
+    }
+
+    fn compare_variants(
+        &mut self,
+        previous: Vec<(String, Option<Value>)>,
+        current: Vec<(String, Option<Value>)>,
+        location: &str,
+    ) {
+        let current: HashMap<String, Option<Value>> = current.into_iter().collect();
+        for (variant, previous_payload) in previous {
+            let Some(current_payload) = current.get(&variant) else {
+                self.issues.push(UpgradeIssue::RemovedVariant {
+                    location: location.to_owned(),
+                    variant,
+                });
+                continue;
+            };
+            match (previous_payload, current_payload) {
+                (None, None) => {}
+                (Some(previous_payload), Some(current_payload)) => {
+                    self.compare_field(&previous_payload, current_payload, location, &variant)
+                }
+                // Unit variant became a struct variant or the other way around
+                (previous_payload, current_payload) => {
+                    let type_or_unit = |payload: Option<&Value>| {
+                        payload.map_or_else(|| "unit".to_owned(), type_name)
+                    };
+                    self.issues.push(UpgradeIssue::ChangedFieldType {
+                        location: location.to_owned(),
+                        field: variant,
+                        previous: type_or_unit(previous_payload.as_ref()),
+                        current: type_or_unit(current_payload.as_ref()),
+                    });
+                }
+            }
+        }
+    }
+
+    fn compare_fields(&mut self, previous: &Value, current: &Value, location: &str) {
+        let properties = |schema: &Value| {
+            schema
+                .get("properties")
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default()
+        };
+        let required = |schema: &Value| -> BTreeSet<String> {
+            schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|required| {
+                    required
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(ToOwned::to_owned)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let current_properties = properties(current);
+        for (field, previous_field) in properties(previous) {
+            match current_properties.get(&field) {
+                Some(current_field) => {
+                    self.compare_field(&previous_field, current_field, location, &field)
+                }
+                None => self.issues.push(UpgradeIssue::RemovedField {
+                    location: location.to_owned(),
+                    field,
+                }),
+            }
+        }
+        for field in required(current).difference(&required(previous)) {
+            self.issues.push(UpgradeIssue::NewRequiredField {
+                location: location.to_owned(),
+                field: field.clone(),
+            });
+        }
+    }
+
+    fn compare_field(&mut self, previous: &Value, current: &Value, location: &str, field: &str) {
+        let (previous_type, current_type) = (type_name(previous), type_name(current));
+        if previous_type != current_type {
+            self.issues.push(UpgradeIssue::ChangedFieldType {
+                location: location.to_owned(),
+                field: field.to_owned(),
+                previous: previous_type,
+                current: current_type,
+            });
+            return;
+        }
+        self.compare(previous, current, &format!("{location}.{field}"));
+    }
+}
+
+/// Name of the referenced definition
+fn reference(schema: &Value) -> Option<&str> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference.rsplit('/').next();
+    }
+    match schema.get("allOf").and_then(Value::as_array) {
+        Some(all_of) if all_of.len() == 1 => reference(&all_of[0]),
+        _ => None,
+    }
+}
+
+fn resolve<'a>(schema: &'a Value, definitions: &'a JsonMap<String, Value>) -> Option<&'a Value> {
+    match reference(schema) {
+        Some(name) => definitions.get(name),
+        None => Some(schema),
+    }
+}
+
+/// Variants of an enum schema: the name and, for struct or tuple variants, the payload schema
+fn variants(schema: &Value) -> Vec<(String, Option<Value>)> {
+    let unit_variants = |schema: &Value| -> Vec<(String, Option<Value>)> {
+        schema
+            .get("enum")
+            .and_then(Value::as_array)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|variant| (variant.to_owned(), None))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let Some(one_of) = schema.get("oneOf").and_then(Value::as_array) else {
+        return unit_variants(schema);
+    };
+    one_of
+        .iter()
+        .flat_map(|variant| {
+            let payload = variant
+                .get("properties")
+                .and_then(Value::as_object)
+                .filter(|properties| properties.len() == 1)
+                .and_then(|properties| properties.iter().next());
+            match payload {
+                Some((name, payload)) => vec![(name.clone(), Some(payload.clone()))],
+                None => unit_variants(variant),
+            }
+        })
+        .collect()
+}
+
+/// Human readable type of a schema
+fn type_name(schema: &Value) -> String {
+    if let Some(name) = reference(schema) {
+        return name.to_owned();
+    }
+    if let Some(any_of) = schema.get("anyOf").and_then(Value::as_array) {
+        let types: Vec<String> = any_of
+            .iter()
+            .filter(|schema| schema.get("type").and_then(Value::as_str) != Some("null"))
+            .map(type_name)
+            .collect();
+        return if types.len() < any_of.len() {
+            format!("Option<{}>", types.join(" | "))
+        } else {
+            types.join(" | ")
+        };
+    }
+    match schema.get("type") {
+        Some(Value::String(ty)) => single_type_name(ty, schema),
+        Some(Value::Array(types)) => {
+            let types: Vec<&str> = types.iter().filter_map(Value::as_str).collect();
+            let non_null: Vec<String> = types
+                .iter()
+                .filter(|ty| **ty != "null")
+                .map(|ty| single_type_name(ty, schema))
+                .collect();
+            if non_null.len() < types.len() {
+                format!("Option<{}>", non_null.join(" | "))
+            } else {
+                non_null.join(" | ")
+            }
+        }
+        _ => "any".to_owned(),
+    }
+}
+
+fn single_type_name(ty: &str, schema: &Value) -> String {
+    match ty {
+        "array" => match schema.get("items") {
+            Some(Value::Array(items)) => format!(
+                "({})",
+                items.iter().map(type_name).collect::<Vec<_>>().join(", ")
+            ),
+            Some(items) => format!("Vec<{}>", type_name(items)),
+            None => "Vec<any>".to_owned(),
+        },
+        "integer" => schema
+            .get("format")
+            .and_then(Value::as_str)
+            .unwrap_or("integer")
+            .to_owned(),
+        ty => ty.to_owned(),
+    }
+}
+
+struct ConstDeclaration {
+    /// Path of the constant in the source file
+    name: String,
+    ty: String,
+    value: String,
+}
+
+/// Collect the constant declarations of a source file, tracking the inline modules they are declared in.
+fn const_declarations(source: &str) -> Vec<ConstDeclaration> {
+    let mut declarations = vec![];
+    let mut modules: Vec<(String, i64)> = vec![];
+    let mut depth = 0i64;
+    let mut statement: Option<String> = None;
+
+    for line in source.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if statement.is_none() {
+            let item = strip_visibility(line);
+            if let Some(module) = item.strip_prefix("mod ") {
+                if let Some(module) = module.strip_suffix('{') {
+                    modules.push((module.trim().to_owned(), depth + 1));
+                }
+            } else if item.starts_with("const ") && !item.starts_with("const fn") {
+                statement = Some(String::new());
+            }
+        }
+        if let Some(current) = statement.as_mut() {
+            current.push(' ');
+            current.push_str(line);
+            if line.ends_with(';') {
+                let module_path = modules
+                    .iter()
+                    .map(|(module, _)| module.as_str())
+                    .collect::<Vec<_>>();
+                declarations.extend(parse_const(current, &module_path));
+                statement = None;
+            }
+        }
+
+        depth += line.matches('{').count() as i64 - line.matches('}').count() as i64;
+        while modules
+            .last()
+            .is_some_and(|(_, module_depth)| *module_depth > depth)
+        {
+            modules.pop();
+        }
+    }
+    declarations
+}
+
+fn strip_visibility(item: &str) -> &str {
+    let Some(item) = item.strip_prefix("pub") else {
+        return item;
+    };
+    match item.strip_prefix('(') {
+        Some(restricted) => restricted
+            .split_once(')')
+            .map_or(item, |(_, item)| item)
+            .trim_start(),
+        None => item.trim_start(),
+    }
+}
+
+/// Parse `const NAME: Type = value;`
+fn parse_const(statement: &str, module_path: &[&str]) -> Option<ConstDeclaration> {
+    let statement = statement.split_whitespace().collect::<Vec<_>>().join(" ");
+    let declaration = strip_visibility(&statement).strip_prefix("const ")?;
+    let (name, rest) = declaration.split_once(':')?;
+    let (ty, value) = rest.split_once('=')?;
+    let value = value.trim().trim_end_matches(';').trim();
+    let name = module_path
+        .iter()
+        .copied()
+        .chain(std::iter::once(name.trim()))
+        .collect::<Vec<_>>()
+        .join("::");
+    Some(ConstDeclaration {
+        name,
+        ty: ty.trim().to_owned(),
+        value: value.replace("( ", "(").replace(" )", ")"),
+    })
+}
+
+fn is_string_literal(value: &str) -> bool {
+    value.len() >= 2 && value.starts_with('"') && value.ends_with('"')
+}
+
+/// Resolve a path like `storage_namespaces::account::INFO` to the namespace constant it references
+fn resolve_namespace(namespaces: &HashMap<String, String>, path: &str) -> Option<String> {
+    let segments: Vec<&str> = path.split("::").collect();
+    (0..segments.len()).find_map(|start| namespaces.get(&segments[start..].join("::")).cloned())
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn release(execute: Value) -> ModuleRelease {
+        ModuleRelease {
+            name: "tester".to_owned(),
+            version: "0.1.0".to_owned(),
+            api: json!({ "idl_version": "1.0.0", "execute": execute, "query": null }),
+            storage: None,
+        }
+    }
+
+    fn execute_msg(variants: Value, definitions: Value) -> Value {
+        json!({ "title": "ExecuteMsg", "oneOf": variants, "definitions": definitions })
+    }
+
+    fn struct_variant(name: &str, properties: Value, required: Value) -> Value {
+        json!({
+            "type": "object",
+            "required": [name],
+            "properties": {
+                name: { "type": "object", "required": required, "properties": properties }
+            }
+        })
+    }
+
+    fn previous_release() -> ModuleRelease {
+        release(execute_msg(
+            json!([
+                struct_variant(
+                    "update_config",
+                    json!({
+                        "admin": { "type": ["string", "null"] },
+                        "fee": { "$ref": "#/definitions/Uint128" }
+                    }),
+                    json!(["fee"])
+                ),
+                { "type": "string", "enum": ["claim", "reset"] }
+            ]),
+            json!({ "Uint128": { "type": "string" } }),
+        ))
+    }
+
+    #[coverage_helper::test]
+    fn identical_releases_are_safe() {
+        let previous = previous_release();
+        assert!(previous.compare(&previous).is_safe());
+    }
+
+    #[coverage_helper::test]
+    fn adding_variants_and_optional_fields_is_safe() {
+        let next = release(execute_msg(
+            json!([
+                struct_variant(
+                    "update_config",
+                    json!({
+                        "admin": { "type": ["string", "null"] },
+                        "fee": { "$ref": "#/definitions/Uint128" },
+                        "memo": { "type": ["string", "null"] }
+                    }),
+                    json!(["fee"])
+                ),
+                { "type": "string", "enum": ["claim", "reset", "pause"] }
+            ]),
+            json!({ "Uint128": { "type": "string" } }),
+        ));
+        assert_eq!(previous_release().compare(&next), UpgradeReport::default());
+    }
+
+    fn breaking_release() -> ModuleRelease {
+        release(execute_msg(
+            json!([
+                struct_variant(
+                    "update_config",
+                    json!({
+                        "fee": { "type": "integer", "format": "uint64" },
+                        "owner": { "type": "string" }
+                    }),
+                    json!(["fee", "owner"])
+                ),
+                { "type": "string", "enum": ["claim"] }
+            ]),
+            json!({}),
+        ))
+    }
+
+    #[coverage_helper::test]
+    fn flags_breaking_message_changes() {
+        assert_eq!(
+            previous_release().compare(&breaking_release()).issues,
+            vec![
+                UpgradeIssue::RemovedField {
+                    location: "execute.update_config".to_owned(),
+                    field: "admin".to_owned(),
+                },
+                UpgradeIssue::ChangedFieldType {
+                    location: "execute.update_config".to_owned(),
+                    field: "fee".to_owned(),
+                    previous: "Uint128".to_owned(),
+                    current: "uint64".to_owned(),
+                },
+                UpgradeIssue::NewRequiredField {
+                    location: "execute.update_config".to_owned(),
+                    field: "owner".to_owned(),
+                },
+                UpgradeIssue::RemovedVariant {
+                    location: "execute".to_owned(),
+                    variant: "reset".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[coverage_helper::test]
+    fn parses_storage_declarations() {
+        let namespaces = r#"
+            pub const CONFIG_STORAGE_KEY: &str = "cfg";
+            pub mod tester {
+                pub const USERS: &str = "ta";
+            }
+        "#;
+        let state = r#"
+            pub mod state {
+                use super::*;
+
+                pub const CONFIG: Item<Config> = Item::new(storage_namespaces::CONFIG_STORAGE_KEY);
+                pub const USERS: Map<&Addr, User> =
+                    Map::new(storage_namespaces::tester::USERS);
+                // pub const OLD: Item<u64> = Item::new("old");
+            }
+            const COUNT: Item<u64> = Item::new("count");
+        "#;
+
+        let layout = StorageLayout::parse([namespaces, state]);
+        assert_eq!(
+            layout.entries,
+            vec![
+                StorageEntry {
+                    key: "cfg".to_owned(),
+                    name: "state::CONFIG".to_owned(),
+                    ty: "Item<Config>".to_owned(),
+                },
+                StorageEntry {
+                    key: "ta".to_owned(),
+                    name: "state::USERS".to_owned(),
+                    ty: "Map<&Addr, User>".to_owned(),
+                },
+                StorageEntry {
+                    key: "count".to_owned(),
+                    name: "COUNT".to_owned(),
+                    ty: "Item<u64>".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[coverage_helper::test]
+    fn flags_reused_storage_keys() {
+        let mut previous = previous_release();
+        previous.storage = Some(StorageLayout::parse([r#"
+            const CONFIG: Item<Config> = Item::new("cfg");
+            const COUNT: Item<u64> = Item::new("count");
+        "#]));
+        let mut next = previous.clone();
+        next.storage = Some(StorageLayout::parse([r#"
+            const CONFIG: Item<Config> = Item::new("cfg");
+            const ADMIN: Item<Addr> = Item::new("count");
+            const USERS: Map<&Addr, User> = Map::new("users");
+            const BALANCES: Map<&Addr, Uint128> = Map::new("users");
+        "#]));
+
+        assert_eq!(
+            previous.compare(&next).issues,
+            vec![
+                UpgradeIssue::ReusedStorageKey {
+                    key: "count".to_owned(),
+                    previous: "COUNT: Item<u64>".to_owned(),
+                    current: "ADMIN: Item<Addr>".to_owned(),
+                },
+                UpgradeIssue::DuplicateStorageKey {
+                    key: "users".to_owned(),
+                    declarations: vec!["USERS".to_owned(), "BALANCES".to_owned()],
+                },
+            ]
+        );
+    }
+
+    #[coverage_helper::test]
+    fn repository_release_is_compatible_with_itself() {
+        let schema_dir = Path::new(REPOSITORY_SCHEMA_DIR);
+        let release = ModuleRelease::load(
+            schema_dir,
+            "registry",
+            ::registry::contract::CONTRACT_VERSION,
+        )
+        .unwrap();
+
+        assert!(release.api.get("execute").is_some());
+        assert!(release.compare(&release).is_safe());
+    }
+
+    /// Source files declaring the storage of the framework contracts
+    fn framework_storage_sources() -> Vec<(&'static str, Vec<PathBuf>)> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let std_source = |file: &str| root.join("packages/abstract-std/src").join(file);
+        let with_namespaces = |mut sources: Vec<PathBuf>| {
+            sources.push(std_source("objects/storage_namespaces.rs"));
+            sources
+        };
+        vec![
+            (
+                "account",
+                with_namespaces(vec![
+                    std_source("account.rs"),
+                    root.join("contracts/account/src/modules.rs"),
+                    root.join("contracts/account/src/modules/migration.rs"),
+                ]),
+            ),
+            (
+                "ans-host",
+                with_namespaces(vec![std_source("native/ans_host.rs")]),
+            ),
+            (
+                "ibc-client",
+                with_namespaces(vec![std_source("native/ibc/ibc_client.rs")]),
+            ),
+            (
+                "ibc-host",
+                with_namespaces(vec![std_source("native/ibc/ibc_host.rs")]),
+            ),
+            (
+                "module-factory",
+                with_namespaces(vec![std_source("native/module_factory.rs")]),
+            ),
+            (
+                "registry",
+                with_namespaces(vec![std_source("native/registry.rs")]),
+            ),
+        ]
+    }
+
+    #[coverage_helper::test]
+    fn framework_storage_matches_release_layout() {
+        let version = ::registry::contract::CONTRACT_VERSION;
+        for (module, sources) in framework_storage_sources() {
+            let release_dir = Path::new(REPOSITORY_SCHEMA_DIR).join(module).join(version);
+            assert!(
+                StorageLayout::load(&release_dir).unwrap().is_some(),
+                "missing storage layout of {module} {version}"
+            );
+
+            // Reusing or duplicating a key of the released layout fails the check
+            let report = UpgradeCheck::default()
+                .with_storage_sources(module, sources)
+                .check(module, version, version)
+                .unwrap();
+            assert!(report.is_safe(), "{module} {version}:\n{report}");
+        }
+    }
+
+    #[coverage_helper::test]
+    fn refuses_unsafe_upgrade_without_override() {
+        let schema_dir = std::env::temp_dir().join("abstract-upgrade-check");
+        for (version, release) in [("0.1.0", previous_release()), ("0.2.0", breaking_release())] {
+            let release_dir = schema_dir.join("tester").join(version);
+            fs::create_dir_all(&release_dir).unwrap();
+            fs::write(release_dir.join("tester.json"), release.api.to_string()).unwrap();
+        }
+
+        let check = UpgradeCheck::new(&schema_dir);
+        assert!(matches!(
+            check.ensure_safe("tester", "0.1.0", "0.2.0"),
+            Err(AbstractInterfaceError::UnsafeUpgrade { .. })
+        ));
+
+        let check = check.allow_unsafe(true);
+        assert!(!check
+            .ensure_safe("tester", "0.1.0", "0.2.0")
+            .unwrap()
+            .is_safe());
+    }
+
+    #[coverage_helper::test]
+    fn skips_missing_release_schema() {
+        // There is no schema for the previous version
+        let check = UpgradeCheck::default();
+        let version = ::registry::contract::CONTRACT_VERSION;
+        assert!(matches!(
+            check.check("registry", "0.0.1", version),
+            Err(AbstractInterfaceError::ReleaseSchema { .. })
+        ));
+        assert_eq!(
+            check.ensure_safe("registry", "0.0.1", version).unwrap(),
+            UpgradeReport::default()
+        );
+    }
+}
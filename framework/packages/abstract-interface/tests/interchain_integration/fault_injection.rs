@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod test {
+    use crate::interchain_integration::{
+        ibc_abstract_setup, interchain_accounts::create_test_remote_account, logger_test_init,
+        JUNO, STARGAZE,
+    };
+    use abstract_interface::{
+        fault_injection::IbcFaultInjector, Abstract, AbstractInterfaceError, AccountI,
+        AccountQueryFns,
+    };
+    use abstract_std::{
+        account::ExecuteMsg as AccountExecuteMsg,
+        ibc_client::QueryMsgFns,
+        objects::{gov_type::GovernanceDetails, TruncatedChainId},
+    };
+    use cw_orch::{anyhow::Result as AnyResult, environment::Environment, prelude::*};
+    use cw_orch_interchain::prelude::*;
+
+    fn local_account(abstr: &Abstract<MockBech32>) -> AnyResult<AccountI<MockBech32>> {
+        let account = AccountI::create_default_account(
+            abstr,
+            GovernanceDetails::Monarchy {
+                monarch: abstr.registry.environment().sender_addr().to_string(),
+            },
+        )?;
+        account.set_ibc_status(true)?;
+        Ok(account)
+    }
+
+    fn update_name(name: &str) -> AccountExecuteMsg {
+        AccountExecuteMsg::UpdateInfo {
+            name: Some(name.to_string()),
+            description: None,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn remote_account_registration_timeout() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain =
+            MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+        let (abstr_origin, abstr_remote) = ibc_abstract_setup(&mock_interchain, JUNO, STARGAZE)?;
+        let faults = IbcFaultInjector::new(&mock_interchain, &abstr_origin, &abstr_remote);
+
+        let account = local_account(&abstr_origin)?;
+        let register_tx =
+            account.register_remote_account(TruncatedChainId::from_chain_id(STARGAZE))?;
+        faults.timeout(register_tx)?;
+
+        // The remote account address is not stored
+        let remote_account = abstr_origin
+            .ibc
+            .client
+            .remote_account(account.id()?, TruncatedChainId::from_chain_id(STARGAZE))?;
+        assert_eq!(remote_account.remote_account_addr, None);
+        Ok(())
+    }
+
+    #[test]
+    fn remote_account_registration_error_ack() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain =
+            MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+        let (abstr_origin, abstr_remote) = ibc_abstract_setup(&mock_interchain, JUNO, STARGAZE)?;
+        let faults = IbcFaultInjector::new(&mock_interchain, &abstr_origin, &abstr_remote);
+
+        let account = local_account(&abstr_origin)?;
+        let register_tx =
+            account.register_remote_account(TruncatedChainId::from_chain_id(STARGAZE))?;
+        faults.error_ack(register_tx)?;
+
+        let remote_account = abstr_origin
+            .ibc
+            .client
+            .remote_account(account.id()?, TruncatedChainId::from_chain_id(STARGAZE))?;
+        assert_eq!(remote_account.remote_account_addr, None);
+
+        // The connection is restored, so the registration can be retried
+        let register_tx =
+            account.register_remote_account(TruncatedChainId::from_chain_id(STARGAZE))?;
+        mock_interchain.await_and_check_packets(JUNO, register_tx)?;
+        let remote_account = abstr_origin
+            .ibc
+            .client
+            .remote_account(account.id()?, TruncatedChainId::from_chain_id(STARGAZE))?;
+        assert!(remote_account.remote_account_addr.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn error_ack_not_injected() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain =
+            MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+        let (abstr_origin, abstr_remote) = ibc_abstract_setup(&mock_interchain, JUNO, STARGAZE)?;
+        let faults = IbcFaultInjector::new(&mock_interchain, &abstr_origin, &abstr_remote);
+
+        // A local action sends no packets, so no error acknowledgement can be produced
+        let account = local_account(&abstr_origin)?;
+        let update_tx = account.execute(&update_name("local"), &[])?;
+        let err = faults.error_ack(update_tx).unwrap_err();
+        assert!(matches!(err, AbstractInterfaceError::FaultNotInjected(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn remote_actions_out_of_order() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain =
+            MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+        let (abstr_origin, abstr_remote) = ibc_abstract_setup(&mock_interchain, JUNO, STARGAZE)?;
+        let faults = IbcFaultInjector::new(&mock_interchain, &abstr_origin, &abstr_remote);
+
+        let (origin_account, remote_account_id) =
+            create_test_remote_account(&abstr_origin, JUNO, STARGAZE, &mock_interchain, vec![])?;
+        let host_chain = TruncatedChainId::from_chain_id(STARGAZE);
+
+        let first_tx =
+            origin_account.execute_on_remote(host_chain.clone(), update_name("first"))?;
+        let second_tx = origin_account.execute_on_remote(host_chain, update_name("second"))?;
+        faults.reversed(vec![first_tx, second_tx])?;
+
+        // The first action arrived last
+        let remote_account = AccountI::load_from(&abstr_remote, remote_account_id)?;
+        assert_eq!(remote_account.info()?.info.name, Some("first".to_string()));
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod test {
+    use crate::interchain_integration::{ibc_abstract_setup, logger_test_init, JUNO, STARGAZE};
+    use abstract_interface::{AccountI, GasBenchmark};
+    use abstract_std::{
+        account::ExecuteMsg as AccountExecuteMsg,
+        objects::{gov_type::GovernanceDetails, TruncatedChainId},
+    };
+    use cw_orch::{anyhow::Result as AnyResult, prelude::*};
+    use cw_orch_interchain::prelude::*;
+
+    /// Cost of IBC dispatch on the origin chain, relaying is not included.
+    #[test]
+    fn ibc_dispatch_gas() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain =
+            MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+        let (abstr_origin, _abstr_remote) = ibc_abstract_setup(&mock_interchain, JUNO, STARGAZE)?;
+        let origin_chain = mock_interchain.get_chain(JUNO)?;
+        let host_chain = TruncatedChainId::from_chain_id(STARGAZE);
+
+        let account = AccountI::create_default_account(
+            &abstr_origin,
+            GovernanceDetails::Monarchy {
+                monarch: origin_chain.sender_addr().to_string(),
+            },
+        )?;
+        account.set_ibc_status(true)?;
+
+        let mut benchmark = GasBenchmark::new("ibc", env!("CARGO_PKG_VERSION"));
+        let mut register_tx = None;
+        benchmark.record(&origin_chain, "register_remote_account", || {
+            let response = account.register_remote_account(host_chain.clone())?;
+            register_tx = Some(response.clone());
+            Ok(response)
+        })?;
+        mock_interchain.await_and_check_packets(JUNO, register_tx.unwrap())?;
+
+        let mut action_tx = None;
+        let cost = benchmark.record(&origin_chain, "execute_on_remote", || {
+            let response = account.execute_on_remote(
+                host_chain.clone(),
+                AccountExecuteMsg::UpdateInfo {
+                    name: Some("remote".to_string()),
+                    description: None,
+                    link: None,
+                },
+            )?;
+            action_tx = Some(response.clone());
+            Ok(response)
+        })?;
+        mock_interchain.await_and_check_packets(JUNO, action_tx.unwrap())?;
+
+        // account -> ibc-client -> polytone note
+        assert!(cost.contract_calls >= 3);
+        if let Ok(dir) = std::env::var("GAS_REPORT_DIR") {
+            benchmark.write(dir)?;
+        }
+        Ok(())
+    }
+}
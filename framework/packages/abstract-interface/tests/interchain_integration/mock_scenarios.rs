@@ -0,0 +1,126 @@
+//! Offline equivalents of the Starship scenarios, built on the mock interchain environment.
+//! The ibc-hooks lifecycle scenarios are covered by the ibc-client `token_transfers` tests.
+#[cfg(test)]
+mod test {
+    use crate::interchain_integration::{
+        interchain_accounts::create_test_remote_account, logger_test_init, JUNO, OSMOSIS, STARGAZE,
+    };
+    use abstract_interface::{Abstract, AccountI, AccountQueryFns};
+    use abstract_sdk::PfmMemoBuilder;
+    use abstract_std::{
+        account::ExecuteMsg as AccountExecuteMsg, ibc_client::QueryMsgFns,
+        objects::TruncatedChainId, IBC_CLIENT,
+    };
+    use cosmwasm_std::{coins, Uint128};
+    use cw_orch::{anyhow::Result as AnyResult, prelude::*};
+    use cw_orch_interchain::prelude::*;
+
+    const NEW_NAME: &str = "Renamed over IBC";
+
+    #[test]
+    fn deploy_and_connect_all() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain = MockBech32InterchainEnv::new(vec![
+            (JUNO, "juno"),
+            (STARGAZE, "stargaze"),
+            (OSMOSIS, "osmosis"),
+        ]);
+
+        let deployments =
+            Abstract::deploy_and_connect_all(&mock_interchain, &[JUNO, STARGAZE, OSMOSIS])?;
+        assert_eq!(deployments.len(), 3);
+
+        // Every deployment knows the hosts of the other chains
+        for abstr in &deployments {
+            let hosts = abstr.ibc.client.list_remote_hosts()?.hosts;
+            assert_eq!(hosts.len(), 2);
+        }
+
+        // Remote accounts can be created in any direction
+        create_test_remote_account(&deployments[2], OSMOSIS, JUNO, &mock_interchain, vec![])?;
+        create_test_remote_account(&deployments[1], STARGAZE, OSMOSIS, &mock_interchain, vec![])?;
+        Ok(())
+    }
+
+    #[test]
+    fn ics20_with_pfm_memo() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain = MockBech32InterchainEnv::new(vec![
+            (JUNO, "juno"),
+            (STARGAZE, "stargaze"),
+            (OSMOSIS, "osmosis"),
+        ]);
+        let juno = mock_interchain.get_chain(JUNO)?;
+        let osmosis = mock_interchain.get_chain(OSMOSIS)?;
+
+        let deployments =
+            Abstract::deploy_and_connect_all(&mock_interchain, &[JUNO, STARGAZE, OSMOSIS])?;
+        let [abstr_juno, abstr_stargaze, abstr_osmosis] = &deployments[..] else {
+            unreachable!()
+        };
+        // JUNO>STARGAZE>OSMOSIS
+        abstr_juno.create_ics20_channel(abstr_stargaze, &mock_interchain)?;
+        let (stargaze_osmosis_channel, _) =
+            abstr_stargaze.create_ics20_channel(abstr_osmosis, &mock_interchain)?;
+
+        let (origin_account, _) =
+            create_test_remote_account(abstr_juno, JUNO, STARGAZE, &mock_interchain, vec![])?;
+        juno.add_balance(&origin_account.address()?, coins(100, "ujuno"))?;
+
+        let receiver = osmosis.addr_make("pfm-receiver");
+        let memo = PfmMemoBuilder::new(stargaze_osmosis_channel).build(receiver.to_string())?;
+        let send_funds_tx = origin_account.execute_on_module(
+            IBC_CLIENT,
+            abstract_std::ibc_client::ExecuteMsg::SendFunds {
+                host_chain: TruncatedChainId::from_chain_id(STARGAZE),
+                memo: Some(memo),
+                receiver: None,
+            },
+            coins(100, "ujuno"),
+        )?;
+        mock_interchain.await_and_check_packets(JUNO, send_funds_tx)?;
+
+        // The funds were forwarded to the final receiver
+        let receiver_balance = osmosis.balance(&receiver, None)?;
+        assert_eq!(receiver_balance.len(), 1);
+        assert_eq!(receiver_balance[0].amount, Uint128::new(100));
+        Ok(())
+    }
+
+    #[test]
+    fn polytone_timeout() -> AnyResult<()> {
+        logger_test_init();
+        let mock_interchain =
+            MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+        let stargaze = mock_interchain.get_chain(STARGAZE)?;
+
+        let deployments = Abstract::deploy_and_connect_all(&mock_interchain, &[JUNO, STARGAZE])?;
+        let [abstr_juno, abstr_stargaze] = &deployments[..] else {
+            unreachable!()
+        };
+
+        let (origin_account, remote_account_id) =
+            create_test_remote_account(abstr_juno, JUNO, STARGAZE, &mock_interchain, vec![])?;
+
+        let ibc_action_result = origin_account.execute_on_remote(
+            TruncatedChainId::from_chain_id(STARGAZE),
+            AccountExecuteMsg::UpdateInfo {
+                name: Some(NEW_NAME.to_string()),
+                description: None,
+                link: None,
+            },
+        )?;
+
+        // Trigger timeout
+        stargaze.wait_seconds(60 * 60 * 24)?;
+        mock_interchain
+            .await_packets(JUNO, ibc_action_result)?
+            .assert()
+            .unwrap_err();
+
+        // The action never reached the remote account
+        let remote_account = AccountI::load_from(abstr_stargaze, remote_account_id)?;
+        assert_ne!(remote_account.info()?.info.name, Some(NEW_NAME.to_string()));
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+pub mod fault_injection;
+pub mod gas;
 pub mod interchain_accounts;
+pub mod mock_scenarios;
 pub mod module_to_module_interactions;
 
 use abstract_interface::Abstract;
@@ -167,6 +167,7 @@ pub const fn mock_app(id: &'static str, version: &'static str) -> MockAppContrac
                         .unwrap(),
                         callback: Some(Callback {
                             msg: to_json_binary(&MockCallbackMsg::ModuleExecute)?,
+                            callback_id: None,
                         }),
                     },
                     vec![],
@@ -186,6 +187,7 @@ pub const fn mock_app(id: &'static str, version: &'static str) -> MockAppContrac
                         host_chain: remote_chain,
                         callback: Callback {
                             msg: to_json_binary(&MockCallbackMsg::BalanceQuery)?,
+                            callback_id: None,
                         },
                         queries: vec![cosmwasm_std::QueryRequest::Bank(
                             cosmwasm_std::BankQuery::AllBalances { address },
@@ -213,6 +215,7 @@ pub const fn mock_app(id: &'static str, version: &'static str) -> MockAppContrac
                     &QueryMsg::from(MockQueryMsg::Foo {}),
                     Callback {
                         msg: to_json_binary(&MockCallbackMsg::ModuleQuery)?,
+                        callback_id: None,
                     },
                 )?;
 
@@ -1,7 +1,15 @@
 //! # AnsHost Entry
 //! An entry (value) in the ans_host key-value store.
 
-use abstract_std::objects::{ans_host::AnsHostResult, AnsEntryConvertor};
+use std::collections::BTreeMap;
+
+use abstract_std::{
+    ans_host::{AnsEntries, BatchEntriesResponse},
+    objects::{
+        ans_host::{AnsHostError, AnsHostResult},
+        AnsEntryConvertor,
+    },
+};
 use cosmwasm_std::{Addr, QuerierWrapper};
 use cw_asset::{Asset, AssetInfo};
 
@@ -125,6 +133,224 @@ where
     }
 }
 
+/// Memoized ANS values, filled by [`AnsHost::query_entries`] batch queries.
+/// Entries that were queried but are not registered are stored as `None` so they aren't queried again.
+#[derive(Debug, Default, Clone)]
+pub struct AnsCache {
+    assets: BTreeMap<AssetEntry, Option<AssetInfo>>,
+    contracts: BTreeMap<ContractEntry, Option<Addr>>,
+    channels: BTreeMap<ChannelEntry, Option<String>>,
+    pairings: BTreeMap<DexAssetPairing, Option<Vec<PoolReference>>>,
+    pool_metadatas: BTreeMap<UniquePoolId, Option<PoolMetadata>>,
+}
+
+impl AnsCache {
+    /// Store the response of a batch query for the requested entries.
+    pub fn insert_batch(&mut self, requested: AnsEntries, response: BatchEntriesResponse) {
+        let AnsEntries {
+            assets,
+            contracts,
+            channels,
+            pairings,
+            pool_ids,
+        } = requested;
+        self.assets.extend(assets.into_iter().map(|k| (k, None)));
+        self.contracts
+            .extend(contracts.into_iter().map(|k| (k, None)));
+        self.channels
+            .extend(channels.into_iter().map(|k| (k, None)));
+        self.pairings
+            .extend(pairings.into_iter().map(|k| (k, None)));
+        self.pool_metadatas
+            .extend(pool_ids.into_iter().map(|k| (k, None)));
+
+        self.assets
+            .extend(response.assets.into_iter().map(|(k, v)| (k, Some(v))));
+        self.contracts
+            .extend(response.contracts.into_iter().map(|(k, v)| (k, Some(v))));
+        self.channels
+            .extend(response.channels.into_iter().map(|(k, v)| (k, Some(v))));
+        self.pairings
+            .extend(response.pairings.into_iter().map(|(k, v)| (k, Some(v))));
+        self.pool_metadatas.extend(
+            response
+                .pool_metadatas
+                .into_iter()
+                .map(|(k, v)| (k, Some(v))),
+        );
+    }
+
+    fn asset(&self, entry: &AssetEntry, ans_host: &AnsHost) -> AnsHostResult<AssetInfo> {
+        self.assets
+            .get(entry)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| AnsHostError::AssetNotFound {
+                asset: entry.clone(),
+                ans_host: ans_host.address.clone(),
+            })
+    }
+}
+
+fn push_unique<T: PartialEq>(batch: &mut Vec<T>, entry: T) {
+    if !batch.contains(&entry) {
+        batch.push(entry);
+    }
+}
+
+/// Resolve an entry as part of a single batched ANS query.
+/// Used by [`AbstractNameServiceClient::resolve_batched`](crate::features::AbstractNameServiceClient::resolve_batched).
+pub trait BatchResolve: Resolve {
+    /// Add the entries that are required to resolve `self` and are not cached yet to the batch.
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries);
+    /// Resolve the entry from the cache. Entries missing from the cache are reported as not found.
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output>;
+}
+
+impl BatchResolve for AssetEntry {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        if !cache.assets.contains_key(self) {
+            push_unique(&mut batch.assets, self.clone());
+        }
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        cache.asset(self, ans_host)
+    }
+}
+
+impl BatchResolve for LpToken {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        AnsEntryConvertor::new(self.clone())
+            .asset_entry()
+            .add_to_batch(cache, batch)
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        cache.asset(
+            &AnsEntryConvertor::new(self.clone()).asset_entry(),
+            ans_host,
+        )
+    }
+}
+
+impl BatchResolve for ContractEntry {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        if !cache.contracts.contains_key(self) {
+            push_unique(&mut batch.contracts, self.clone());
+        }
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        cache
+            .contracts
+            .get(self)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| AnsHostError::ContractNotFound {
+                contract: self.clone(),
+                ans_host: ans_host.address.clone(),
+            })
+    }
+}
+
+impl BatchResolve for ChannelEntry {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        if !cache.channels.contains_key(self) {
+            push_unique(&mut batch.channels, self.clone());
+        }
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        cache
+            .channels
+            .get(self)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| AnsHostError::ChannelNotFound {
+                channel: self.clone(),
+                ans_host: ans_host.address.clone(),
+            })
+    }
+}
+
+impl BatchResolve for DexAssetPairing {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        if !cache.pairings.contains_key(self) {
+            push_unique(&mut batch.pairings, self.clone());
+        }
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        cache.pairings.get(self).cloned().flatten().ok_or_else(|| {
+            AnsHostError::DexPairingNotFound {
+                pairing: self.clone(),
+                ans_host: ans_host.address.clone(),
+            }
+        })
+    }
+}
+
+impl BatchResolve for UniquePoolId {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        if !cache.pool_metadatas.contains_key(self) {
+            push_unique(&mut batch.pool_ids, *self);
+        }
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        cache
+            .pool_metadatas
+            .get(self)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| AnsHostError::PoolMetadataNotFound {
+                pool: *self,
+                ans_host: ans_host.address.clone(),
+            })
+    }
+}
+
+impl BatchResolve for AnsAsset {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        self.name.add_to_batch(cache, batch)
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        Ok(Asset::new(cache.asset(&self.name, ans_host)?, self.amount))
+    }
+}
+
+impl BatchResolve for PoolMetadata {
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        self.assets.add_to_batch(cache, batch)
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        Ok(ResolvedPoolMetadata {
+            assets: self.assets.resolve_cached(cache, ans_host)?,
+            dex: self.dex.clone(),
+            pool_type: self.pool_type,
+        })
+    }
+}
+
+impl<T> BatchResolve for Vec<T>
+where
+    T: BatchResolve,
+{
+    fn add_to_batch(&self, cache: &AnsCache, batch: &mut AnsEntries) {
+        self.iter()
+            .for_each(|entry| entry.add_to_batch(cache, batch))
+    }
+
+    fn resolve_cached(&self, cache: &AnsCache, ans_host: &AnsHost) -> AnsHostResult<Self::Output> {
+        self.iter()
+            .map(|entry| entry.resolve_cached(cache, ans_host))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -641,4 +867,132 @@ mod tests {
             assert_eq!(res, Ok(values));
         }
     }
+
+    mod batch_resolve {
+        use super::*;
+        use crate::{features::AbstractNameService, mock_module::MockModule};
+        use abstract_std::ans_host::QueryMsg;
+        use cosmwasm_std::{
+            from_json, testing::MockStorage, to_json_binary, Deps, Querier, QuerierResult,
+        };
+        use std::cell::Cell;
+
+        /// Counts the queries leaving the contract.
+        /// This doesn't measure gas: a smart query costs more than a raw query, so fewer queries aren't necessarily cheaper.
+        struct CountingQuerier {
+            inner: MockQuerier,
+            count: Cell<u32>,
+        }
+
+        impl Querier for CountingQuerier {
+            fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+                self.count.set(self.count.get() + 1);
+                self.inner.raw_query(bin_request)
+            }
+        }
+
+        fn counting_querier(
+            ans_host: &AnsHost,
+            registered: Vec<(AssetEntry, AssetInfo)>,
+        ) -> CountingQuerier {
+            let inner = MockQuerierBuilder::default()
+                .with_contract_map_entries(
+                    &ans_host.address,
+                    ASSET_ADDRESSES,
+                    registered.iter().map(|(k, v)| (k, v.clone())).collect(),
+                )
+                .with_smart_handler(&ans_host.address, move |msg| {
+                    match from_json(msg).unwrap() {
+                        QueryMsg::BatchEntries {
+                            entries: AnsEntries { assets, .. },
+                        } => to_json_binary(&BatchEntriesResponse {
+                            assets: registered
+                                .iter()
+                                .filter(|(k, _)| assets.contains(k))
+                                .cloned()
+                                .collect(),
+                            ..Default::default()
+                        })
+                        .map_err(|e| e.to_string()),
+                        _ => Err("unexpected query".into()),
+                    }
+                })
+                .build();
+            CountingQuerier {
+                inner,
+                count: Cell::new(0),
+            }
+        }
+
+        fn registered_assets(mock_api: MockApi) -> Vec<(AssetEntry, AssetInfo)> {
+            ["atom", "juno", "osmo", "stars", "usdc"]
+                .into_iter()
+                .map(|name| {
+                    (
+                        AssetEntry::new(name),
+                        AssetInfo::cw20(mock_api.addr_make(name)),
+                    )
+                })
+                .collect()
+        }
+
+        #[coverage_helper::test]
+        fn resolves_all_entries_in_one_query() {
+            let mock_api = MockApi::default();
+            let ans_host = mock_ans_host(mock_api);
+            let registered = registered_assets(mock_api);
+            let (keys, values): (Vec<_>, Vec<_>) = registered.clone().into_iter().unzip();
+
+            let storage = MockStorage::default();
+            let querier = counting_querier(&ans_host, registered);
+            let deps = Deps {
+                storage: &storage,
+                api: &mock_api,
+                querier: QuerierWrapper::new(&querier),
+            };
+            let module = MockModule::new(mock_api, test_account(mock_api));
+            let ans = module.name_service(deps);
+
+            // One raw query per entry
+            assert_eq!(ans.query(&keys).unwrap(), values);
+            let raw_queries = querier.count.replace(0);
+            assert_eq!(raw_queries, keys.len() as u32);
+
+            // One smart query for all entries
+            assert_eq!(ans.resolve_batched(&keys).unwrap(), values);
+            assert_eq!(querier.count.replace(0), 1);
+
+            // Cached entries are not queried again, also through other entry types
+            assert_eq!(ans.resolve_batched(&keys).unwrap(), values);
+            let resolved = ans.resolve_batched(&AnsAsset::new("juno", 10u128)).unwrap();
+            assert_eq!(resolved, Asset::new(values[1].clone(), 10u128));
+            assert_eq!(querier.count.get(), 0);
+        }
+
+        #[coverage_helper::test]
+        fn missing_entries_are_cached() {
+            let mock_api = MockApi::default();
+            let ans_host = mock_ans_host(mock_api);
+
+            let storage = MockStorage::default();
+            let querier = counting_querier(&ans_host, registered_assets(mock_api));
+            let deps = Deps {
+                storage: &storage,
+                api: &mock_api,
+                querier: QuerierWrapper::new(&querier),
+            };
+            let module = MockModule::new(mock_api, test_account(mock_api));
+            let ans = module.name_service(deps);
+
+            let entries = vec![AssetEntry::new("atom"), AssetEntry::new("missing")];
+            let res = ans.resolve_batched(&entries);
+            assert!(res.unwrap_err().to_string().contains("not found"));
+            assert_eq!(querier.count.replace(0), 1);
+
+            // The registered entry was cached alongside the missing one
+            assert!(ans.resolve_batched(&AssetEntry::new("atom")).is_ok());
+            assert!(ans.resolve_batched(&AssetEntry::new("missing")).is_err());
+            assert_eq!(querier.count.get(), 0);
+        }
+    }
 }
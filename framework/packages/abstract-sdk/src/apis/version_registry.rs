@@ -1,15 +1,17 @@
 use abstract_std::{
     objects::{
-        module::{Module, ModuleInfo},
+        module::{Module, ModuleInfo, ModuleStanding, ModuleVersion, Monetization},
         module_reference::ModuleReference,
         module_version::MODULE,
         namespace::Namespace,
         registry::RegistryContract,
+        storage_namespaces::MODULE_STANDING_CACHE,
         AccountId,
     },
     registry::{ModuleConfiguration, ModuleResponse, NamespaceResponse, NamespacesResponse},
 };
-use cosmwasm_std::{Addr, Deps};
+use cosmwasm_std::{Addr, BankMsg, BlockInfo, CosmosMsg, Deps, DepsMut, MessageInfo, Timestamp};
+use cw_storage_plus::Map;
 
 use super::AbstractApi;
 use crate::{
@@ -18,6 +20,11 @@ use crate::{
     AbstractSdkError, AbstractSdkResult,
 };
 
+/// Block time until which the standing of an Account on this module doesn't have to be queried again
+const STANDING_CACHE: Map<&AccountId, Timestamp> = Map::new(MODULE_STANDING_CACHE);
+/// Seconds before a standing without subscription is queried again
+const NOT_REQUIRED_STANDING_TTL: u64 = 24 * 60 * 60;
+
 /// Access the Abstract Registry and access module information.
 pub trait ModuleRegistryInterface: AbstractRegistryAccess + ModuleIdentification {
     /**
@@ -47,6 +54,67 @@ pub trait ModuleRegistryInterface: AbstractRegistryAccess + ModuleIdentification
             registry: vc,
         })
     }
+
+    /// Errors if the subscription of the Account to this module is suspended.
+    /// Usable standings are cached in the module's storage until they can change,
+    /// so the registry is only queried once per subscription period.
+    fn assert_module_standing(
+        &self,
+        deps: DepsMut,
+        account_id: &AccountId,
+        block: &BlockInfo,
+    ) -> AbstractSdkResult<()> {
+        if STANDING_CACHE
+            .may_load(deps.storage, account_id)?
+            .is_some_and(|valid_until| block.time < valid_until)
+        {
+            return Ok(());
+        }
+
+        let module_id = self.module_id();
+        let module = installed_module_info(deps.as_ref())?;
+        let standing = self
+            .module_registry(deps.as_ref())?
+            .query_module_standing(account_id, &module, block)?;
+        let valid_until = match standing {
+            ModuleStanding::Suspended { paid_until } => {
+                return Err(AbstractSdkError::ModuleSuspended {
+                    account_id: account_id.to_string(),
+                    module_id: module_id.to_owned(),
+                    paid_until,
+                })
+            }
+            // Subscriptions start active for a period, so this can't turn into a suspension right away
+            ModuleStanding::NotRequired {} => block.time.plus_seconds(NOT_REQUIRED_STANDING_TTL),
+            ModuleStanding::Active { paid_until } => paid_until,
+            // Can get suspended on any block
+            ModuleStanding::Lapsed { .. } => return Ok(()),
+        };
+        STANDING_CACHE.save(deps.storage, account_id, &valid_until)?;
+        Ok(())
+    }
+
+    /// Charges the usage fee of this module from the funds sent with the call, if it's monetized with one.
+    /// The fee is removed from `msg_info.funds`, so the rest of the call can't use it.
+    /// Returns the message that forwards the fee to the Account that owns the module's namespace.
+    fn charge_usage_fee(
+        &self,
+        deps: Deps,
+        msg_info: &mut MessageInfo,
+    ) -> AbstractSdkResult<Option<CosmosMsg>> {
+        let module = installed_module_info(deps)?;
+        self.module_registry(deps)?
+            .charge_usage_fee(module, msg_info)
+    }
+}
+
+/// Info of the module version that is running, as stored on instantiation and migration
+fn installed_module_info(deps: Deps) -> AbstractSdkResult<ModuleInfo> {
+    let module_data = MODULE.load(deps.storage)?;
+    Ok(ModuleInfo::from_id(
+        &module_data.module,
+        ModuleVersion::Version(module_data.version),
+    )?)
 }
 
 impl<T> ModuleRegistryInterface for T where T: AbstractRegistryAccess + ModuleIdentification {}
@@ -150,6 +218,50 @@ impl<T: ModuleRegistryInterface> ModuleRegistry<'_, T> {
             .map_err(|error| self.wrap_query_error(error))
     }
 
+    /// Raw query for the standing of an Account on a module version
+    pub fn query_module_standing(
+        &self,
+        account_id: &AccountId,
+        module_info: &ModuleInfo,
+        block: &BlockInfo,
+    ) -> AbstractSdkResult<ModuleStanding> {
+        self.registry
+            .query_module_standing_raw(account_id, module_info, block, &self.deps.querier)
+            .map_err(|error| self.wrap_query_error(error))
+    }
+
+    /// Charges the usage fee of a module from the sent funds, if it's monetized with one.
+    /// Returns the message that forwards the fee to the Account that owns the module's namespace.
+    pub fn charge_usage_fee(
+        &self,
+        module_info: ModuleInfo,
+        msg_info: &mut MessageInfo,
+    ) -> AbstractSdkResult<Option<CosmosMsg>> {
+        let Monetization::UsageFee(fee) = self.query_config(module_info.clone())?.monetization
+        else {
+            return Ok(None);
+        };
+        let fee = fee.charge(msg_info)?;
+        if fee.amount.is_zero() {
+            return Ok(None);
+        }
+        let NamespaceResponse::Claimed(namespace_info) =
+            self.query_namespace(module_info.namespace.clone())?
+        else {
+            return Err(AbstractSdkError::UnclaimedModuleNamespace {
+                namespace: module_info.namespace.to_string(),
+                module: module_info.id_with_version(),
+            });
+        };
+        Ok(Some(
+            BankMsg::Send {
+                to_address: namespace_info.account.addr().to_string(),
+                amount: vec![fee],
+            }
+            .into(),
+        ))
+    }
+
     /// Queries the module info of the standalone code id
     pub fn query_standalone_info_raw(&self, code_id: u64) -> AbstractSdkResult<ModuleInfo> {
         self.registry
@@ -221,15 +333,22 @@ mod test {
 
     use abstract_std::{
         objects::{
-            module::{ModuleId, Monetization},
+            fee::FixedFee,
+            module::{LapsePolicy, ModuleId, ModuleSubscription},
             module_version::ModuleData,
             namespace::ABSTRACT_NAMESPACE,
             ABSTRACT_ACCOUNT_ID,
         },
-        registry::ModulesResponse,
+        registry::{
+            state::{MODULE_CONFIG, MODULE_SUBSCRIPTIONS},
+            ModulesResponse,
+        },
     };
     use abstract_testing::prelude::*;
-    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{
+        coin,
+        testing::{mock_dependencies, mock_env},
+    };
 
     struct MockBinding {}
 
@@ -426,6 +545,152 @@ mod test {
         )
     }
 
+    fn save_module_data(storage: &mut dyn cosmwasm_std::Storage) {
+        MODULE
+            .save(
+                storage,
+                &ModuleData {
+                    module: TEST_MODULE_ID.to_owned(),
+                    version: TEST_VERSION.to_owned(),
+                    dependencies: vec![],
+                    metadata: None,
+                },
+            )
+            .unwrap();
+    }
+
+    fn subscription_config() -> ModuleConfiguration {
+        ModuleConfiguration::new(
+            Monetization::Subscription {
+                fee: FixedFee::new(&coin(10, "ujuno")),
+                period: 100,
+                lapse_policy: LapsePolicy::Suspend { grace_period: 0 },
+            },
+            None,
+            vec![],
+        )
+    }
+
+    #[coverage_helper::test]
+    fn module_standing_is_cached() {
+        let mut deps = mock_dependencies();
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let mut env = mock_env();
+        let paid_until = env.block.time.plus_seconds(100);
+        let subscription = |paid_until| ModuleSubscription {
+            paid_until,
+            lapse_policy: LapsePolicy::Suspend { grace_period: 0 },
+        };
+        let module = ModuleInfo::from_id(TEST_MODULE_ID, TEST_VERSION.parse().unwrap()).unwrap();
+        save_module_data(&mut deps.storage);
+
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .with_contract_map_entry(
+                &abstr.registry,
+                MODULE_CONFIG,
+                (&module, subscription_config()),
+            )
+            .with_contract_map_entry(
+                &abstr.registry,
+                MODULE_SUBSCRIPTIONS,
+                ((&TEST_ACCOUNT_ID, TEST_MODULE_ID), subscription(paid_until)),
+            )
+            .build();
+        let binding = MockBinding {};
+        binding
+            .assert_module_standing(deps.as_mut(), &TEST_ACCOUNT_ID, &env.block)
+            .unwrap();
+
+        // Registry is not queried again while the subscription is paid for
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .with_contract_map_entry(
+                &abstr.registry,
+                MODULE_CONFIG,
+                (&module, subscription_config()),
+            )
+            .with_contract_map_entry(
+                &abstr.registry,
+                MODULE_SUBSCRIPTIONS,
+                (
+                    (&TEST_ACCOUNT_ID, TEST_MODULE_ID),
+                    subscription(env.block.time),
+                ),
+            )
+            .build();
+        binding
+            .assert_module_standing(deps.as_mut(), &TEST_ACCOUNT_ID, &env.block)
+            .unwrap();
+
+        env.block.time = paid_until;
+        let res = binding.assert_module_standing(deps.as_mut(), &TEST_ACCOUNT_ID, &env.block);
+        assert_eq!(
+            res,
+            Err(AbstractSdkError::ModuleSuspended {
+                account_id: TEST_ACCOUNT_ID.to_string(),
+                module_id: TEST_MODULE_ID.to_owned(),
+                paid_until: mock_env().block.time,
+            })
+        );
+    }
+
+    #[coverage_helper::test]
+    fn module_standing_without_subscription() {
+        let mut deps = mock_dependencies();
+        deps.querier = abstract_mock_querier(deps.api);
+        save_module_data(&mut deps.storage);
+        let mut env = mock_env();
+
+        let binding = MockBinding {};
+        binding
+            .assert_module_standing(deps.as_mut(), &TEST_ACCOUNT_ID, &env.block)
+            .unwrap();
+        assert_eq!(
+            STANDING_CACHE
+                .load(&deps.storage, &TEST_ACCOUNT_ID)
+                .unwrap(),
+            env.block.time.plus_seconds(NOT_REQUIRED_STANDING_TTL)
+        );
+
+        env.block.time = env.block.time.plus_seconds(NOT_REQUIRED_STANDING_TTL);
+        binding
+            .assert_module_standing(deps.as_mut(), &TEST_ACCOUNT_ID, &env.block)
+            .unwrap();
+        assert_eq!(
+            STANDING_CACHE
+                .load(&deps.storage, &TEST_ACCOUNT_ID)
+                .unwrap(),
+            env.block.time.plus_seconds(NOT_REQUIRED_STANDING_TTL)
+        );
+    }
+
+    #[coverage_helper::test]
+    fn module_standing_without_subscription_record() {
+        let mut deps = mock_dependencies();
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let module = ModuleInfo::from_id(TEST_MODULE_ID, TEST_VERSION.parse().unwrap()).unwrap();
+        save_module_data(&mut deps.storage);
+        // E.g. upgraded to a version that requires a subscription
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .with_contract_map_entry(
+                &abstr.registry,
+                MODULE_CONFIG,
+                (&module, subscription_config()),
+            )
+            .build();
+
+        let binding = MockBinding {};
+        let res =
+            binding.assert_module_standing(deps.as_mut(), &TEST_ACCOUNT_ID, &mock_env().block);
+        assert_eq!(
+            res,
+            Err(AbstractSdkError::ModuleSuspended {
+                account_id: TEST_ACCOUNT_ID.to_string(),
+                module_id: TEST_MODULE_ID.to_owned(),
+                paid_until: Timestamp::from_seconds(0),
+            })
+        );
+    }
+
     #[coverage_helper::test]
     fn abstract_api() {
         let (deps, _, app) = mock_module_setup();
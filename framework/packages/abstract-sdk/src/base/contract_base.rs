@@ -1,6 +1,7 @@
 use abstract_std::ibc::{Callback, IbcResult, ModuleIbcInfo};
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, Storage};
+use cosmwasm_std::{from_json, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, Storage};
 use cw2::{ContractVersion, CONTRACT};
+use serde::de::DeserializeOwned;
 
 use super::handler::Handler;
 use crate::{std::objects::dependency::StaticDependency, AbstractSdkError, AbstractSdkResult};
@@ -36,6 +37,53 @@ pub type IbcCallbackHandlerFn<Module, Error> =
     fn(DepsMut, Env, Module, Callback, IbcResult) -> Result<Response, Error>;
 // ANCHOR_END: ibc
 
+// ANCHOR: ibc_id
+/// Function signature for an IBC callback handler registered for a callback id.
+/// The payload of the callback is deserialized into `Payload`.
+pub type IbcCallbackIdHandlerFn<Module, Payload, Error> =
+    fn(DepsMut, Env, Module, Payload, IbcResult) -> Result<Response, Error>;
+// ANCHOR_END: ibc_id
+
+/// IBC callback handler registered for a callback id, implemented by [`TypedIbcCallback`].
+pub trait IbcCallbackIdHandler<Module, Error> {
+    /// Deserialize the payload of the callback and call the handler with it.
+    fn handle(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        module: Module,
+        payload: &Binary,
+        result: IbcResult,
+    ) -> Result<Response, Error>;
+}
+
+/// IBC callback handler that receives the payload of the callback as `Payload`.
+pub struct TypedIbcCallback<Module, Payload, Error>(
+    pub IbcCallbackIdHandlerFn<Module, Payload, Error>,
+);
+
+impl<Module, Payload: DeserializeOwned, Error: From<AbstractSdkError>>
+    IbcCallbackIdHandler<Module, Error> for TypedIbcCallback<Module, Payload, Error>
+{
+    fn handle(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        module: Module,
+        payload: &Binary,
+        result: IbcResult,
+    ) -> Result<Response, Error> {
+        let payload = from_json(payload).map_err(AbstractSdkError::from)?;
+        (self.0)(deps, env, module, payload, result)
+    }
+}
+
+/// IBC callback handlers keyed by callback id.
+pub type IbcCallbackIdHandlers<Module, Error> = &'static [(
+    &'static str,
+    &'static dyn IbcCallbackIdHandler<Module, Error>,
+)];
+
 // ANCHOR: module_ibc
 /// Function signature for an Module to Module IBC handler.
 pub type ModuleIbcHandlerFn<Module, Error> =
@@ -48,6 +96,12 @@ pub type MigrateHandlerFn<Module, CustomMigrateMsg, Error> =
     fn(DepsMut, Env, Module, CustomMigrateMsg) -> Result<Response, Error>;
 // ANCHOR_END: mig
 
+// ANCHOR: mig_step
+/// Function signature for a migration step.
+/// The step runs when migrating from a version that matches its version requirement.
+pub type MigrationStepFn<Module, Error> = fn(DepsMut, &Env, &Module) -> Result<Response, Error>;
+// ANCHOR_END: mig_step
+
 // ANCHOR: sudo
 /// Function signature for a sudo handler.
 pub type SudoHandlerFn<Module, CustomSudoMsg, Error> =
@@ -82,12 +136,16 @@ pub struct AbstractContract<Module: Handler + 'static, Error: From<AbstractSdkEr
     /// Handler for migrations.
     pub(crate) migrate_handler:
         Option<MigrateHandlerFn<Module, <Module as Handler>::CustomMigrateMsg, Error>>,
+    /// Ordered migration steps keyed by the version requirement of the migrated-from version.
+    pub(crate) migration_steps: &'static [(&'static str, MigrationStepFn<Module, Error>)],
     /// Handler for sudo messages.
     pub(crate) sudo_handler: Option<SudoHandlerFn<Module, <Module as Handler>::SudoMsg, Error>>,
     /// List of reply handlers per reply ID.
     pub reply_handlers: [&'static [(u64, ReplyHandlerFn<Module, Error>)]; MAX_REPLY_COUNT],
     /// IBC callback handler following an IBC action
     pub(crate) ibc_callback_handler: Option<IbcCallbackHandlerFn<Module, Error>>,
+    /// IBC callback handlers per callback ID.
+    pub(crate) ibc_callback_handlers: IbcCallbackIdHandlers<Module, Error>,
     /// Module IBC handler for passing messages between a module on different chains.
     pub(crate) module_ibc_handler: Option<ModuleIbcHandlerFn<Module, Error>>,
 }
@@ -101,10 +159,12 @@ where
         Self {
             info: (name, version, metadata),
             ibc_callback_handler: None,
+            ibc_callback_handlers: &[],
             reply_handlers: [&[], &[]],
             dependencies: &[],
             execute_handler: None,
             migrate_handler: None,
+            migration_steps: &[],
             sudo_handler: None,
             instantiate_handler: None,
             query_handler: None,
@@ -142,6 +202,16 @@ where
         self
     }
 
+    /// Add IBC callback handlers per callback ID to the contract.
+    /// Callbacks created with [`Callback::with_id`] are routed to the handler of their ID.
+    pub const fn with_ibc_callbacks(
+        mut self,
+        callback_handlers: IbcCallbackIdHandlers<Module, Error>,
+    ) -> Self {
+        self.ibc_callback_handlers = callback_handlers;
+        self
+    }
+
     /// add IBC callback handler to contract
     pub const fn with_module_ibc(
         mut self,
@@ -173,6 +243,17 @@ where
         self
     }
 
+    /// Add ordered migration steps to the contract.
+    /// Each step is keyed by a semver requirement, like `"<0.3.0"`, and runs on migration when
+    /// the stored version matches the requirement and the new version does not.
+    pub const fn with_migrations(
+        mut self,
+        migration_steps: &'static [(&'static str, MigrationStepFn<Module, Error>)],
+    ) -> Self {
+        self.migration_steps = migration_steps;
+        self
+    }
+
     /// Add sudo handler to the contract.
     pub const fn with_sudo(
         mut self,
@@ -269,10 +350,12 @@ mod test {
 
         assert!(contract.dependencies.is_empty());
         assert!(contract.ibc_callback_handler.is_none());
+        assert!(contract.ibc_callback_handlers.is_empty());
         assert!(contract.instantiate_handler.is_none());
         assert!(contract.execute_handler.is_none());
         assert!(contract.query_handler.is_none());
         assert!(contract.migrate_handler.is_none());
+        assert!(contract.migration_steps.is_empty());
     }
 
     #[coverage_helper::test]
@@ -329,6 +412,15 @@ mod test {
         assert!(contract.migrate_handler.is_some());
     }
 
+    #[coverage_helper::test]
+    fn test_with_migrations() {
+        const STEP: MigrationStepFn<MockModule, MockError> = |_, _, _| Ok(Response::default());
+        let contract = MockAppContract::new("test_contract", "0.1.0", ModuleMetadata::default())
+            .with_migrations(&[("<0.1.0", STEP)]);
+
+        assert_eq!(contract.migration_steps[0].0, "<0.1.0");
+    }
+
     #[coverage_helper::test]
     fn test_with_reply_handlers() {
         const REPLY_ID: u64 = 50u64;
@@ -350,4 +442,18 @@ mod test {
 
         assert!(contract.ibc_callback_handler.is_some());
     }
+
+    #[coverage_helper::test]
+    fn test_with_ibc_callbacks() {
+        const CALLBACK_ID: &str = "transfer";
+        const HANDLER: TypedIbcCallback<MockModule, String, MockError> =
+            TypedIbcCallback(|_, _, _, payload, _| {
+                Ok(Response::default().add_attribute("payload", payload))
+            });
+        let contract = MockAppContract::new("test_contract", "0.1.0", ModuleMetadata::default())
+            .with_ibc_callbacks(&[(CALLBACK_ID, &HANDLER)]);
+
+        assert_eq!(contract.ibc_callback_handlers[0].0, CALLBACK_ID);
+        assert!(contract.ibc_callback_handler.is_none());
+    }
 }
@@ -40,6 +40,17 @@ pub trait IbcCallbackEndpoint: Handler {
             }
             .into());
         };
+        // Callbacks created with an id are routed to the handler of that id
+        if let Some(callback_id) = &msg.callback.callback_id {
+            let handler = self
+                .maybe_ibc_callback_id_handler(callback_id)
+                .ok_or_else(|| AbstractSdkError::UnknownIbcCallbackId {
+                    callback_id: callback_id.clone(),
+                    module: self.module_id().to_string(),
+                })?;
+            return handler.handle(deps, env, self, &msg.callback.msg, msg.result);
+        }
+
         let ibc_callback_handler =
             self.maybe_ibc_callback_handler()
                 .ok_or(AbstractSdkError::NoModuleIbcHandler(
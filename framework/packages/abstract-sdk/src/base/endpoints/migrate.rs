@@ -1,8 +1,10 @@
 use cosmwasm_std::{DepsMut, Env, Response};
 use schemars::JsonSchema;
+use semver::{Version, VersionReq};
 use serde::Serialize;
 
 use super::super::Handler;
+use crate::AbstractSdkError;
 
 /// Trait for a contract's Migrate entry point.
 pub trait MigrateEndpoint: Handler {
@@ -16,4 +18,39 @@ pub trait MigrateEndpoint: Handler {
         env: Env,
         msg: Self::MigrateMsg,
     ) -> Result<Response, Self::Error>;
+
+    /// Runs, in registration order, the migration steps whose version requirement
+    /// matches `from_version` but not `to_version`.
+    /// The executed steps are recorded in the `migration_steps` attribute.
+    fn run_migration_steps(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        from_version: &Version,
+        to_version: &Version,
+    ) -> Result<Response, Self::Error> {
+        let mut response = Response::new();
+        let mut executed = vec![];
+        for (requirement, step) in self.migration_steps() {
+            let version_req = VersionReq::parse(requirement).map_err(|error| {
+                AbstractSdkError::InvalidMigrationRequirement {
+                    requirement: requirement.to_string(),
+                    error: error.to_string(),
+                }
+            })?;
+            if !version_req.matches(from_version) || version_req.matches(to_version) {
+                continue;
+            }
+            let step_response = step(deps.branch(), env, self)?;
+            response = response
+                .add_submessages(step_response.messages)
+                .add_attributes(step_response.attributes)
+                .add_events(step_response.events);
+            executed.push(*requirement);
+        }
+        if !executed.is_empty() {
+            response = response.add_attribute("migration_steps", executed.join(","));
+        }
+        Ok(response)
+    }
 }
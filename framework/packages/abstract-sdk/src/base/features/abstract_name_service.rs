@@ -1,15 +1,20 @@
 use abstract_std::{
     ans_host::{
-        AssetPairingFilter, AssetPairingMapEntry, PoolAddressListResponse, QueryMsg,
+        AnsEntries, AssetPairingFilter, AssetPairingMapEntry, PoolAddressListResponse, QueryMsg,
         RegisteredDexesResponse,
     },
     objects::{ans_host::AnsHost, DexAssetPairing},
 };
 use cosmwasm_std::Deps;
+use std::{cell::RefCell, rc::Rc};
 
 use super::ModuleIdentification;
 use crate::apis::AbstractApi;
-use crate::{ans_resolve::Resolve, cw_helpers::ApiQuery, AbstractSdkResult};
+use crate::{
+    ans_resolve::{AnsCache, BatchResolve, Resolve},
+    cw_helpers::ApiQuery,
+    AbstractSdkResult,
+};
 
 /// ANCHOR: ans
 /// Accessor to the Abstract Name Service.
@@ -23,6 +28,7 @@ pub trait AbstractNameService: Sized {
             base: self,
             deps,
             host: self.ans_host(deps).unwrap(),
+            cache: Rc::default(),
         }
     }
 }
@@ -34,6 +40,8 @@ pub struct AbstractNameServiceClient<'a, T: AbstractNameService> {
     deps: Deps<'a>,
     /// Abstract Name Service Contract
     pub host: AnsHost,
+    /// Values resolved through [`Self::resolve_batched`], shared between clones of this client.
+    cache: Rc<RefCell<AnsCache>>,
 }
 
 impl<T: ModuleIdentification + AbstractNameService> AbstractApi<T>
@@ -57,6 +65,24 @@ impl<T: ModuleIdentification + AbstractNameService> AbstractNameServiceClient<'_
             .map_err(|error| self.wrap_query_error(error))
     }
 
+    /// Resolve an entry with a single batched smart query to the ANS host.
+    /// Resolved values are memoized by this client so repeated lookups don't query again.
+    /// Prefer this over [`Self::query`] when resolving many entries, as each raw query has a fixed gas cost.
+    pub fn resolve_batched<R: BatchResolve>(&self, entry: &R) -> AbstractSdkResult<R::Output> {
+        let mut batch = AnsEntries::default();
+        entry.add_to_batch(&self.cache.borrow(), &mut batch);
+        if !batch.is_empty() {
+            let response = self
+                .host
+                .query_entries(&self.deps.querier, batch.clone())
+                .map_err(|error| self.wrap_query_error(error))?;
+            self.cache.borrow_mut().insert_batch(batch, response);
+        }
+        entry
+            .resolve_cached(&self.cache.borrow(), &self.host)
+            .map_err(|error| self.wrap_query_error(error))
+    }
+
     /// Returns if the entry is registered on the ANS.
     /// Will return an Err if the query failed for technical reasons (like a wrong address or state parsing error).
     /// Will return true if found.
@@ -3,8 +3,9 @@ use cosmwasm_std::Storage;
 use cw2::{ContractVersion, CONTRACT};
 
 use super::contract_base::{
-    AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, InstantiateHandlerFn,
-    MigrateHandlerFn, ModuleIbcHandlerFn, QueryHandlerFn, SudoHandlerFn,
+    AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, IbcCallbackIdHandler,
+    InstantiateHandlerFn, MigrateHandlerFn, MigrationStepFn, ModuleIbcHandlerFn, QueryHandlerFn,
+    SudoHandlerFn,
 };
 use crate::{
     base::{
@@ -126,6 +127,11 @@ where
         };
         Ok(handler)
     }
+    /// Get the ordered migration steps of the contract.
+    fn migration_steps(&self) -> &'static [(&'static str, MigrationStepFn<Self, Self::Error>)] {
+        let contract = self.contract();
+        contract.migration_steps
+    }
 
     /// Get a sudo handler if it exists.
     fn maybe_sudo_handler(&self) -> Option<SudoHandlerFn<Self, Self::SudoMsg, Self::Error>> {
@@ -147,6 +153,18 @@ where
         let contract = self.contract();
         contract.ibc_callback_handler
     }
+    /// Get the ibc callback handler registered for a callback id if it exists.
+    fn maybe_ibc_callback_id_handler(
+        &self,
+        callback_id: &str,
+    ) -> Option<&'static dyn IbcCallbackIdHandler<Self, Self::Error>> {
+        let contract = self.contract();
+        contract
+            .ibc_callback_handlers
+            .iter()
+            .find(|(id, _)| *id == callback_id)
+            .map(|(_, handler)| *handler)
+    }
     /// Get an IBC module call handler if it exists.
     fn maybe_module_ibc_handler(&self) -> Option<ModuleIbcHandlerFn<Self, Self::Error>> {
         let contract = self.contract();
@@ -8,9 +8,10 @@ pub(crate) mod features;
 mod handler;
 
 pub use contract_base::{
-    AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, InstantiateHandlerFn,
-    MigrateHandlerFn, ModuleIbcHandlerFn, ModuleId, ModuleMetadata, QueryHandlerFn, ReplyHandlerFn,
-    SudoHandlerFn, VersionString,
+    AbstractContract, ExecuteHandlerFn, IbcCallbackHandlerFn, IbcCallbackIdHandler,
+    IbcCallbackIdHandlerFn, IbcCallbackIdHandlers, InstantiateHandlerFn, MigrateHandlerFn,
+    MigrationStepFn, ModuleIbcHandlerFn, ModuleId, ModuleMetadata, QueryHandlerFn, ReplyHandlerFn,
+    SudoHandlerFn, TypedIbcCallback, VersionString,
 };
 pub use endpoints::{
     CustomExecuteHandler, ExecuteEndpoint, IbcCallbackEndpoint, InstantiateEndpoint,
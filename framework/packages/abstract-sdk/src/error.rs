@@ -35,6 +35,10 @@ pub enum AbstractSdkError {
     #[error("Missing handler for {endpoint}")]
     MissingHandler { endpoint: String },
 
+    // migration step keyed by an invalid semver requirement
+    #[error("Invalid version requirement \"{requirement}\" for migration step: {error}")]
+    InvalidMigrationRequirement { requirement: String, error: String },
+
     // missing module error
     #[error("Missing module {module}")]
     MissingModule { module: String },
@@ -62,6 +66,10 @@ pub enum AbstractSdkError {
     #[error("Called an IBC module action on {0}, when no endpoint was registered.")]
     NoModuleIbcHandler(String),
 
+    // no handler registered for the callback id
+    #[error("No IBC callback handler registered for callback id \"{callback_id}\" on {module}.")]
+    UnknownIbcCallbackId { callback_id: String, module: String },
+
     // Query from api object failed
     #[error("API query for {api} failed in {module_id}: {error}")]
     ApiQuery {
@@ -89,6 +97,18 @@ pub enum AbstractSdkError {
         "Only the admin can execute this action. An admin is either the owner of an account of an account called by its owner"
     )]
     OnlyAdmin {},
+
+    // Subscription of the account to the module lapsed
+    #[error("Subscription of account {account_id} to {module_id} lapsed at {paid_until}")]
+    ModuleSuspended {
+        account_id: String,
+        module_id: String,
+        paid_until: cosmwasm_std::Timestamp,
+    },
+
+    // The usage fee of a module can only be paid to a claimed namespace
+    #[error("Namespace {namespace} is not claimed, the usage fee of {module} can't be paid")]
+    UnclaimedModuleNamespace { namespace: String, module: String },
 }
 
 impl AbstractSdkError {
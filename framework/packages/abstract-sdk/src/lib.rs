@@ -41,7 +41,7 @@ pub mod features {
     pub use crate::base::features::*;
 }
 
-pub use ans_resolve::Resolve;
+pub use ans_resolve::{AnsCache, BatchResolve, Resolve};
 
 /// Common state-store namespaces.
 pub mod namespaces {
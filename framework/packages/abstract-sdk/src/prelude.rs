@@ -11,7 +11,7 @@
 #[cfg(feature = "stargate")]
 pub use crate::apis::{distribution::*, stargate::feegrant::*};
 pub use crate::{
-    ans_resolve::Resolve,
+    ans_resolve::{BatchResolve, Resolve},
     apis::{
         adapter::*, app::*, bank::*, execution::*, ibc::*, modules::*, respond::*, verify::*,
         version_registry::*,
@@ -84,6 +84,10 @@ pub mod state {
     pub const CALLING_TO_AS_ADMIN: Item<Addr> =
         Item::new(storage_namespaces::account::CALLING_TO_AS_ADMIN);
     pub const CALLING_TO_AS_ADMIN_WILD_CARD: &str = "calling-to-wild-card";
+    /// Auditors of which the Account requires an attestation to install a module.
+    /// No attestation is required when empty.
+    pub const TRUSTED_AUDITORS: Item<Vec<AccountId>> =
+        Item::new(storage_namespaces::account::TRUSTED_AUDITORS);
 
     #[cfg(feature = "xion")]
     /// XION temporary state. This is used to make sure that the account only has admin rights when authenticated through XION
@@ -278,6 +282,9 @@ pub enum QueryMsg {
     /// Returns [`TopLevelOwnerResponse`]
     #[returns(TopLevelOwnerResponse)]
     TopLevelOwner {},
+    /// Returns [`TrustedAuditorsResponse`]
+    #[returns(TrustedAuditorsResponse)]
+    TrustedAuditors {},
     /// Query the contract's ownership information
     #[returns(Ownership<String>)]
     Ownership {},
@@ -321,6 +328,13 @@ pub enum InternalConfigAction {
         /// Addresses to remove from the Account's execution whitelist
         to_remove: Vec<String>,
     },
+    /// Update the auditors in [`state::TRUSTED_AUDITORS`]
+    /// When set, modules can only be installed if one of these auditors attested them in the registry.
+    /// Only callable by owner.
+    UpdateTrustedAuditors {
+        to_add: Vec<AccountId>,
+        to_remove: Vec<AccountId>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -368,6 +382,11 @@ pub struct SubAccountIdsResponse {
     pub sub_accounts: Vec<u32>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct TrustedAuditorsResponse {
+    pub auditors: Vec<AccountId>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ConfigResponse {
     pub whitelisted_addresses: Vec<Addr>,
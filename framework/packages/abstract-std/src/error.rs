@@ -68,6 +68,9 @@ pub enum AbstractError {
         from: Version,
         to: Version,
     },
+
+    #[error("Event {event} is missing attribute {key}")]
+    MissingEventAttribute { event: String, key: String },
 }
 
 impl From<semver::Error> for AbstractError {
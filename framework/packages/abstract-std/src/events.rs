@@ -0,0 +1,407 @@
+//! # Abstract Events
+//! Typed representation of the events emitted by the framework contracts.
+//!
+//! Every framework action emits an `abstract` event (indexed as [`ABSTRACT_EVENT_TYPE`]) that contains
+//! the `contract` and `action` attributes, followed by action specific attributes.
+//! [`AbstractEvent::from_event`] parses such an event into its typed representation.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, Addr, Attribute, Event};
+
+use crate::{
+    objects::{
+        module::{ModuleInfo, ModuleVersion},
+        namespace::Namespace,
+        AccountId, TruncatedChainId,
+    },
+    AbstractError, AbstractResult, ABSTRACT_EVENT_TYPE, ACCOUNT, IBC_CLIENT, REGISTRY,
+};
+
+/// Attribute added by the chain to every wasm event, contains the address of the emitting contract.
+pub const CONTRACT_ADDRESS_ATTRIBUTE: &str = "_contract_address";
+
+/// Kind of IBC callback received by the IBC client.
+#[cw_serde]
+#[derive(Eq)]
+pub enum IbcCallbackKind {
+    /// Callback of a remote module action
+    Action,
+    /// Callback of a remote query
+    Query,
+}
+
+/// Typed framework event.
+#[cw_serde]
+pub enum AbstractEvent {
+    /// Account was registered on the registry
+    AccountCreated {
+        account_id: AccountId,
+        account: Addr,
+        namespace: Option<Namespace>,
+    },
+    /// Modules were installed on the account
+    ModulesInstalled {
+        account: Addr,
+        modules: Vec<ModuleInfo>,
+    },
+    /// Modules, or the account itself, were upgraded
+    ModulesUpgraded {
+        account: Addr,
+        /// Ids of the upgraded modules
+        modules: Vec<String>,
+        /// Deprecation warnings for the new versions
+        deprecated: Vec<String>,
+    },
+    /// Module was uninstalled from the account
+    ModuleUninstalled { account: Addr, module_id: String },
+    /// Sub-account was created and registered on its parent
+    SubAccountCreated {
+        parent: Addr,
+        sub_account_id: AccountId,
+    },
+    /// Sub-account was removed from its parent
+    SubAccountRemoved {
+        parent: Addr,
+        sub_account_id: AccountId,
+    },
+    /// Remote account creation was acknowledged by the IBC client
+    RemoteAccountRegistered {
+        account_id: AccountId,
+        chain: TruncatedChainId,
+    },
+    /// IBC client received the callback of a remote action or query
+    IbcCallbackReceived {
+        chain: TruncatedChainId,
+        kind: IbcCallbackKind,
+    },
+    /// Framework action without a typed representation
+    Other {
+        contract: String,
+        action: String,
+        attributes: Vec<Attribute>,
+    },
+}
+
+impl AbstractEvent {
+    /// Parse an event, returns `None` if it's not an Abstract event.
+    /// Both the event as returned by the contract (`abstract`) and as indexed by the chain (`wasm-abstract`) are accepted.
+    pub fn from_event(event: &Event) -> AbstractResult<Option<Self>> {
+        if event.ty != ABSTRACT_EVENT_TYPE && event.ty != "abstract" {
+            return Ok(None);
+        }
+        let event = EventAttributes(event);
+        let contract = event.get("contract")?;
+        let action = event.get("action")?;
+
+        let parsed = match (contract, action) {
+            (REGISTRY, "add_account") => AbstractEvent::AccountCreated {
+                account_id: event.get("account_id")?.parse()?,
+                account: Addr::unchecked(event.get("account_address")?),
+                namespace: event.find("namespace").map(Namespace::new).transpose()?,
+            },
+            (ACCOUNT, "install_modules") => AbstractEvent::ModulesInstalled {
+                account: event.contract_address()?,
+                modules: split_non_empty(event.get("installed_modules")?, ",")
+                    .iter()
+                    .map(|module| parse_module_with_version(module))
+                    .collect::<AbstractResult<_>>()?,
+            },
+            (ACCOUNT, "upgrade_modules") => AbstractEvent::ModulesUpgraded {
+                account: event.contract_address()?,
+                modules: split_non_empty(event.get("upgraded_modules")?, ","),
+                deprecated: event
+                    .find("deprecated_modules")
+                    .map(from_json::<Vec<String>>)
+                    .transpose()?
+                    .unwrap_or_default(),
+            },
+            (ACCOUNT, "uninstall_module") => AbstractEvent::ModuleUninstalled {
+                account: event.contract_address()?,
+                module_id: event.get("module")?.to_owned(),
+            },
+            (ACCOUNT, "register_sub_account") => AbstractEvent::SubAccountCreated {
+                parent: event.contract_address()?,
+                sub_account_id: parse_local_account_id(event.get("sub_account_added")?)?,
+            },
+            (ACCOUNT, "unregister_sub_account") => AbstractEvent::SubAccountRemoved {
+                parent: event.contract_address()?,
+                sub_account_id: parse_local_account_id(event.get("sub_account_removed")?)?,
+            },
+            (IBC_CLIENT, "acknowledge_remote_account_registration") => {
+                AbstractEvent::RemoteAccountRegistered {
+                    account_id: event.get("account_id")?.parse()?,
+                    chain: TruncatedChainId::from_string(event.get("chain")?.to_owned())?,
+                }
+            }
+            (IBC_CLIENT, "module_action_ibc_callback") => AbstractEvent::IbcCallbackReceived {
+                chain: TruncatedChainId::from_string(event.get("chain")?.to_owned())?,
+                kind: IbcCallbackKind::Action,
+            },
+            (IBC_CLIENT, "module_query_ibc_callback") => AbstractEvent::IbcCallbackReceived {
+                chain: TruncatedChainId::from_string(event.get("chain")?.to_owned())?,
+                kind: IbcCallbackKind::Query,
+            },
+            (contract, action) => AbstractEvent::Other {
+                contract: contract.to_owned(),
+                action: action.to_owned(),
+                attributes: event
+                    .0
+                    .attributes
+                    .iter()
+                    .filter(|attr| {
+                        !["contract", "action", CONTRACT_ADDRESS_ATTRIBUTE]
+                            .contains(&attr.key.as_str())
+                    })
+                    .cloned()
+                    .collect(),
+            },
+        };
+        Ok(Some(parsed))
+    }
+
+    /// Parse all Abstract events, skipping other events.
+    pub fn from_events<'a>(
+        events: impl IntoIterator<Item = &'a Event>,
+    ) -> AbstractResult<Vec<Self>> {
+        events
+            .into_iter()
+            .filter_map(|event| Self::from_event(event).transpose())
+            .collect()
+    }
+}
+
+struct EventAttributes<'a>(&'a Event);
+
+impl EventAttributes<'_> {
+    fn find(&self, key: &str) -> Option<&str> {
+        self.0
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    }
+
+    fn get(&self, key: &str) -> AbstractResult<&str> {
+        self.find(key)
+            .ok_or_else(|| AbstractError::MissingEventAttribute {
+                event: self.0.ty.clone(),
+                key: key.to_owned(),
+            })
+    }
+
+    fn contract_address(&self) -> AbstractResult<Addr> {
+        self.get(CONTRACT_ADDRESS_ATTRIBUTE).map(Addr::unchecked)
+    }
+}
+
+fn split_non_empty(value: &str, delimiter: &str) -> Vec<String> {
+    value
+        .split(delimiter)
+        .filter(|item| !item.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Parse a `namespace:name:version` module id
+fn parse_module_with_version(value: &str) -> AbstractResult<ModuleInfo> {
+    let (id, version) = value
+        .rsplit_once(':')
+        .ok_or_else(|| AbstractError::FormattingError {
+            object: "module".into(),
+            expected: "namespace:name:version".into(),
+            actual: value.into(),
+        })?;
+    ModuleInfo::from_id(id, version.parse::<ModuleVersion>()?)
+}
+
+fn parse_local_account_id(value: &str) -> AbstractResult<AccountId> {
+    value
+        .parse()
+        .map(AccountId::local)
+        .map_err(|_| AbstractError::FormattingError {
+            object: "account sequence".into(),
+            expected: "u32".into(),
+            actual: value.into(),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn account_event(action: &str) -> Event {
+        Event::new(ABSTRACT_EVENT_TYPE)
+            .add_attribute(CONTRACT_ADDRESS_ATTRIBUTE, "account")
+            .add_attribute("contract", ACCOUNT)
+            .add_attribute("action", action)
+    }
+
+    #[coverage_helper::test]
+    fn skips_other_events() {
+        let event = Event::new("wasm").add_attribute("action", "install_modules");
+        assert_eq!(AbstractEvent::from_event(&event), Ok(None));
+    }
+
+    #[coverage_helper::test]
+    fn parses_account_created() {
+        let event = Event::new("abstract")
+            .add_attribute("contract", REGISTRY)
+            .add_attribute("action", "add_account")
+            .add_attribute("account_id", "local-5")
+            .add_attribute("account_address", "account")
+            .add_attribute("namespace", "tester");
+
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::AccountCreated {
+                account_id: AccountId::local(5),
+                account: Addr::unchecked("account"),
+                namespace: Some(Namespace::new("tester").unwrap()),
+            }))
+        );
+
+        let event = Event::new("abstract")
+            .add_attribute("contract", REGISTRY)
+            .add_attribute("action", "add_account")
+            .add_attribute("account_id", "local-5")
+            .add_attribute("account_address", "account");
+        let Ok(Some(AbstractEvent::AccountCreated { namespace, .. })) =
+            AbstractEvent::from_event(&event)
+        else {
+            panic!("expected account created event");
+        };
+        assert_eq!(namespace, None);
+    }
+
+    #[coverage_helper::test]
+    fn parses_installed_modules() {
+        let event = account_event("install_modules")
+            .add_attribute("installed_modules", "tester:app:1.0.0,tester:adapter:0.1.0");
+
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::ModulesInstalled {
+                account: Addr::unchecked("account"),
+                modules: vec![
+                    ModuleInfo::from_id("tester:app", ModuleVersion::Version("1.0.0".to_owned()))
+                        .unwrap(),
+                    ModuleInfo::from_id(
+                        "tester:adapter",
+                        ModuleVersion::Version("0.1.0".to_owned())
+                    )
+                    .unwrap(),
+                ],
+            }))
+        );
+    }
+
+    #[coverage_helper::test]
+    fn parses_upgraded_modules() {
+        let event = account_event("upgrade_modules")
+            .add_attribute("upgraded_modules", "tester:app,abstract:account");
+
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::ModulesUpgraded {
+                account: Addr::unchecked("account"),
+                modules: vec!["tester:app".to_owned(), "abstract:account".to_owned()],
+                deprecated: vec![],
+            }))
+        );
+
+        // Deprecation reasons can contain any delimiter
+        let warnings = vec![
+            "tester:app:1.0.0 is deprecated: unsafe; migrate, please".to_owned(),
+            "abstract:account:0.1.0 is deprecated: old".to_owned(),
+        ];
+        let event = account_event("upgrade_modules")
+            .add_attribute("upgraded_modules", "tester:app,abstract:account")
+            .add_attribute(
+                "deprecated_modules",
+                cosmwasm_std::to_json_string(&warnings).unwrap(),
+            );
+        let Ok(Some(AbstractEvent::ModulesUpgraded { deprecated, .. })) =
+            AbstractEvent::from_event(&event)
+        else {
+            panic!("expected modules upgraded event");
+        };
+        assert_eq!(deprecated, warnings);
+    }
+
+    #[coverage_helper::test]
+    fn parses_sub_account_events() {
+        let event = account_event("register_sub_account").add_attribute("sub_account_added", "3");
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::SubAccountCreated {
+                parent: Addr::unchecked("account"),
+                sub_account_id: AccountId::local(3),
+            }))
+        );
+
+        let event =
+            account_event("unregister_sub_account").add_attribute("sub_account_removed", "3");
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::SubAccountRemoved {
+                parent: Addr::unchecked("account"),
+                sub_account_id: AccountId::local(3),
+            }))
+        );
+    }
+
+    #[coverage_helper::test]
+    fn parses_ibc_client_events() {
+        let ibc_client_event = |action: &str| {
+            Event::new(ABSTRACT_EVENT_TYPE)
+                .add_attribute("contract", IBC_CLIENT)
+                .add_attribute("action", action)
+                .add_attribute("chain", "juno")
+        };
+
+        let event = ibc_client_event("acknowledge_remote_account_registration")
+            .add_attribute("account_id", "local-1");
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::RemoteAccountRegistered {
+                account_id: AccountId::local(1),
+                chain: TruncatedChainId::from_chain_id("juno-1"),
+            }))
+        );
+
+        let event = ibc_client_event("module_query_ibc_callback");
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::IbcCallbackReceived {
+                chain: TruncatedChainId::from_chain_id("juno-1"),
+                kind: IbcCallbackKind::Query,
+            }))
+        );
+    }
+
+    #[coverage_helper::test]
+    fn unknown_actions_keep_attributes() {
+        let event = account_event("update_status").add_attribute("is_suspended", "true");
+
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Ok(Some(AbstractEvent::Other {
+                contract: ACCOUNT.to_owned(),
+                action: "update_status".to_owned(),
+                attributes: vec![Attribute::new("is_suspended", "true")],
+            }))
+        );
+    }
+
+    #[coverage_helper::test]
+    fn missing_attribute_errors() {
+        let event = account_event("uninstall_module");
+
+        assert_eq!(
+            AbstractEvent::from_event(&event),
+            Err(AbstractError::MissingEventAttribute {
+                event: ABSTRACT_EVENT_TYPE.to_owned(),
+                key: "module".to_owned(),
+            })
+        );
+    }
+}
@@ -61,4 +61,6 @@ pub use crate::native::*;
 pub mod constants;
 pub use constants::*;
 
+pub mod events;
+
 pub mod native_addrs;
@@ -6,7 +6,7 @@
 //! Contract and asset addresses are stored on the ans_host contract and are retrievable trough smart or raw queries.
 
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal};
 use cw_asset::{AssetInfo, AssetInfoUnchecked};
 
 use crate::objects::{
@@ -30,6 +30,54 @@ pub type ChannelMapEntry = (ChannelEntry, String);
 pub type ContractMapEntry = (ContractEntry, Addr);
 /// A map entry of (unique_pool_id -> pool_metadata)
 pub type PoolMetadataMapEntry = (UniquePoolId, PoolMetadata);
+/// Map entry for asset metadata (asset_name -> metadata)
+pub type AssetMetadataMapEntry = (AssetEntry, AssetMetadata);
+
+/// Entries to resolve in a single [`QueryMsg::BatchEntries`] query
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct AnsEntries {
+    pub assets: Vec<AssetEntry>,
+    pub contracts: Vec<ContractEntry>,
+    pub channels: Vec<ChannelEntry>,
+    pub pairings: Vec<DexAssetPairing>,
+    pub pool_ids: Vec<UniquePoolId>,
+}
+
+impl AnsEntries {
+    /// Whether there is nothing to resolve
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+            && self.contracts.is_empty()
+            && self.channels.is_empty()
+            && self.pairings.is_empty()
+            && self.pool_ids.is_empty()
+    }
+}
+
+/// Display and pricing information of an asset
+#[cosmwasm_schema::cw_serde]
+pub struct AssetMetadata {
+    /// Number of decimals of the asset
+    pub decimals: u8,
+    /// Name used to display the asset
+    pub display_name: String,
+    /// Coingecko-style identifier of the asset
+    pub coingecko_id: Option<String>,
+    /// Preferred source for the price of the asset
+    pub price_source: Option<PriceSource>,
+}
+
+/// Where the price of an asset should be sourced from
+#[cosmwasm_schema::cw_serde]
+pub enum PriceSource {
+    /// Price through a pool registered in ANS
+    Pool(UniquePoolId),
+    /// Price provided by an oracle contract registered in ANS
+    Oracle(ContractEntry),
+    /// Fixed peg to another asset, `rate` units of `asset` per unit
+    Peg { asset: AssetEntry, rate: Decimal },
+}
 
 /// AnsHost state details
 pub mod state {
@@ -38,10 +86,10 @@ pub mod state {
     use cw_storage_plus::{Item, Map};
 
     use crate::{
-        ans_host::{DexAssetPairing, DexName, UniquePoolId},
+        ans_host::{AssetMetadata, DexAssetPairing, DexName, UniquePoolId},
         objects::{
-            pool_metadata::PoolMetadata, pool_reference::PoolReference, storage_namespaces,
-            AssetEntry, ChannelEntry, ContractEntry,
+            namespace::Namespace, pool_metadata::PoolMetadata, pool_reference::PoolReference,
+            storage_namespaces, AssetEntry, ChannelEntry, ContractEntry,
         },
     };
 
@@ -80,6 +128,14 @@ pub mod state {
     /// Stores the metadata for the pools using the unique pool id as the key
     pub const POOL_METADATA: Map<UniquePoolId, PoolMetadata> =
         Map::new(storage_namespaces::ans_host::POOL_METADATA);
+
+    /// Stores the optional display and pricing metadata of assets
+    pub const ASSET_METADATA: Map<&AssetEntry, AssetMetadata> =
+        Map::new(storage_namespaces::ans_host::ASSET_METADATA);
+
+    /// Stores the namespace whose owner can curate the contract entries of a protocol
+    pub const PROTOCOL_NAMESPACES: Map<&str, Namespace> =
+        Map::new(storage_namespaces::ans_host::PROTOCOL_NAMESPACES);
     // ANCHOR_END: ans_state
 }
 
@@ -95,6 +151,7 @@ pub struct InstantiateMsg {
 #[derive(cw_orch::ExecuteFns)]
 pub enum ExecuteMsg {
     /// Updates the contract addressbook
+    /// Accounts that own a namespace can update contracts of the protocols bound to that namespace
     UpdateContractAddresses {
         // Contracts to update or add
         to_add: Vec<(UncheckedContractEntry, String)>,
@@ -102,6 +159,7 @@ pub enum ExecuteMsg {
         to_remove: Vec<UncheckedContractEntry>,
     },
     /// Updates the Asset addressbook
+    /// Accounts that own a namespace can update assets prefixed with `<namespace>/`
     UpdateAssetAddresses {
         // Assets to update or add
         to_add: Vec<(String, AssetInfoUnchecked)>,
@@ -116,6 +174,7 @@ pub enum ExecuteMsg {
         to_remove: Vec<UncheckedChannelEntry>,
     },
     /// Registers a dex
+    /// Accounts that own a namespace can register the dex with the same name
     UpdateDexes {
         // Dexes to add
         to_add: Vec<String>,
@@ -123,12 +182,28 @@ pub enum ExecuteMsg {
         to_remove: Vec<String>,
     },
     /// Update the pools
+    /// Accounts that own a namespace can update the pools of the dex with the same name
     UpdatePools {
         // Pools to update or add
         to_add: Vec<(UncheckedPoolAddress, PoolMetadata)>,
         // Pools to remove
         to_remove: Vec<UniquePoolId>,
     },
+    /// Updates the metadata of registered assets
+    /// Accounts that own a namespace can update the metadata of assets prefixed with `<namespace>/`
+    UpdateAssetMetadata {
+        // Asset metadata to update or add
+        to_add: Vec<(String, AssetMetadata)>,
+        // Asset metadata to remove
+        to_remove: Vec<String>,
+    },
+    /// Binds protocols of contract entries to the namespace that can curate them
+    UpdateProtocolNamespaces {
+        // (protocol, namespace) bindings to update or add
+        to_add: Vec<(String, String)>,
+        // Protocols to unbind
+        to_remove: Vec<String>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -262,6 +337,25 @@ pub enum QueryMsg {
         start_after: Option<UniquePoolId>,
         limit: Option<u8>,
     },
+    /// Resolve entries of different kinds in a single query
+    /// Entries that are not registered are omitted from the response
+    /// returns [`BatchEntriesResponse`]
+    #[returns(BatchEntriesResponse)]
+    BatchEntries { entries: AnsEntries },
+    /// Queries asset metadata based on asset name
+    /// returns [`AssetMetadatasResponse`]
+    #[returns(AssetMetadatasResponse)]
+    AssetMetadatas {
+        // Names of assets to query
+        names: Vec<String>,
+    },
+    /// Page over asset metadata
+    /// returns [`AssetMetadataListResponse`]
+    #[returns(AssetMetadataListResponse)]
+    AssetMetadataList {
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -340,3 +434,20 @@ pub struct PoolMetadatasResponse {
 pub struct PoolMetadataListResponse {
     pub metadatas: Vec<PoolMetadataMapEntry>,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct AssetMetadatasResponse {
+    pub metadatas: Vec<AssetMetadataMapEntry>,
+}
+
+pub type AssetMetadataListResponse = AssetMetadatasResponse;
+
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct BatchEntriesResponse {
+    pub assets: Vec<AssetMapEntry>,
+    pub contracts: Vec<ContractMapEntry>,
+    pub channels: Vec<ChannelMapEntry>,
+    pub pairings: Vec<AssetPairingMapEntry>,
+    pub pool_metadatas: Vec<PoolMetadataMapEntry>,
+}
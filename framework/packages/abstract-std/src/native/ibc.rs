@@ -28,6 +28,10 @@ pub struct Callback {
     /// Used to add information to the callback.
     /// This is usually used to provide information to the ibc callback function for context
     pub msg: Binary,
+    /// Id of the handler that receives the callback, set by [`Callback::with_id`].
+    /// Callbacks without an id are handled by the module's default ibc callback handler.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
 }
 // ANCHOR_END: callback-info
 
@@ -35,6 +39,15 @@ impl Callback {
     pub fn new<T: Serialize>(msg: &T) -> StdResult<Self> {
         Ok(Self {
             msg: to_json_binary(msg)?,
+            callback_id: None,
+        })
+    }
+
+    /// Create a callback that is routed to the handler registered for `callback_id`
+    pub fn with_id<T: Serialize>(callback_id: impl Into<String>, msg: &T) -> StdResult<Self> {
+        Ok(Self {
+            msg: to_json_binary(msg)?,
+            callback_id: Some(callback_id.into()),
         })
     }
 }
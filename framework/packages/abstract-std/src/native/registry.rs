@@ -20,10 +20,12 @@ pub struct Config {
 pub mod state {
     use cw_storage_plus::{Item, Map};
 
-    use super::{Account, Config, ModuleConfiguration, ModuleDefaultConfiguration};
+    use super::{
+        Account, Config, ModuleAttestation, ModuleConfiguration, ModuleDefaultConfiguration,
+    };
     use crate::objects::{
         account::{AccountId, AccountSequence},
-        module::ModuleInfo,
+        module::{ModuleInfo, ModuleSubscription},
         module_reference::ModuleReference,
         namespace::Namespace,
         storage_namespaces::{self},
@@ -62,16 +64,31 @@ pub mod state {
         Map::new(storage_namespaces::registry::NAMESPACES);
     pub const REV_NAMESPACES: Map<&AccountId, Namespace> =
         Map::new(storage_namespaces::registry::REV_NAMESPACES);
+    /// Namespaces offered to another Account, waiting for acceptance
+    pub const PENDING_NAMESPACE_TRANSFERS: Map<&Namespace, AccountId> =
+        Map::new(storage_namespaces::registry::PENDING_NAMESPACE_TRANSFERS);
+    /// Subscriptions of Accounts to modules, keyed by module id
+    pub const MODULE_SUBSCRIPTIONS: Map<(&AccountId, &str), ModuleSubscription> =
+        Map::new(storage_namespaces::registry::MODULE_SUBSCRIPTIONS);
+    /// Accounts approved to attest module audits
+    pub const AUDITORS: Map<&AccountId, cosmwasm_std::Empty> =
+        Map::new(storage_namespaces::registry::AUDITORS);
+    /// Audit attestations of module versions
+    pub const MODULE_ATTESTATIONS: Map<&ModuleInfo, Vec<ModuleAttestation>> =
+        Map::new(storage_namespaces::registry::MODULE_ATTESTATIONS);
 }
 
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Api, Coin, Storage};
+use cosmwasm_std::{Addr, Api, Coin, Storage, Timestamp};
 use cw_clearable::Clearable;
 
 use self::state::{MODULE_CONFIG, MODULE_DEFAULT_CONFIG};
 use crate::objects::{
     account::AccountId,
-    module::{Module, ModuleInfo, ModuleMetadata, ModuleStatus, Monetization},
+    module::{
+        Module, ModuleDeprecation, ModuleInfo, ModuleMetadata, ModuleStanding, ModuleStatus,
+        Monetization,
+    },
     module_reference::ModuleReference,
     namespace::Namespace,
 };
@@ -131,10 +148,32 @@ pub enum ExecuteMsg {
     /// Yank a version of a module so that it may not be installed
     /// Only callable by Admin
     YankModule { module: ModuleInfo },
+    /// Deprecate a version of a module, optionally pointing to its replacement
+    /// Deprecated modules can still be installed, but installs and upgrades emit a warning
+    /// Only callable by namespace admin
+    DeprecateModule {
+        module: ModuleInfo,
+        replacement: Option<ModuleInfo>,
+        reason: String,
+    },
     /// Propose new modules to the version registry
     /// Namespaces need to be claimed by the Account before proposing modules
     /// Once proposed, the modules need to be approved by the Admin via [`ExecuteMsg::ApproveOrRejectModules`]
     ProposeModules { modules: Vec<ModuleMapEntry> },
+    /// Approve or revoke the Accounts that can attest module audits
+    /// Only callable by Admin
+    UpdateAuditors {
+        to_add: Vec<AccountId>,
+        to_remove: Vec<AccountId>,
+    },
+    /// Attest that a version of a module was audited
+    /// Replaces the previous attestation of the auditor for this module
+    /// Only callable by the Account of an approved auditor
+    AttestModule {
+        module: ModuleInfo,
+        /// Hash or URL of the audit report
+        report: String,
+    },
     /// Sets the metadata configuration for a module.
     /// Only callable by namespace admin
     UpdateModuleConfiguration {
@@ -157,6 +196,18 @@ pub enum ExecuteMsg {
     /// Forgo namespace claims
     /// Only admin or root user can call this
     ForgoNamespace { namespaces: Vec<String> },
+    /// Offer a namespace to another Account.
+    /// The transfer completes when the receiving Account calls [`ExecuteMsg::AcceptNamespace`].
+    /// Replaces any pending transfer of the namespace.
+    /// Only callable by the namespace owner
+    TransferNamespace {
+        namespace: String,
+        to_account: AccountId,
+    },
+    /// Accept a pending namespace transfer.
+    /// Modules registered under the namespace move with it and no fee is charged.
+    /// Only callable by the receiving Account or its owner
+    AcceptNamespace { namespace: String },
     /// Register a new Account to the deployed Accounts.
     /// Claims namespace if provided.  
     /// Only new accounts can call this.
@@ -171,6 +222,14 @@ pub enum ExecuteMsg {
         /// The fee charged when registering a namespace
         namespace_registration_fee: Option<Clearable<Coin>>,
     },
+    /// Pay for `periods` periods of a module subscription on behalf of an Account.
+    /// The fee is forwarded to the Account that owns the module's namespace.
+    #[cw_orch(payable)]
+    PayModuleSubscription {
+        account_id: AccountId,
+        module: ModuleInfo,
+        periods: u64,
+    },
 }
 
 #[non_exhaustive]
@@ -199,6 +258,8 @@ pub struct ModuleFilter {
     pub name: Option<String>,
     pub version: Option<String>,
     pub status: Option<ModuleStatus>,
+    /// Only return modules attested by this auditor
+    pub attested_by: Option<AccountId>,
 }
 
 /// Registry Query Msg
@@ -223,6 +284,10 @@ pub enum QueryMsg {
     /// Returns [`NamespaceResponse`]
     #[returns(NamespaceResponse)]
     Namespace { namespace: Namespace },
+    /// Queries the pending transfer of a namespace
+    /// Returns [`PendingNamespaceTransferResponse`]
+    #[returns(PendingNamespaceTransferResponse)]
+    PendingNamespaceTransfer { namespace: Namespace },
     /// Returns [`ConfigResponse`]
     #[returns(ConfigResponse)]
     Config {},
@@ -245,6 +310,32 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u8>,
     },
+    /// Queries the audit attestations of a module version
+    /// Returns [`ModuleAttestationsResponse`]
+    #[returns(ModuleAttestationsResponse)]
+    ModuleAttestations { module: ModuleInfo },
+    /// Lists the approved auditors
+    /// Returns [`AuditorsResponse`]
+    #[returns(AuditorsResponse)]
+    Auditors {
+        start_after: Option<AccountId>,
+        limit: Option<u8>,
+    },
+    /// Queries the standing of an Account on a module version
+    /// Returns [`ModuleStandingResponse`]
+    #[returns(ModuleStandingResponse)]
+    ModuleStanding {
+        account_id: AccountId,
+        module: ModuleInfo,
+    },
+    /// Lists the standing of an Account for all of its module subscriptions
+    /// Returns [`AccountStandingResponse`]
+    #[returns(AccountStandingResponse)]
+    AccountStanding {
+        account_id: AccountId,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -275,6 +366,8 @@ pub struct ModuleConfiguration {
     pub monetization: Monetization,
     pub metadata: Option<ModuleMetadata>,
     pub instantiation_funds: Vec<Coin>,
+    /// Set when the module version is deprecated
+    pub deprecation: Option<ModuleDeprecation>,
 }
 
 #[non_exhaustive]
@@ -299,6 +392,7 @@ impl ModuleConfiguration {
             monetization,
             metadata,
             instantiation_funds,
+            deprecation: None,
         }
     }
 
@@ -321,11 +415,36 @@ impl ModuleConfiguration {
     }
 }
 
+/// Attestation by an auditor that a module version was audited
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleAttestation {
+    pub auditor: AccountId,
+    /// Hash or URL of the audit report
+    pub report: String,
+    pub date: Timestamp,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleAttestationsResponse {
+    pub attestations: Vec<ModuleAttestation>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct AuditorsResponse {
+    pub auditors: Vec<AccountId>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ModulesListResponse {
     pub modules: Vec<ModuleResponse>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct PendingNamespaceTransferResponse {
+    /// Account the namespace is offered to, if any
+    pub to_account: Option<AccountId>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub enum NamespaceResponse {
     Claimed(NamespaceInfo),
@@ -359,6 +478,17 @@ pub struct NamespaceListResponse {
     pub namespaces: Vec<(Namespace, AccountId)>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleStandingResponse {
+    pub standing: ModuleStanding,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct AccountStandingResponse {
+    /// Module ids with the standing of their subscription
+    pub modules: Vec<(String, ModuleStanding)>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ConfigResponse {
     pub security_enabled: bool,
@@ -6,10 +6,10 @@ use super::{AssetEntry, ChannelEntry, ContractEntry};
 use crate::{
     ans_host::{
         state::{
-            ASSET_ADDRESSES, ASSET_PAIRINGS, CHANNELS, CONTRACT_ADDRESSES, POOL_METADATA,
-            REGISTERED_DEXES, REV_ASSET_ADDRESSES,
+            ASSET_ADDRESSES, ASSET_METADATA, ASSET_PAIRINGS, CHANNELS, CONTRACT_ADDRESSES,
+            POOL_METADATA, REGISTERED_DEXES, REV_ASSET_ADDRESSES,
         },
-        RegisteredDexesResponse,
+        AnsEntries, AssetMetadata, BatchEntriesResponse, QueryMsg, RegisteredDexesResponse,
     },
     native_addrs,
     objects::{DexAssetPairing, PoolMetadata, PoolReference, UniquePoolId},
@@ -154,6 +154,38 @@ impl AnsHost {
         Ok(result)
     }
 
+    /// Resolve entries of different kinds with a single smart query.
+    /// Entries that are not registered are omitted from the response.
+    #[function_name::named]
+    pub fn query_entries(
+        &self,
+        querier: &QuerierWrapper,
+        entries: AnsEntries,
+    ) -> AnsHostResult<BatchEntriesResponse> {
+        querier
+            .query_wasm_smart(&self.address, &QueryMsg::BatchEntries { entries })
+            .map_err(|error| AnsHostError::QueryFailed {
+                method_name: function_name!().to_owned(),
+                error,
+            })
+    }
+
+    /// Raw query of the metadata of an asset, if any is registered
+    #[function_name::named]
+    pub fn query_asset_metadata(
+        &self,
+        querier: &QuerierWrapper,
+        asset: &AssetEntry,
+    ) -> AnsHostResult<Option<AssetMetadata>> {
+        let result = ASSET_METADATA
+            .query(querier, self.address.clone(), asset)
+            .map_err(|error| AnsHostError::QueryFailed {
+                method_name: function_name!().to_owned(),
+                error,
+            })?;
+        Ok(result)
+    }
+
     /// Raw Query to AnsHost contract
     pub fn query_assets_reverse(
         &self,
@@ -3,7 +3,10 @@ use std::{
     str::FromStr,
 };
 
-use cosmwasm_std::{ensure_eq, to_json_binary, Addr, Binary, QuerierWrapper, StdError, StdResult};
+use cosmwasm_std::{
+    ensure_eq, to_json_binary, Addr, Binary, BlockInfo, QuerierWrapper, StdError, StdResult,
+    Timestamp,
+};
 use cw2::ContractVersion;
 use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
 use semver::Version;
@@ -510,6 +513,15 @@ pub fn assert_module_data_validity(
 pub enum Monetization {
     None,
     InstallFee(FixedFee),
+    /// Recurring fee, paid upfront for every `period` (in seconds) the module is used.
+    /// The first period is charged on installation.
+    Subscription {
+        fee: FixedFee,
+        period: u64,
+        lapse_policy: LapsePolicy,
+    },
+    /// Fee charged by the module itself on every call that requires it.
+    UsageFee(FixedFee),
 }
 
 impl Default for Monetization {
@@ -518,6 +530,106 @@ impl Default for Monetization {
     }
 }
 
+impl Monetization {
+    /// Standing of an Account on a module with this monetization, given its subscription record.
+    /// An Account without a record never paid for the subscription, e.g. because it installed
+    /// the module before it required one, so its subscription lapsed at the start of time.
+    pub fn standing(
+        &self,
+        subscription: Option<ModuleSubscription>,
+        block: &BlockInfo,
+    ) -> ModuleStanding {
+        let Monetization::Subscription { lapse_policy, .. } = self else {
+            return ModuleStanding::NotRequired {};
+        };
+        subscription
+            .unwrap_or_else(|| ModuleSubscription {
+                paid_until: Timestamp::from_seconds(0),
+                lapse_policy: lapse_policy.clone(),
+            })
+            .standing(block)
+    }
+}
+
+/// Soft deprecation of a module version, set by the owner of its namespace.
+/// Deprecated modules can still be installed, but installs and upgrades report the deprecation.
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleDeprecation {
+    /// Module version that should be used instead
+    pub replacement: Option<ModuleInfo>,
+    pub reason: String,
+}
+
+impl ModuleDeprecation {
+    /// Warning shown to users of the deprecated `module`
+    pub fn warning(&self, module: &ModuleInfo) -> String {
+        let mut warning = format!(
+            "{} is deprecated: {}",
+            module.id_with_version(),
+            self.reason
+        );
+        if let Some(replacement) = &self.replacement {
+            warning.push_str(&format!(", use {} instead", replacement.id_with_version()));
+        }
+        warning
+    }
+}
+
+/// What happens to a module once its subscription is no longer paid for.
+#[cosmwasm_schema::cw_serde]
+pub enum LapsePolicy {
+    /// The module keeps working, its standing is only reported as lapsed.
+    Continue,
+    /// Module calls are rejected once the subscription lapsed for longer than `grace_period` seconds.
+    Suspend { grace_period: u64 },
+}
+
+/// Subscription of an Account to a module, stored in the registry.
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleSubscription {
+    /// The subscription is paid for up to this time
+    pub paid_until: Timestamp,
+    pub lapse_policy: LapsePolicy,
+}
+
+impl ModuleSubscription {
+    /// Standing of the subscription at the given block
+    pub fn standing(&self, block: &BlockInfo) -> ModuleStanding {
+        let paid_until = self.paid_until;
+        if block.time < paid_until {
+            return ModuleStanding::Active { paid_until };
+        }
+        match self.lapse_policy {
+            LapsePolicy::Suspend { grace_period }
+                if block.time >= paid_until.plus_seconds(grace_period) =>
+            {
+                ModuleStanding::Suspended { paid_until }
+            }
+            _ => ModuleStanding::Lapsed { paid_until },
+        }
+    }
+}
+
+/// Standing of an Account with respect to the monetization of a module
+#[cosmwasm_schema::cw_serde]
+pub enum ModuleStanding {
+    /// The module does not require a subscription
+    NotRequired {},
+    /// Subscription is paid for
+    Active { paid_until: Timestamp },
+    /// Subscription ran out but the module can still be used
+    Lapsed { paid_until: Timestamp },
+    /// Subscription ran out and the module can't be used until it's paid for
+    Suspended { paid_until: Timestamp },
+}
+
+impl ModuleStanding {
+    /// Whether the module can be used by the Account
+    pub fn is_usable(&self) -> bool {
+        !matches!(self, ModuleStanding::Suspended { .. })
+    }
+}
+
 /// Module Metadata String
 pub type ModuleMetadata = String;
 
@@ -892,4 +1004,72 @@ mod test {
             assert!(res.is_ok());
         }
     }
+
+    mod module_subscription {
+        use cosmwasm_std::{coin, testing::mock_env};
+
+        use super::*;
+
+        #[coverage_helper::test]
+        fn standing_follows_lapse_policy() {
+            let mut env = mock_env();
+            let paid_until = env.block.time.plus_seconds(100);
+            let mut subscription = ModuleSubscription {
+                paid_until,
+                lapse_policy: LapsePolicy::Suspend { grace_period: 50 },
+            };
+            assert_eq!(
+                subscription.standing(&env.block),
+                ModuleStanding::Active { paid_until }
+            );
+
+            env.block.time = paid_until.plus_seconds(10);
+            assert_eq!(
+                subscription.standing(&env.block),
+                ModuleStanding::Lapsed { paid_until }
+            );
+
+            env.block.time = paid_until.plus_seconds(50);
+            let standing = subscription.standing(&env.block);
+            assert_eq!(standing, ModuleStanding::Suspended { paid_until });
+            assert!(!standing.is_usable());
+
+            subscription.lapse_policy = LapsePolicy::Continue;
+            assert_eq!(
+                subscription.standing(&env.block),
+                ModuleStanding::Lapsed { paid_until }
+            );
+        }
+
+        #[coverage_helper::test]
+        fn missing_subscription_never_paid() {
+            let env = mock_env();
+            let monetization = Monetization::Subscription {
+                fee: FixedFee::new(&coin(10, "ujuno")),
+                period: 100,
+                lapse_policy: LapsePolicy::Suspend { grace_period: 50 },
+            };
+            assert_eq!(
+                monetization.standing(None, &env.block),
+                ModuleStanding::Suspended {
+                    paid_until: Timestamp::from_seconds(0)
+                }
+            );
+
+            let paid_until = env.block.time.plus_seconds(100);
+            let subscription = ModuleSubscription {
+                paid_until,
+                lapse_policy: LapsePolicy::Continue,
+            };
+            assert_eq!(
+                monetization.standing(Some(subscription.clone()), &env.block),
+                ModuleStanding::Active { paid_until }
+            );
+            // Records are ignored once the module no longer requires a subscription
+            assert_eq!(
+                Monetization::None.standing(Some(subscription), &env.block),
+                ModuleStanding::NotRequired {}
+            );
+        }
+    }
 }
@@ -8,5 +8,5 @@ pub use super::gov_type::GovernanceDetails;
 
 pub use gov_ownable::{
     assert_nested_owner, get_ownership, initialize_owner, is_owner, query_ownership,
-    update_ownership, GovAction, GovOwnershipError, Ownership,
+    update_ownership, Expiration, GovAction, GovOwnershipError, Ownership,
 };
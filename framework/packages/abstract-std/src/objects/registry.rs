@@ -1,8 +1,8 @@
-use cosmwasm_std::{Addr, Deps, QuerierWrapper};
+use cosmwasm_std::{Addr, BlockInfo, Deps, QuerierWrapper};
 use thiserror::Error;
 
 use super::{
-    module::{Module, ModuleInfo},
+    module::{Module, ModuleInfo, ModuleStanding, Monetization},
     module_reference::ModuleReference,
     namespace::Namespace,
     AccountId,
@@ -12,11 +12,11 @@ use crate::{
     native_addrs,
     registry::{
         state::{
-            ACCOUNT_ADDRESSES, CONFIG, NAMESPACES, REGISTERED_MODULES, SERVICE_INFOS,
-            STANDALONE_INFOS,
+            ACCOUNT_ADDRESSES, CONFIG, MODULE_ATTESTATIONS, MODULE_CONFIG, MODULE_SUBSCRIPTIONS,
+            NAMESPACES, REGISTERED_MODULES, REV_NAMESPACES, SERVICE_INFOS, STANDALONE_INFOS,
         },
-        Account, ModuleConfiguration, ModuleResponse, ModulesResponse, NamespaceResponse,
-        NamespacesResponse, QueryMsg,
+        Account, ModuleAttestation, ModuleConfiguration, ModuleResponse, ModulesResponse,
+        NamespaceResponse, NamespacesResponse, QueryMsg,
     },
     AbstractResult,
 };
@@ -179,6 +179,65 @@ impl RegistryContract {
         Ok(namespace_response)
     }
 
+    /// Queries the namespace owned by an account
+    #[function_name::named]
+    pub fn query_account_namespace_raw(
+        &self,
+        account_id: &AccountId,
+        querier: &QuerierWrapper,
+    ) -> RegistryResult<Option<Namespace>> {
+        let namespace = REV_NAMESPACES
+            .query(querier, self.address.clone(), account_id)
+            .map_err(|error| RegistryError::QueryFailed {
+                method_name: function_name!().to_owned(),
+                error,
+            })?;
+        Ok(namespace)
+    }
+
+    /// Raw query for the standing of an Account on a module version
+    #[function_name::named]
+    pub fn query_module_standing_raw(
+        &self,
+        account_id: &AccountId,
+        module: &ModuleInfo,
+        block: &BlockInfo,
+        querier: &QuerierWrapper,
+    ) -> RegistryResult<ModuleStanding> {
+        let map_err = |error| RegistryError::QueryFailed {
+            method_name: function_name!().to_owned(),
+            error,
+        };
+        let monetization = MODULE_CONFIG
+            .query(querier, self.address.clone(), module)
+            .map_err(map_err)?
+            .unwrap_or_default()
+            .monetization;
+        if !matches!(monetization, Monetization::Subscription { .. }) {
+            return Ok(ModuleStanding::NotRequired {});
+        }
+        let subscription = MODULE_SUBSCRIPTIONS
+            .query(querier, self.address.clone(), (account_id, &module.id()))
+            .map_err(map_err)?;
+        Ok(monetization.standing(subscription, block))
+    }
+
+    /// Raw query for the audit attestations of a module
+    #[function_name::named]
+    pub fn query_module_attestations_raw(
+        &self,
+        module: &ModuleInfo,
+        querier: &QuerierWrapper,
+    ) -> RegistryResult<Vec<ModuleAttestation>> {
+        let attestations = MODULE_ATTESTATIONS
+            .query(querier, self.address.clone(), module)
+            .map_err(|error| RegistryError::QueryFailed {
+                method_name: function_name!().to_owned(),
+                error,
+            })?;
+        Ok(attestations.unwrap_or_default())
+    }
+
     /// Queries the namespaces owned by accounts
     #[function_name::named]
     pub fn query_namespaces(
@@ -8,6 +8,8 @@ pub const OWNERSHIP_STORAGE_KEY: &str = "ownership";
 pub const MODULE_STORAGE_KEY: &str = "mod";
 /// Storage key for config in all modules
 pub const CONFIG_STORAGE_KEY: &str = "cfg";
+/// Storage key for the cached subscription standings of Accounts in all modules
+pub const MODULE_STANDING_CACHE: &str = "stnd";
 
 pub mod account {
     pub const SUSPENSION_STATUS: &str = "aa";
@@ -20,6 +22,7 @@ pub mod account {
     pub const INSTALL_MODULES_CONTEXT: &str = "ah";
     pub const MIGRATE_CONTEXT: &str = "ai";
     pub const CALLING_TO_AS_ADMIN: &str = "aj";
+    pub const TRUSTED_AUDITORS: &str = "ak";
 
     // XION authentificators, could be there could be not
     #[cfg(feature = "xion")]
@@ -34,6 +37,8 @@ pub mod ans_host {
     pub const REGISTERED_DEXES: &str = "be";
     pub const ASSET_PAIRINGS: &str = "bf";
     pub const POOL_METADATA: &str = "bg";
+    pub const ASSET_METADATA: &str = "bh";
+    pub const PROTOCOL_NAMESPACES: &str = "bi";
 }
 
 pub mod registry {
@@ -48,6 +53,10 @@ pub mod registry {
     pub const LOCAL_ACCOUNT_SEQUENCE: &str = "ci";
     pub const NAMESPACES: &str = "cj";
     pub const REV_NAMESPACES: &str = "ck";
+    pub const MODULE_SUBSCRIPTIONS: &str = "cl";
+    pub const AUDITORS: &str = "cm";
+    pub const MODULE_ATTESTATIONS: &str = "cn";
+    pub const PENDING_NAMESPACE_TRANSFERS: &str = "co";
 }
 
 pub mod module_factory {
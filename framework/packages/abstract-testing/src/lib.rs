@@ -1,6 +1,7 @@
 pub(crate) mod abstract_mock_querier;
 pub mod map_tester;
 pub mod mock_ans;
+pub mod property_tester;
 pub(crate) mod mock_querier;
 
 use abstract_std::account::{ConfigResponse as AccountConfigResponse, QueryMsg as AccountQueryMsg};
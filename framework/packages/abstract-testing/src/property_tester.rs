@@ -0,0 +1,373 @@
+//! Property testing of contract state invariants.
+//!
+//! [`CwPropertyTester`] executes random sequences of actions against fresh mock dependencies and checks
+//! a set of named invariants after every step. Actions that return an error are treated like failed
+//! transactions: their state changes are reverted before the invariants are checked.
+//! When an invariant is violated the failing sequence is shrunk to a minimal reproduction.
+use std::fmt::{Debug, Display};
+
+use cosmwasm_std::{Order, Response, Storage};
+
+use crate::MockDeps;
+
+/// Seed used when none is provided, keeps test runs deterministic.
+pub const DEFAULT_SEED: u64 = 0xab57_7ac7;
+
+/// Deterministic pseudo-random number generator (SplitMix64) used to generate actions.
+#[derive(Debug, Clone)]
+pub struct TestRng {
+    state: u64,
+}
+
+impl TestRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in `0..upper`. Panics if `upper` is zero.
+    pub fn gen_range(&mut self, upper: usize) -> usize {
+        assert!(upper > 0, "empty range");
+        (self.next_u64() % upper as u64) as usize
+    }
+
+    pub fn gen_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// Random element of `items`. Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.gen_range(items.len())]
+    }
+}
+
+/// Invariant violation, with the minimal sequence of actions that reproduces it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyFailure<Action> {
+    /// Seed of the failing case, can be passed to [`CwPropertyTester::seed`] to replay it.
+    pub seed: u64,
+    /// Name of the violated invariant
+    pub invariant: String,
+    /// Reason reported by the invariant
+    pub reason: String,
+    /// Length of the sequence before shrinking
+    pub original_len: usize,
+    /// Shrunk sequence of actions
+    pub sequence: Vec<Action>,
+}
+
+impl<Action: Debug> Display for PropertyFailure<Action> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "invariant \"{}\" violated: {}",
+            self.invariant, self.reason
+        )?;
+        writeln!(
+            f,
+            "minimal sequence ({} of {} steps, seed {}):",
+            self.sequence.len(),
+            self.original_len,
+            self.seed
+        )?;
+        for (i, action) in self.sequence.iter().enumerate() {
+            writeln!(f, "  {i}: {action:?}")?;
+        }
+        Ok(())
+    }
+}
+
+type Setup<'a> = Box<dyn Fn() -> MockDeps + 'a>;
+type Generator<'a, Action> = Box<dyn Fn(&mut TestRng) -> Action + 'a>;
+type Execute<'a, Action, TError> =
+    Box<dyn Fn(&mut MockDeps, Action) -> Result<Response, TError> + 'a>;
+type Invariant<'a> = Box<dyn Fn(&MockDeps) -> Result<(), String> + 'a>;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Violation {
+    /// Amount of actions executed before the violation
+    steps: usize,
+    invariant: String,
+    reason: String,
+}
+
+pub struct CwPropertyTester<'a, Action, TError> {
+    setup: Setup<'a>,
+    generator: Generator<'a, Action>,
+    execute: Execute<'a, Action, TError>,
+    invariants: Vec<(String, Invariant<'a>)>,
+    cases: usize,
+    max_steps: usize,
+    seed: u64,
+}
+
+impl<'a, Action, TError> CwPropertyTester<'a, Action, TError>
+where
+    Action: Clone + Debug,
+{
+    /// - `setup` creates the initial state, called for every (re-)played sequence.
+    /// - `generator` creates a random action, usually a sender and an `ExecuteMsg`.
+    /// - `execute` applies an action to the state.
+    pub fn new(
+        setup: impl Fn() -> MockDeps + 'a,
+        generator: impl Fn(&mut TestRng) -> Action + 'a,
+        execute: impl Fn(&mut MockDeps, Action) -> Result<Response, TError> + 'a,
+    ) -> Self {
+        Self {
+            setup: Box::new(setup),
+            generator: Box::new(generator),
+            execute: Box::new(execute),
+            invariants: vec![],
+            cases: 64,
+            max_steps: 32,
+            seed: DEFAULT_SEED,
+        }
+    }
+
+    /// Add an invariant that must hold after setup and after every step.
+    pub fn with_invariant(
+        mut self,
+        name: impl Into<String>,
+        invariant: impl Fn(&MockDeps) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.invariants.push((name.into(), Box::new(invariant)));
+        self
+    }
+
+    /// Amount of random sequences to test
+    pub fn cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Maximum length of a random sequence
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Run all cases, panics with the minimal failing sequence if an invariant is violated.
+    pub fn run(&self) {
+        if let Err(failure) = self.check() {
+            panic!("{failure}");
+        }
+    }
+
+    /// Run all cases and return the shrunk failure, if any.
+    pub fn check(&self) -> Result<(), PropertyFailure<Action>> {
+        for case in 0..self.cases as u64 {
+            let seed = self.seed.wrapping_add(case);
+            let mut rng = TestRng::new(seed);
+            let len = 1 + rng.gen_range(self.max_steps.max(1));
+            let sequence: Vec<Action> = (0..len).map(|_| (self.generator)(&mut rng)).collect();
+
+            if let Some(violation) = self.replay(&sequence) {
+                let original_len = violation.steps;
+                let (sequence, violation) =
+                    self.shrink(sequence[..violation.steps].to_vec(), violation);
+                return Err(PropertyFailure {
+                    seed,
+                    invariant: violation.invariant,
+                    reason: violation.reason,
+                    original_len,
+                    sequence,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Execute the sequence on a fresh state and return the first violation.
+    fn replay(&self, sequence: &[Action]) -> Option<Violation> {
+        let mut deps = (self.setup)();
+        if let Some(violation) = self.check_invariants(&deps, 0) {
+            return Some(violation);
+        }
+        for (i, action) in sequence.iter().enumerate() {
+            let snapshot = snapshot(&deps.storage);
+            if (self.execute)(&mut deps, action.clone()).is_err() {
+                // Failed transactions don't commit their state changes
+                restore(&mut deps.storage, snapshot);
+            }
+            if let Some(violation) = self.check_invariants(&deps, i + 1) {
+                return Some(violation);
+            }
+        }
+        None
+    }
+
+    fn check_invariants(&self, deps: &MockDeps, steps: usize) -> Option<Violation> {
+        self.invariants.iter().find_map(|(name, invariant)| {
+            invariant(deps).err().map(|reason| Violation {
+                steps,
+                invariant: name.clone(),
+                reason,
+            })
+        })
+    }
+
+    /// Remove chunks of actions, halving the chunk size, as long as the same invariant keeps failing.
+    fn shrink(
+        &self,
+        mut sequence: Vec<Action>,
+        mut violation: Violation,
+    ) -> (Vec<Action>, Violation) {
+        let mut chunk = (sequence.len() / 2).max(1);
+        loop {
+            let mut start = 0;
+            while start < sequence.len() {
+                let end = (start + chunk).min(sequence.len());
+                let mut candidate = sequence[..start].to_vec();
+                candidate.extend_from_slice(&sequence[end..]);
+
+                match self.replay(&candidate) {
+                    Some(shrunk) if shrunk.invariant == violation.invariant => {
+                        candidate.truncate(shrunk.steps);
+                        sequence = candidate;
+                        violation = shrunk;
+                    }
+                    _ => start += chunk,
+                }
+            }
+            if chunk == 1 {
+                break;
+            }
+            chunk /= 2;
+        }
+        (sequence, violation)
+    }
+}
+
+fn snapshot(storage: &dyn Storage) -> Vec<(Vec<u8>, Vec<u8>)> {
+    storage.range(None, None, Order::Ascending).collect()
+}
+
+fn restore(storage: &mut dyn Storage, snapshot: Vec<(Vec<u8>, Vec<u8>)>) {
+    let keys: Vec<Vec<u8>> = storage
+        .range(None, None, Order::Ascending)
+        .map(|(key, _)| key)
+        .collect();
+    for key in keys {
+        storage.remove(&key);
+    }
+    for (key, value) in snapshot {
+        storage.set(&key, &value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{testing::mock_dependencies, StdError, StdResult};
+    use cw_storage_plus::Item;
+
+    use super::*;
+
+    const COUNTER: Item<u64> = Item::new("counter");
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Action {
+        Increment,
+        Reset,
+        /// Writes to storage but fails afterwards
+        Overflow,
+    }
+
+    fn setup() -> MockDeps {
+        let mut deps = mock_dependencies();
+        COUNTER.save(&mut deps.storage, &0).unwrap();
+        deps
+    }
+
+    fn generate(rng: &mut TestRng) -> Action {
+        rng.choose(&[Action::Increment, Action::Reset, Action::Overflow])
+            .clone()
+    }
+
+    fn execute(deps: &mut MockDeps, action: Action) -> StdResult<Response> {
+        match action {
+            Action::Increment => {
+                COUNTER.update(&mut deps.storage, |c| StdResult::Ok(c + 1))?;
+            }
+            Action::Reset => COUNTER.save(&mut deps.storage, &0)?,
+            Action::Overflow => {
+                COUNTER.save(&mut deps.storage, &u64::MAX)?;
+                return Err(StdError::generic_err("overflow"));
+            }
+        }
+        Ok(Response::new())
+    }
+
+    fn counter_below(max: u64) -> impl Fn(&MockDeps) -> Result<(), String> {
+        move |deps| {
+            let counter = COUNTER.load(&deps.storage).map_err(|e| e.to_string())?;
+            if counter < max {
+                Ok(())
+            } else {
+                Err(format!("counter is {counter}"))
+            }
+        }
+    }
+
+    #[test]
+    fn rng_is_deterministic() {
+        let mut a = TestRng::new(42);
+        let mut b = TestRng::new(42);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(TestRng::new(1).next_u64(), TestRng::new(2).next_u64());
+    }
+
+    #[test]
+    fn failed_actions_are_reverted() {
+        CwPropertyTester::new(setup, generate, execute)
+            .with_invariant("counter never overflows", counter_below(u64::MAX))
+            .run();
+    }
+
+    #[test]
+    fn shrinks_to_minimal_sequence() {
+        let failure = CwPropertyTester::new(setup, generate, execute)
+            .with_invariant("counter below 3", counter_below(3))
+            .max_steps(64)
+            .check()
+            .unwrap_err();
+
+        assert_eq!(failure.invariant, "counter below 3");
+        assert_eq!(failure.reason, "counter is 3");
+        assert_eq!(failure.sequence, vec![Action::Increment; 3]);
+        assert!(failure.original_len >= 3);
+
+        // The reported seed reproduces the failure
+        let replayed = CwPropertyTester::new(setup, generate, execute)
+            .with_invariant("counter below 3", counter_below(3))
+            .max_steps(64)
+            .seed(failure.seed)
+            .cases(1)
+            .check()
+            .unwrap_err();
+        assert_eq!(replayed, failure);
+    }
+
+    #[test]
+    fn checks_initial_state() {
+        let failure = CwPropertyTester::new(setup, generate, execute)
+            .with_invariant("counter below 0", counter_below(0))
+            .check()
+            .unwrap_err();
+
+        assert!(failure.sequence.is_empty());
+    }
+}
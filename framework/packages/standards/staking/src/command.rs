@@ -1,5 +1,8 @@
 use abstract_sdk::feature_objects::{AnsHost, RegistryContract};
-use abstract_std::objects::{AnsAsset, AssetEntry, ContractEntry};
+use abstract_std::{
+    ans_host::{ContractListResponse, QueryMsg as AnsQueryMsg},
+    objects::{AnsAsset, AssetEntry, ContractEntry},
+};
 use cosmwasm_std::{Addr, CosmosMsg, Deps, Env, QuerierWrapper};
 
 use crate::{
@@ -7,13 +10,53 @@ use crate::{
     CwStakingError, Identify,
 };
 
+/// Prefix of the ANS contract entries of staking contracts, see [`CwStakingCommand::staking_entry`]
+pub const STAKING_CONTRACT_PREFIX: &str = "staking/";
+
 /// Trait that defines the staking commands for providers
 pub trait CwStakingCommand: Identify {
     /// Construct a staking contract entry from the staking token and the provider
     fn staking_entry(&self, staking_token: &AssetEntry) -> ContractEntry {
         ContractEntry {
             protocol: self.name().to_string(),
-            contract: format!("staking/{staking_token}"),
+            contract: format!("{STAKING_CONTRACT_PREFIX}{staking_token}"),
+        }
+    }
+
+    /// List the staking tokens of this provider that are registered in the ANS.
+    /// By default these are the tokens that have a [`Self::staking_entry`] registered.
+    fn staking_tokens(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+    ) -> Result<Vec<AssetEntry>, CwStakingError> {
+        let mut staking_tokens = vec![];
+        // Contract entries are ordered by protocol first, so all the staking entries of this provider are contiguous.
+        let mut start_after = ContractEntry {
+            protocol: self.name().to_string(),
+            contract: STAKING_CONTRACT_PREFIX.to_string(),
+        };
+        loop {
+            let ContractListResponse { contracts } = querier.query_wasm_smart(
+                &ans_host.address,
+                &AnsQueryMsg::ContractList {
+                    filter: None,
+                    start_after: Some(start_after.clone()),
+                    limit: None,
+                },
+            )?;
+            if contracts.is_empty() {
+                return Ok(staking_tokens);
+            }
+            for (entry, _) in contracts {
+                match entry.contract.strip_prefix(STAKING_CONTRACT_PREFIX) {
+                    Some(token) if entry.protocol == self.name() => {
+                        staking_tokens.push(AssetEntry::new(token))
+                    }
+                    _ => return Ok(staking_tokens),
+                }
+                start_after = entry;
+            }
         }
     }
 
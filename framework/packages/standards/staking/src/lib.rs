@@ -3,7 +3,7 @@ pub mod error;
 pub mod msg;
 // Export interface for use in SDK modules
 pub use abstract_adapter_utils::{coins_in_assets, cw_approve_msgs, Identify};
-pub use command::CwStakingCommand;
+pub use command::{CwStakingCommand, STAKING_CONTRACT_PREFIX};
 pub use error::CwStakingError;
 
 pub const CW_STAKING_ADAPTER_ID: &str = "abstract:cw-staking";
@@ -111,6 +111,16 @@ pub enum StakingQueryMsg {
         /// The staking tokens to query
         staking_tokens: Vec<AssetEntry>,
     },
+    /// Get all the staked, unbonding and claimable positions of a staker
+    /// for every staking token that the providers have registered in the ANS.
+    /// Returns [`PositionsResponse`]
+    #[returns(PositionsResponse)]
+    Positions {
+        /// The address of the staker (contract or user)
+        staker_address: String,
+        /// Names of the providers to query, all providers available on this chain if not set.
+        providers: Option<Vec<ProviderName>>,
+    },
 }
 
 use cosmwasm_std::{Addr, Uint128};
@@ -210,3 +220,36 @@ pub struct Claim {
     /// When the tokens can be claimed
     pub claimable_at: Expiration,
 }
+
+/// Response for the positions query
+#[cosmwasm_schema::cw_serde]
+pub struct PositionsResponse {
+    /// Non-empty positions of the staker
+    pub positions: Vec<StakingPosition>,
+}
+
+/// Position of a staker for a staking token of a provider
+#[cosmwasm_schema::cw_serde]
+pub struct StakingPosition {
+    /// Name of the provider
+    pub provider: ProviderName,
+    /// Staked token
+    pub staking_token: AssetEntry,
+    /// Staked amounts, per unbonding period
+    pub staked: Vec<StakedAmount>,
+    /// Unbonding entries
+    pub unbonding: Vec<Claim>,
+    /// Amount of unbonded tokens that can be claimed with [`StakingAction::Claim`]
+    pub claimable: Uint128,
+    /// Tokens that are distributed as rewards and can be claimed with [`StakingAction::ClaimRewards`]
+    pub reward_tokens: Vec<AssetInfo>,
+}
+
+/// Amount staked for an unbonding period
+#[cosmwasm_schema::cw_serde]
+pub struct StakedAmount {
+    /// The unbonding period of the stake
+    pub unbonding_period: Option<Duration>,
+    /// Amount of staked tokens
+    pub amount: Uint128,
+}
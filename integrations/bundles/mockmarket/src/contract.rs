@@ -0,0 +1,401 @@
+//! Contract logic of the mock red bank.
+//! It serves as both the lending market and the price oracle of the mockmarket protocol.
+use cosmwasm_std::{
+    ensure, to_json_binary, Addr, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, Fraction,
+    MessageInfo, Order, OverflowError, Response, StdError, StdResult, Uint128,
+};
+use cw_storage_plus::{Item, Map};
+use thiserror::Error;
+
+use crate::msg::{
+    ExecuteMsg, InstantiateMsg, MarketParams, PriceResponse, QueryMsg, UserCollateralResponse,
+    UserDebtResponse, UserPositionResponse,
+};
+
+const OWNER: Item<Addr> = Item::new("owner");
+const MARKETS: Map<&str, MarketParams> = Map::new("markets");
+const PRICES: Map<&str, Decimal> = Map::new("prices");
+const COLLATERALS: Map<(&Addr, &str), Uint128> = Map::new("collaterals");
+const DEBTS: Map<(&Addr, &str), Uint128> = Map::new("debts");
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MockMarketError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error(transparent)]
+    Payment(#[from] cw_utils::PaymentError),
+
+    #[error("Only the market owner can do this")]
+    Unauthorized {},
+
+    #[error("Asset {0} is not listed on the market")]
+    UnknownAsset(String),
+
+    #[error("No price set for {0}")]
+    PriceNotSet(String),
+
+    #[error("Invalid market parameters, expected max_ltv <= liquidation_threshold <= 1")]
+    InvalidParams {},
+
+    #[error("Price must be positive")]
+    ZeroPrice {},
+
+    #[error("No funds sent")]
+    NoFunds {},
+
+    #[error("Not enough collateral to withdraw")]
+    InsufficientCollateral {},
+
+    #[error("Not enough liquidity in the market")]
+    InsufficientLiquidity {},
+
+    #[error("Position would exceed its max loan-to-value")]
+    Unhealthy {},
+
+    #[error("No debt to repay")]
+    NoDebt {},
+
+    #[error("Position is healthy and can't be liquidated")]
+    NotLiquidatable {},
+}
+
+pub type MockMarketResult<T = Response> = Result<T, MockMarketError>;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> MockMarketResult {
+    let owner = deps.api.addr_validate(&msg.owner)?;
+    OWNER.save(deps.storage, &owner)?;
+    Ok(Response::new())
+}
+
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> MockMarketResult {
+    match msg {
+        ExecuteMsg::InitAsset { denom, params } => init_asset(deps, info, denom, params),
+        ExecuteMsg::SetPrice { denom, price } => set_price(deps, info, denom, price),
+        ExecuteMsg::Deposit { on_behalf_of } => deposit(deps, info, on_behalf_of),
+        ExecuteMsg::Withdraw {
+            denom,
+            amount,
+            recipient,
+        } => withdraw(deps, info, denom, amount, recipient),
+        ExecuteMsg::Borrow {
+            denom,
+            amount,
+            recipient,
+        } => borrow(deps, env, info, denom, amount, recipient),
+        ExecuteMsg::Repay { on_behalf_of } => repay(deps, info, on_behalf_of),
+        ExecuteMsg::Liquidate {
+            user,
+            collateral_denom,
+            recipient,
+        } => liquidate(deps, info, user, collateral_denom, recipient),
+    }
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Market { denom } => to_json_binary(&MARKETS.load(deps.storage, &denom)?),
+        QueryMsg::Price { denom } => to_json_binary(&PriceResponse {
+            price: PRICES.load(deps.storage, &denom)?,
+            denom,
+        }),
+        QueryMsg::UserCollateral { user, denom } => {
+            let user = deps.api.addr_validate(&user)?;
+            to_json_binary(&UserCollateralResponse {
+                amount: COLLATERALS
+                    .may_load(deps.storage, (&user, &denom))?
+                    .unwrap_or_default(),
+                denom,
+            })
+        }
+        QueryMsg::UserDebt { user, denom } => {
+            let user = deps.api.addr_validate(&user)?;
+            to_json_binary(&UserDebtResponse {
+                amount: DEBTS
+                    .may_load(deps.storage, (&user, &denom))?
+                    .unwrap_or_default(),
+                denom,
+            })
+        }
+        QueryMsg::UserPosition { user } => {
+            let user = deps.api.addr_validate(&user)?;
+            let position = user_position(deps, &user).map_err(|e| match e {
+                MockMarketError::Std(e) => e,
+                e => StdError::generic_err(e.to_string()),
+            })?;
+            to_json_binary(&position)
+        }
+    }
+}
+
+fn init_asset(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    params: MarketParams,
+) -> MockMarketResult {
+    assert_owner(deps.as_ref(), &info.sender)?;
+    ensure!(
+        params.max_ltv <= params.liquidation_threshold
+            && params.liquidation_threshold <= Decimal::one(),
+        MockMarketError::InvalidParams {}
+    );
+    MARKETS.save(deps.storage, &denom, &params)?;
+    Ok(Response::new().add_attribute("action", "init_asset"))
+}
+
+fn set_price(deps: DepsMut, info: MessageInfo, denom: String, price: Decimal) -> MockMarketResult {
+    assert_owner(deps.as_ref(), &info.sender)?;
+    ensure!(!price.is_zero(), MockMarketError::ZeroPrice {});
+    PRICES.save(deps.storage, &denom, &price)?;
+    Ok(Response::new().add_attribute("action", "set_price"))
+}
+
+fn deposit(deps: DepsMut, info: MessageInfo, on_behalf_of: Option<String>) -> MockMarketResult {
+    ensure!(!info.funds.is_empty(), MockMarketError::NoFunds {});
+    let user = maybe_addr(deps.as_ref(), on_behalf_of, &info.sender)?;
+    for coin in info.funds {
+        assert_listed(deps.as_ref(), &coin.denom)?;
+        COLLATERALS.update(
+            deps.storage,
+            (&user, &coin.denom),
+            |current| -> MockMarketResult<_> {
+                Ok(current.unwrap_or_default().checked_add(coin.amount)?)
+            },
+        )?;
+    }
+    Ok(Response::new().add_attribute("action", "deposit"))
+}
+
+fn withdraw(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    amount: Option<Uint128>,
+    recipient: Option<String>,
+) -> MockMarketResult {
+    let recipient = maybe_addr(deps.as_ref(), recipient, &info.sender)?;
+    let current = COLLATERALS
+        .may_load(deps.storage, (&info.sender, &denom))?
+        .unwrap_or_default();
+    let amount = amount.unwrap_or(current);
+    ensure!(
+        !amount.is_zero() && amount <= current,
+        MockMarketError::InsufficientCollateral {}
+    );
+    save_or_remove(
+        deps.storage,
+        COLLATERALS,
+        (&info.sender, &denom),
+        current - amount,
+    )?;
+    assert_healthy(deps.as_ref(), &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw")
+        .add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(amount, denom)],
+        }))
+}
+
+fn borrow(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+    recipient: Option<String>,
+) -> MockMarketResult {
+    assert_listed(deps.as_ref(), &denom)?;
+    let recipient = maybe_addr(deps.as_ref(), recipient, &info.sender)?;
+    let liquidity = deps
+        .querier
+        .query_balance(&env.contract.address, &denom)?
+        .amount;
+    ensure!(
+        amount <= liquidity,
+        MockMarketError::InsufficientLiquidity {}
+    );
+    DEBTS.update(
+        deps.storage,
+        (&info.sender, &denom),
+        |current| -> MockMarketResult<_> { Ok(current.unwrap_or_default().checked_add(amount)?) },
+    )?;
+    assert_healthy(deps.as_ref(), &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "borrow")
+        .add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(amount, denom)],
+        }))
+}
+
+fn repay(deps: DepsMut, info: MessageInfo, on_behalf_of: Option<String>) -> MockMarketResult {
+    let coin = cw_utils::one_coin(&info)?;
+    let user = maybe_addr(deps.as_ref(), on_behalf_of, &info.sender)?;
+    let repaid = reduce_debt(deps, &user, &coin)?;
+
+    let mut response = Response::new().add_attribute("action", "repay");
+    if let Some(refund) = refund(&info.sender, &coin, repaid) {
+        response = response.add_message(refund);
+    }
+    Ok(response)
+}
+
+fn liquidate(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    user: String,
+    collateral_denom: String,
+    recipient: Option<String>,
+) -> MockMarketResult {
+    let coin = cw_utils::one_coin(&info)?;
+    let user = deps.api.addr_validate(&user)?;
+    let recipient = maybe_addr(deps.as_ref(), recipient, &info.sender)?;
+    ensure!(
+        user_position(deps.as_ref(), &user)?.is_liquidatable(),
+        MockMarketError::NotLiquidatable {}
+    );
+
+    let debt_price = load_price(deps.as_ref(), &coin.denom)?;
+    let collateral_price = load_price(deps.as_ref(), &collateral_denom)?;
+    let repaid = reduce_debt(deps.branch(), &user, &coin)?;
+
+    // The liquidator receives collateral of the same value as the repaid debt
+    let repaid_value = repaid.mul_floor(debt_price);
+    let user_collateral = COLLATERALS
+        .may_load(deps.storage, (&user, &collateral_denom))?
+        .unwrap_or_default();
+    let seized = repaid_value
+        .multiply_ratio(collateral_price.denominator(), collateral_price.numerator())
+        .min(user_collateral);
+    save_or_remove(
+        deps.storage,
+        COLLATERALS,
+        (&user, &collateral_denom),
+        user_collateral - seized,
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "liquidate")
+        .add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin::new(seized, collateral_denom)],
+        });
+    if let Some(refund) = refund(&info.sender, &coin, repaid) {
+        response = response.add_message(refund);
+    }
+    Ok(response)
+}
+
+/// Computes the position of a user, valued at the oracle prices
+pub fn user_position(deps: Deps, user: &Addr) -> MockMarketResult<UserPositionResponse> {
+    let mut position = UserPositionResponse {
+        total_enabled_collateral: Uint128::zero(),
+        total_collateralized_debt: Uint128::zero(),
+        weighted_max_ltv_collateral: Uint128::zero(),
+        weighted_liquidation_threshold_collateral: Uint128::zero(),
+    };
+
+    let collaterals = COLLATERALS
+        .prefix(user)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (denom, amount) in collaterals {
+        let params = MARKETS.load(deps.storage, &denom)?;
+        let value = amount.mul_floor(load_price(deps, &denom)?);
+        position.total_enabled_collateral += value;
+        position.weighted_max_ltv_collateral += value.mul_floor(params.max_ltv);
+        position.weighted_liquidation_threshold_collateral +=
+            value.mul_floor(params.liquidation_threshold);
+    }
+
+    let debts = DEBTS
+        .prefix(user)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (denom, amount) in debts {
+        position.total_collateralized_debt += amount.mul_ceil(load_price(deps, &denom)?);
+    }
+
+    Ok(position)
+}
+
+fn reduce_debt(deps: DepsMut, user: &Addr, coin: &Coin) -> MockMarketResult<Uint128> {
+    let debt = DEBTS
+        .may_load(deps.storage, (user, &coin.denom))?
+        .unwrap_or_default();
+    ensure!(!debt.is_zero(), MockMarketError::NoDebt {});
+    let repaid = coin.amount.min(debt);
+    save_or_remove(deps.storage, DEBTS, (user, &coin.denom), debt - repaid)?;
+    Ok(repaid)
+}
+
+fn refund(sender: &Addr, coin: &Coin, used: Uint128) -> Option<BankMsg> {
+    let excess = coin.amount - used;
+    (!excess.is_zero()).then(|| BankMsg::Send {
+        to_address: sender.to_string(),
+        amount: vec![Coin::new(excess, coin.denom.clone())],
+    })
+}
+
+fn save_or_remove(
+    storage: &mut dyn cosmwasm_std::Storage,
+    map: Map<(&Addr, &str), Uint128>,
+    key: (&Addr, &str),
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        map.remove(storage, key);
+        Ok(())
+    } else {
+        map.save(storage, key, &amount)
+    }
+}
+
+fn assert_owner(deps: Deps, sender: &Addr) -> MockMarketResult<()> {
+    ensure!(
+        OWNER.load(deps.storage)? == sender,
+        MockMarketError::Unauthorized {}
+    );
+    Ok(())
+}
+
+fn assert_listed(deps: Deps, denom: &str) -> MockMarketResult<()> {
+    ensure!(
+        MARKETS.has(deps.storage, denom),
+        MockMarketError::UnknownAsset(denom.to_owned())
+    );
+    Ok(())
+}
+
+fn assert_healthy(deps: Deps, user: &Addr) -> MockMarketResult<()> {
+    let position = user_position(deps, user)?;
+    ensure!(
+        position.total_collateralized_debt <= position.weighted_max_ltv_collateral,
+        MockMarketError::Unhealthy {}
+    );
+    Ok(())
+}
+
+fn load_price(deps: Deps, denom: &str) -> MockMarketResult<Decimal> {
+    PRICES
+        .may_load(deps.storage, denom)?
+        .ok_or_else(|| MockMarketError::PriceNotSet(denom.to_owned()))
+}
+
+fn maybe_addr(deps: Deps, addr: Option<String>, default: &Addr) -> StdResult<Addr> {
+    addr.map(|addr| deps.api.addr_validate(&addr))
+        .transpose()
+        .map(|addr| addr.unwrap_or_else(|| default.clone()))
+}
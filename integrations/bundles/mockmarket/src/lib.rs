@@ -0,0 +1,155 @@
+//! Mock lending market, modeled after the Mars red bank, used to test money-market integrations offline.
+pub mod contract;
+pub mod money_market;
+pub mod msg;
+
+use std::fmt::Debug;
+
+use abstract_interface::{Abstract, AbstractInterfaceError};
+use abstract_std::{ans_host::ExecuteMsgFns as _, objects::UncheckedContractEntry};
+use cosmwasm_std::{coin, Decimal};
+use cw_orch::{mock::cw_multi_test::ContractWrapper, prelude::*};
+
+use self::msg::{ExecuteMsg, ExecuteMsgFns as _, InstantiateMsg, MarketParams, QueryMsg};
+
+pub const MOCKMARKET: &str = "mockmarket";
+pub const RED_BANK: &str = "red-bank";
+pub const ORACLE: &str = "oracle";
+pub const RED_BANK_ID: &str = "mockmarket:red-bank";
+pub const MOCKMARKET_OWNER: &str = "mockmarket_owner";
+pub const EUR: &str = "eur";
+pub const USD: &str = "usd";
+/// Liquidity of each asset available to borrowers after deployment
+pub const INITIAL_LIQUIDITY: u128 = 1_000_000;
+
+#[cw_orch::interface(InstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+pub struct MockRedBank;
+
+impl<Chain: CwEnv> Uploadable for MockRedBank<Chain> {
+    fn wrapper() -> <Mock as TxHandler>::ContractSource {
+        Box::new(ContractWrapper::new_with_empty(
+            contract::execute,
+            contract::instantiate,
+            contract::query,
+        ))
+    }
+}
+
+pub struct MockMarket {
+    /// Lending market and price oracle of the mock protocol
+    pub red_bank: MockRedBank<MockBech32>,
+    pub owner: Addr,
+}
+
+impl PartialEq for MockMarket {
+    fn eq(&self, other: &Self) -> bool {
+        self.red_bank.address().ok() == other.red_bank.address().ok() && self.owner == other.owner
+    }
+}
+
+impl Debug for MockMarket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockMarket")
+            .field("red_bank", &self.red_bank.address().ok())
+            .field("owner", &self.owner)
+            .finish()
+    }
+}
+
+/// Deploys the red bank, lists EUR and USD at a price of 1,
+/// provides some liquidity for both and registers the protocol on Abstract.
+impl Deploy<MockBech32> for MockMarket {
+    type Error = AbstractInterfaceError;
+    type DeployData = Empty;
+
+    fn store_on(chain: MockBech32) -> Result<Self, Self::Error> {
+        let owner = chain.addr_make(MOCKMARKET_OWNER);
+        let red_bank = MockRedBank::new(RED_BANK_ID, chain.clone());
+        red_bank.upload()?;
+        red_bank.instantiate(
+            &InstantiateMsg {
+                owner: owner.to_string(),
+            },
+            Some(&owner),
+            &[],
+        )?;
+
+        let params = MarketParams {
+            max_ltv: Decimal::percent(75),
+            liquidation_threshold: Decimal::percent(80),
+        };
+        let owned_bank = red_bank.call_as(&owner);
+        for denom in [EUR, USD] {
+            owned_bank.init_asset(denom, params.clone())?;
+            owned_bank.set_price(denom, Decimal::one())?;
+        }
+
+        // Liquidity lent out to borrowers
+        chain.set_balance(
+            &red_bank.address()?,
+            vec![coin(INITIAL_LIQUIDITY, EUR), coin(INITIAL_LIQUIDITY, USD)],
+        )?;
+
+        let mockmarket = Self { red_bank, owner };
+
+        // register contracts in abstract host
+        let abstract_ = Abstract::load_from(chain)?;
+        mockmarket.register_info_on_abstract(&abstract_)?;
+
+        Ok(mockmarket)
+    }
+
+    fn load_from(chain: MockBech32) -> Result<Self, Self::Error> {
+        Ok(Self {
+            owner: chain.addr_make(MOCKMARKET_OWNER),
+            red_bank: MockRedBank::new(RED_BANK_ID, chain),
+        })
+    }
+
+    fn get_contracts_mut(&mut self) -> Vec<Box<&mut dyn ContractInstance<MockBech32>>> {
+        vec![Box::new(&mut self.red_bank)]
+    }
+}
+
+impl MockMarket {
+    /// Sets the oracle price of an asset, used to move positions in and out of liquidation range.
+    pub fn set_price(&self, denom: &str, price: Decimal) -> Result<(), AbstractInterfaceError> {
+        self.red_bank.call_as(&self.owner).set_price(denom, price)?;
+        Ok(())
+    }
+
+    /// registers the mockmarket contracts and assets on Abstract
+    /// this includes:
+    /// - registering the assets on ANS
+    ///   - EUR
+    ///   - USD
+    /// - Register the contracts, both served by the red bank
+    ///   - mockmarket:red-bank
+    ///   - mockmarket:oracle
+    pub(crate) fn register_info_on_abstract(
+        &self,
+        abstrct: &Abstract<MockBech32>,
+    ) -> Result<(), CwOrchError> {
+        abstrct.ans_host.update_asset_addresses(
+            vec![
+                (EUR.to_string(), cw_asset::AssetInfoBase::native(EUR)),
+                (USD.to_string(), cw_asset::AssetInfoBase::native(USD)),
+            ],
+            vec![],
+        )?;
+
+        let red_bank = self.red_bank.addr_str()?;
+        abstrct.ans_host.update_contract_addresses(
+            vec![
+                (
+                    UncheckedContractEntry::new(MOCKMARKET, RED_BANK),
+                    red_bank.clone(),
+                ),
+                (UncheckedContractEntry::new(MOCKMARKET, ORACLE), red_bank),
+            ],
+            vec![],
+        )?;
+
+        Ok(())
+    }
+}
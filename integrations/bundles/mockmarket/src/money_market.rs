@@ -0,0 +1,299 @@
+use abstract_money_market_standard::{Identify, MoneyMarketCommand, MoneyMarketError};
+use abstract_sdk::{
+    feature_objects::AnsHost,
+    std::objects::{ans_host::AnsHostError, AssetEntry, ContractEntry},
+};
+use cosmwasm_std::{wasm_execute, Addr, CosmosMsg, Decimal, Deps, QuerierWrapper, Uint128};
+use cw_asset::{Asset, AssetInfo};
+
+use crate::{
+    msg::{
+        ExecuteMsg, PriceResponse, QueryMsg, UserCollateralResponse, UserDebtResponse,
+        UserPositionResponse,
+    },
+    MOCKMARKET, ORACLE, RED_BANK,
+};
+
+/// [`MoneyMarketCommand`] implementation for the mock red bank
+#[derive(Default)]
+pub struct MockMarketProtocol {
+    pub oracle_contract: Option<Addr>,
+}
+
+impl Identify for MockMarketProtocol {
+    fn name(&self) -> &'static str {
+        MOCKMARKET
+    }
+    fn is_available_on(&self, chain_name: &str) -> bool {
+        abstract_sdk::std::registry::LOCAL_CHAIN.contains(&chain_name)
+    }
+}
+
+impl MoneyMarketCommand for MockMarketProtocol {
+    fn fetch_data(
+        &mut self,
+        _addr_as_sender: Addr,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+    ) -> Result<(), MoneyMarketError> {
+        self.oracle_contract = Some(ans_host.query_contract(querier, &contract_entry(ORACLE))?);
+        Ok(())
+    }
+
+    fn deposit(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = ExecuteMsg::Deposit { on_behalf_of: None };
+        let msg = wasm_execute(contract_addr, &msg, vec![asset.try_into()?])?;
+        Ok(vec![msg.into()])
+    }
+
+    fn withdraw(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        lending_asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = ExecuteMsg::Withdraw {
+            denom: unwrap_native(lending_asset.info)?,
+            amount: Some(lending_asset.amount),
+            recipient: None,
+        };
+        let msg = wasm_execute(contract_addr, &msg, vec![])?;
+        Ok(vec![msg.into()])
+    }
+
+    fn provide_collateral(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        // Deposits are used as collateral
+        self.deposit(deps, contract_addr, asset)
+    }
+
+    fn withdraw_collateral(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        self.withdraw(deps, contract_addr, asset)
+    }
+
+    fn borrow(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = ExecuteMsg::Borrow {
+            denom: unwrap_native(asset.info)?,
+            amount: asset.amount,
+            recipient: None,
+        };
+        let msg = wasm_execute(contract_addr, &msg, vec![])?;
+        Ok(vec![msg.into()])
+    }
+
+    fn repay(
+        &self,
+        _deps: Deps,
+        contract_addr: Addr,
+        asset: Asset,
+    ) -> Result<Vec<CosmosMsg>, MoneyMarketError> {
+        let msg = ExecuteMsg::Repay { on_behalf_of: None };
+        let msg = wasm_execute(contract_addr, &msg, vec![asset.try_into()?])?;
+        Ok(vec![msg.into()])
+    }
+
+    fn price(
+        &self,
+        deps: Deps,
+        base: AssetInfo,
+        quote: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        let oracle_contract = self
+            .oracle_contract
+            .as_ref()
+            .ok_or(MoneyMarketError::ContractAddressEmpty)?;
+        let base_price: PriceResponse = deps.querier.query_wasm_smart(
+            oracle_contract,
+            &QueryMsg::Price {
+                denom: unwrap_native(base)?,
+            },
+        )?;
+        let quote_price: PriceResponse = deps.querier.query_wasm_smart(
+            oracle_contract,
+            &QueryMsg::Price {
+                denom: unwrap_native(quote)?,
+            },
+        )?;
+
+        Ok(base_price.price.checked_div(quote_price.price)?)
+    }
+
+    fn user_deposit(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        asset: AssetInfo,
+    ) -> Result<Uint128, MoneyMarketError> {
+        let response: UserCollateralResponse = deps.querier.query_wasm_smart(
+            contract_addr,
+            &QueryMsg::UserCollateral {
+                user: user.to_string(),
+                denom: unwrap_native(asset)?,
+            },
+        )?;
+        Ok(response.amount)
+    }
+
+    fn user_collateral(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo,
+        collateral_asset: AssetInfo,
+    ) -> Result<Uint128, MoneyMarketError> {
+        self.user_deposit(deps, contract_addr, user, collateral_asset)
+    }
+
+    fn user_borrow(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        borrowed_asset: AssetInfo,
+        _collateral_asset: AssetInfo,
+    ) -> Result<Uint128, MoneyMarketError> {
+        let response: UserDebtResponse = deps.querier.query_wasm_smart(
+            contract_addr,
+            &QueryMsg::UserDebt {
+                user: user.to_string(),
+                denom: unwrap_native(borrowed_asset)?,
+            },
+        )?;
+        Ok(response.amount)
+    }
+
+    fn current_ltv(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo, // LTV is computed over the whole position
+        _collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        let position = user_position(deps, contract_addr, user)?;
+        if position.total_enabled_collateral.is_zero() {
+            return Ok(Decimal::zero());
+        }
+        Ok(Decimal::from_ratio(
+            position.total_collateralized_debt,
+            position.total_enabled_collateral,
+        ))
+    }
+
+    fn max_ltv(
+        &self,
+        deps: Deps,
+        contract_addr: Addr,
+        user: Addr,
+        _borrowed_asset: AssetInfo, // LTV is computed over the whole position
+        _collateral_asset: AssetInfo,
+    ) -> Result<Decimal, MoneyMarketError> {
+        let position = user_position(deps, contract_addr, user)?;
+        if position.total_enabled_collateral.is_zero() {
+            return Ok(Decimal::zero());
+        }
+        Ok(Decimal::from_ratio(
+            position.weighted_max_ltv_collateral,
+            position.total_enabled_collateral,
+        ))
+    }
+
+    fn lending_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &contract_entry(RED_BANK))
+    }
+
+    fn collateral_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &contract_entry(RED_BANK))
+    }
+
+    fn borrow_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &contract_entry(RED_BANK))
+    }
+
+    fn max_ltv_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &contract_entry(RED_BANK))
+    }
+
+    fn current_ltv_address(
+        &self,
+        querier: &QuerierWrapper,
+        ans_host: &AnsHost,
+        _lending_asset: AssetEntry,
+        _collateral_asset: AssetEntry,
+    ) -> Result<Addr, AnsHostError> {
+        ans_host.query_contract(querier, &contract_entry(RED_BANK))
+    }
+}
+
+fn contract_entry(contract: &str) -> ContractEntry {
+    ContractEntry {
+        protocol: MOCKMARKET.to_string(),
+        contract: contract.to_string(),
+    }
+}
+
+fn user_position(
+    deps: Deps,
+    contract_addr: Addr,
+    user: Addr,
+) -> Result<UserPositionResponse, MoneyMarketError> {
+    deps.querier
+        .query_wasm_smart(
+            contract_addr,
+            &QueryMsg::UserPosition {
+                user: user.to_string(),
+            },
+        )
+        .map_err(Into::into)
+}
+
+fn unwrap_native(asset: AssetInfo) -> Result<String, MoneyMarketError> {
+    match asset {
+        cw_asset::AssetInfoBase::Native(denom) => Ok(denom),
+        _ => Err(MoneyMarketError::ExpectedNative {}),
+    }
+}
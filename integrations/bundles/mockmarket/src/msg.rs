@@ -0,0 +1,105 @@
+//! Messages of the mock red bank, a minimal lending market modeled after the Mars red bank.
+//! Deposits are used as collateral, the market owner sets prices and asset parameters.
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Decimal, Uint128};
+
+#[cosmwasm_schema::cw_serde]
+pub struct InstantiateMsg {
+    /// Address allowed to list assets and set prices
+    pub owner: String,
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// List an asset on the market
+    /// Only callable by the owner
+    InitAsset { denom: String, params: MarketParams },
+    /// Set the oracle price of an asset
+    /// Only callable by the owner
+    SetPrice { denom: String, price: Decimal },
+    /// Deposit the sent funds as collateral
+    #[cw_orch(payable)]
+    Deposit { on_behalf_of: Option<String> },
+    /// Withdraw collateral. Withdraws everything when `amount` is not set.
+    Withdraw {
+        denom: String,
+        amount: Option<Uint128>,
+        recipient: Option<String>,
+    },
+    /// Borrow an asset against the deposited collateral
+    Borrow {
+        denom: String,
+        amount: Uint128,
+        recipient: Option<String>,
+    },
+    /// Repay debt with the sent funds. Excess funds are refunded.
+    #[cw_orch(payable)]
+    Repay { on_behalf_of: Option<String> },
+    /// Repay the debt of an unhealthy position with the sent funds
+    /// and receive the same value of its collateral
+    #[cw_orch(payable)]
+    Liquidate {
+        user: String,
+        collateral_denom: String,
+        recipient: Option<String>,
+    },
+}
+
+#[cosmwasm_schema::cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    #[returns(MarketParams)]
+    Market { denom: String },
+    #[returns(PriceResponse)]
+    Price { denom: String },
+    #[returns(UserCollateralResponse)]
+    UserCollateral { user: String, denom: String },
+    #[returns(UserDebtResponse)]
+    UserDebt { user: String, denom: String },
+    #[returns(UserPositionResponse)]
+    UserPosition { user: String },
+}
+
+/// Risk parameters of a listed asset
+#[cosmwasm_schema::cw_serde]
+pub struct MarketParams {
+    /// Maximum loan-to-value when borrowing against this asset
+    pub max_ltv: Decimal,
+    /// Loan-to-value above which positions backed by this asset can be liquidated
+    pub liquidation_threshold: Decimal,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PriceResponse {
+    pub denom: String,
+    pub price: Decimal,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct UserCollateralResponse {
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct UserDebtResponse {
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+/// Position of a user, all values are denominated in the oracle base currency
+#[cosmwasm_schema::cw_serde]
+pub struct UserPositionResponse {
+    pub total_enabled_collateral: Uint128,
+    pub total_collateralized_debt: Uint128,
+    pub weighted_max_ltv_collateral: Uint128,
+    pub weighted_liquidation_threshold_collateral: Uint128,
+}
+
+impl UserPositionResponse {
+    /// Whether the debt exceeds the liquidation threshold of the collateral
+    pub fn is_liquidatable(&self) -> bool {
+        self.total_collateralized_debt > self.weighted_liquidation_threshold_collateral
+    }
+}
@@ -0,0 +1,125 @@
+use abstract_interface::Abstract;
+use cosmwasm_std::{coins, Decimal, Uint128};
+use cw_orch::prelude::*;
+use mockmarket_bundle::{
+    contract::MockMarketError,
+    msg::{ExecuteMsgFns, QueryMsgFns},
+    MockMarket, EUR, USD,
+};
+
+fn setup() -> anyhow::Result<(MockBech32, MockMarket)> {
+    let mock = MockBech32::new("mock");
+    Abstract::deploy_on(mock.clone(), ())?;
+    let mockmarket = MockMarket::deploy_on(mock.clone(), Empty {})?;
+    Ok((mock, mockmarket))
+}
+
+#[test]
+fn deploy() -> anyhow::Result<()> {
+    let (mock, deployed) = setup()?;
+    let loaded = MockMarket::load_from(mock)?;
+
+    assert_eq!(deployed, loaded);
+    Ok(())
+}
+
+#[test]
+fn borrow_against_collateral() -> anyhow::Result<()> {
+    let (mock, mockmarket) = setup()?;
+    let user = mock.addr_make_with_balance("user", coins(1_000, EUR))?;
+    let red_bank = mockmarket.red_bank.call_as(&user);
+
+    red_bank.deposit(None, &coins(1_000, EUR))?;
+    // Max LTV is 75%
+    let err = red_bank.borrow(Uint128::new(751), USD, None).unwrap_err();
+    assert_eq!(
+        err.downcast::<MockMarketError>()?,
+        MockMarketError::Unhealthy {}
+    );
+    red_bank.borrow(Uint128::new(750), USD, None)?;
+
+    assert_eq!(mock.query_balance(&user, USD)?, Uint128::new(750));
+    let position = red_bank.user_position(user.to_string())?;
+    assert_eq!(position.total_collateralized_debt, Uint128::new(750));
+    assert_eq!(position.weighted_max_ltv_collateral, Uint128::new(750));
+
+    // Collateral backing the debt can't be withdrawn
+    let err = red_bank
+        .withdraw(Some(Uint128::one()), EUR, None)
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<MockMarketError>()?,
+        MockMarketError::Unhealthy {}
+    );
+
+    // Excess repayment is refunded
+    mock.add_balance(&user, coins(50, USD))?;
+    red_bank.repay(None, &coins(800, USD))?;
+    assert_eq!(mock.query_balance(&user, USD)?, Uint128::new(50));
+    assert_eq!(
+        red_bank.user_debt(USD, user.to_string())?.amount,
+        Uint128::zero()
+    );
+
+    red_bank.withdraw(None, EUR, None)?;
+    assert_eq!(mock.query_balance(&user, EUR)?, Uint128::new(1_000));
+    Ok(())
+}
+
+#[test]
+fn liquidate_after_price_drop() -> anyhow::Result<()> {
+    let (mock, mockmarket) = setup()?;
+    let user = mock.addr_make_with_balance("user", coins(1_000, EUR))?;
+    let liquidator = mock.addr_make_with_balance("liquidator", coins(100, USD))?;
+
+    let red_bank = mockmarket.red_bank.call_as(&user);
+    red_bank.deposit(None, &coins(1_000, EUR))?;
+    red_bank.borrow(Uint128::new(700), USD, None)?;
+
+    // Healthy positions can't be liquidated
+    let err = mockmarket
+        .red_bank
+        .call_as(&liquidator)
+        .liquidate(EUR, None, user.to_string(), &coins(100, USD))
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<MockMarketError>()?,
+        MockMarketError::NotLiquidatable {}
+    );
+
+    // 700 debt > 850 * 80% liquidation threshold
+    mockmarket.set_price(EUR, Decimal::percent(85))?;
+    assert!(mockmarket
+        .red_bank
+        .user_position(user.to_string())?
+        .is_liquidatable());
+
+    mockmarket.red_bank.call_as(&liquidator).liquidate(
+        EUR,
+        None,
+        user.to_string(),
+        &coins(100, USD),
+    )?;
+
+    // 100 USD of debt is worth 117.6 EUR
+    assert_eq!(mock.query_balance(&liquidator, EUR)?, Uint128::new(117));
+    assert_eq!(
+        mockmarket
+            .red_bank
+            .user_collateral(EUR, user.to_string())?
+            .amount,
+        Uint128::new(883)
+    );
+    assert_eq!(
+        mockmarket.red_bank.user_debt(USD, user.to_string())?.amount,
+        Uint128::new(600)
+    );
+    assert_eq!(
+        mockmarket.red_bank.price(EUR)?,
+        mockmarket_bundle::msg::PriceResponse {
+            denom: EUR.to_string(),
+            price: Decimal::percent(85)
+        }
+    );
+    Ok(())
+}
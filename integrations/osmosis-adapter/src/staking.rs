@@ -27,9 +27,12 @@ impl Identify for Osmosis {
 pub mod fns {
     use std::str::FromStr;
 
-    use abstract_sdk::std::objects::{
-        ans_host::AnsHost, registry::RegistryContract, AnsAsset, AnsEntryConvertor, AssetEntry,
-        PoolReference, PoolType,
+    use abstract_sdk::std::{
+        ans_host::{AssetListResponse, QueryMsg as AnsQueryMsg},
+        objects::{
+            ans_host::AnsHost, registry::RegistryContract, AnsAsset, AnsEntryConvertor, AssetEntry,
+            PoolReference, PoolType,
+        },
     };
 
     use abstract_staking_standard::{
@@ -136,6 +139,39 @@ pub mod fns {
             Ok(())
         }
 
+        /// Osmosis has no staking contracts, the LP token of every Osmosis pool registered in the ANS can be staked.
+        fn staking_tokens(
+            &self,
+            querier: &QuerierWrapper,
+            ans_host: &AnsHost,
+        ) -> Result<Vec<AssetEntry>, CwStakingError> {
+            let prefix = format!("{}/", self.name());
+            let mut staking_tokens = vec![];
+            let mut start_after = prefix.clone();
+            loop {
+                let AssetListResponse { assets } = querier.query_wasm_smart(
+                    &ans_host.address,
+                    &AnsQueryMsg::AssetList {
+                        filter: None,
+                        start_after: Some(start_after.clone()),
+                        limit: None,
+                    },
+                )?;
+                if assets.is_empty() {
+                    return Ok(staking_tokens);
+                }
+                for (entry, _) in assets {
+                    if !entry.as_str().starts_with(&prefix) {
+                        return Ok(staking_tokens);
+                    }
+                    start_after = entry.to_string();
+                    if AnsEntryConvertor::new(entry.clone()).lp_token().is_ok() {
+                        staking_tokens.push(entry);
+                    }
+                }
+            }
+        }
+
         fn stake(
             &self,
             _deps: Deps,
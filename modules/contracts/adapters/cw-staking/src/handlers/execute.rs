@@ -102,6 +102,7 @@ fn handle_ibc_request(
                 provider: provider_name.clone(),
                 action: action.clone(),
             })?,
+            callback_id: None,
         })
     };
     let ibc_action_msg = ibc_client.host_action(host_chain, host_action)?;
@@ -1,6 +1,17 @@
-use abstract_adapter::sdk::features::{AbstractNameService, AbstractRegistryAccess};
-use abstract_staking_standard::{msg::StakingQueryMsg, CwStakingError};
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
+use abstract_adapter::sdk::{
+    feature_objects::{AnsHost, RegistryContract},
+    features::{AbstractNameService, AbstractRegistryAccess},
+};
+use abstract_adapter::std::objects::AssetEntry;
+use abstract_staking_standard::{
+    msg::{
+        PositionsResponse, ProviderName, RewardTokensResponse, StakedAmount, StakingInfoResponse,
+        StakingPosition, StakingQueryMsg, UnbondingResponse,
+    },
+    CwStakingCommand, CwStakingError,
+};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, StdError};
+use cw_utils::Duration;
 
 use crate::{
     contract::{CwStakingAdapter, StakingResult},
@@ -122,5 +133,344 @@ pub fn query_handler(
                 Ok(to_json_binary(&provider.query_rewards(&deps.querier)?)?)
             }
         }
+        StakingQueryMsg::Positions {
+            staker_address,
+            providers,
+        } => {
+            let staker = deps.api.addr_validate(&staker_address)?;
+            let providers = providers.unwrap_or_else(|| resolver::local_provider_names(&env));
+
+            let providers = providers
+                .into_iter()
+                .map(|provider_name| {
+                    // if provider is on an app-chain, error
+                    let (local_provider_name, is_over_ibc) = is_over_ibc(&env, &provider_name)?;
+                    if is_over_ibc {
+                        return Err(CwStakingError::IbcQueryNotSupported);
+                    }
+                    let provider = resolver::resolve_local_provider(&local_provider_name)
+                        .map_err(|e| StdError::generic_err(e.to_string()))?;
+                    Ok((local_provider_name, provider))
+                })
+                .collect::<StakingResult<Vec<_>>>()?;
+            let positions =
+                providers_positions(deps, &env, ans_host, &registry_contract, &staker, providers)?;
+            Ok(to_json_binary(&PositionsResponse { positions })?)
+        }
+    }
+}
+
+/// Query the positions of `staker` with each of the providers, in order.
+/// Providers without any registered staking tokens are skipped.
+fn providers_positions(
+    deps: Deps,
+    env: &Env,
+    ans_host: &AnsHost,
+    registry_contract: &RegistryContract,
+    staker: &Addr,
+    providers: Vec<(ProviderName, Box<dyn CwStakingCommand>)>,
+) -> StakingResult<Vec<StakingPosition>> {
+    let mut positions = vec![];
+    for (provider_name, mut provider) in providers {
+        let staking_tokens = provider.staking_tokens(&deps.querier, ans_host)?;
+        if staking_tokens.is_empty() {
+            continue;
+        }
+        provider.fetch_data(
+            deps,
+            env.clone(),
+            None,
+            ans_host,
+            registry_contract.clone(),
+            staking_tokens.clone(),
+        )?;
+        positions.extend(query_positions(
+            deps,
+            env,
+            provider.as_ref(),
+            provider_name,
+            staker,
+            staking_tokens,
+        )?);
+    }
+    Ok(positions)
+}
+
+/// Query the positions of `staker` for the given staking tokens of a provider.
+/// Positions without any staked or unbonding tokens are omitted.
+fn query_positions(
+    deps: Deps,
+    env: &Env,
+    provider: &dyn CwStakingCommand,
+    provider_name: ProviderName,
+    staker: &Addr,
+    staking_tokens: Vec<AssetEntry>,
+) -> StakingResult<Vec<StakingPosition>> {
+    let StakingInfoResponse { infos } = provider.query_info(&deps.querier)?;
+    let UnbondingResponse { claims } = provider.query_unbonding(&deps.querier, staker.clone())?;
+    let RewardTokensResponse { tokens } = provider.query_rewards(&deps.querier)?;
+
+    // Stakes are queried once per unbonding period that is supported by any of the tokens.
+    let mut unbonding_periods: Vec<Option<Duration>> = vec![];
+    for info in infos {
+        let periods = match info.unbonding_periods {
+            Some(periods) if !periods.is_empty() => periods.into_iter().map(Some).collect(),
+            _ => vec![None],
+        };
+        for period in periods {
+            if !unbonding_periods.contains(&period) {
+                unbonding_periods.push(period);
+            }
+        }
+    }
+    let staked_per_period = unbonding_periods
+        .into_iter()
+        .map(|period| {
+            let staked = provider.query_staked(
+                &deps.querier,
+                staker.clone(),
+                staking_tokens.clone(),
+                period,
+            )?;
+            Ok((period, staked.amounts))
+        })
+        .collect::<StakingResult<Vec<_>>>()?;
+
+    let positions = staking_tokens
+        .into_iter()
+        .zip(claims.into_iter().zip(tokens))
+        .enumerate()
+        .filter_map(|(i, (staking_token, (unbonding, reward_tokens)))| {
+            let staked: Vec<StakedAmount> = staked_per_period
+                .iter()
+                .filter_map(|(period, amounts)| {
+                    let amount = amounts.get(i).copied().unwrap_or_default();
+                    (!amount.is_zero()).then_some(StakedAmount {
+                        unbonding_period: *period,
+                        amount,
+                    })
+                })
+                .collect();
+            if staked.is_empty() && unbonding.is_empty() {
+                return None;
+            }
+            let claimable = unbonding
+                .iter()
+                .filter(|claim| claim.claimable_at.is_expired(&env.block))
+                .map(|claim| claim.amount)
+                .sum();
+            Some(StakingPosition {
+                provider: provider_name.clone(),
+                staking_token,
+                staked,
+                unbonding,
+                claimable,
+                reward_tokens,
+            })
+        })
+        .collect();
+    Ok(positions)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use abstract_adapter::abstract_testing::mock_env_validated;
+    use abstract_adapter::std::objects::AnsAsset;
+    use abstract_adapter_utils::Identify;
+    use abstract_staking_standard::msg::{StakeResponse, StakingInfo, StakingTarget};
+    use cosmwasm_std::{testing::mock_dependencies, CosmosMsg, QuerierWrapper, Uint128};
+    use cw_asset::AssetInfo;
+
+    /// Provider with fixed stakes, without any unbonding period.
+    struct FixedStakes {
+        name: &'static str,
+        stakes: Vec<(&'static str, u128)>,
+    }
+
+    impl Identify for FixedStakes {
+        fn is_available_on(&self, _chain_name: &str) -> bool {
+            true
+        }
+        fn name(&self) -> &'static str {
+            self.name
+        }
+    }
+
+    impl CwStakingCommand for FixedStakes {
+        fn staking_tokens(
+            &self,
+            _querier: &QuerierWrapper,
+            _ans_host: &AnsHost,
+        ) -> Result<Vec<AssetEntry>, CwStakingError> {
+            Ok(self
+                .stakes
+                .iter()
+                .map(|(token, _)| AssetEntry::new(token))
+                .collect())
+        }
+
+        fn fetch_data(
+            &mut self,
+            _deps: Deps,
+            _env: Env,
+            _addr_as_sender: Option<Addr>,
+            _ans_host: &AnsHost,
+            _registry_contract: RegistryContract,
+            _staking_assets: Vec<AssetEntry>,
+        ) -> Result<(), CwStakingError> {
+            Ok(())
+        }
+
+        fn stake(
+            &self,
+            _deps: Deps,
+            _stake_request: Vec<AnsAsset>,
+            _unbonding_period: Option<Duration>,
+        ) -> Result<Vec<CosmosMsg>, CwStakingError> {
+            Err(CwStakingError::NotImplemented(self.name.to_owned()))
+        }
+
+        fn unstake(
+            &self,
+            _deps: Deps,
+            _unstake_request: Vec<AnsAsset>,
+            _unbonding_period: Option<Duration>,
+        ) -> Result<Vec<CosmosMsg>, CwStakingError> {
+            Err(CwStakingError::NotImplemented(self.name.to_owned()))
+        }
+
+        fn claim_rewards(&self, _deps: Deps) -> Result<Vec<CosmosMsg>, CwStakingError> {
+            Err(CwStakingError::NotImplemented(self.name.to_owned()))
+        }
+
+        fn claim(&self, _deps: Deps) -> Result<Vec<CosmosMsg>, CwStakingError> {
+            Err(CwStakingError::NotImplemented(self.name.to_owned()))
+        }
+
+        fn query_info(
+            &self,
+            _querier: &QuerierWrapper,
+        ) -> Result<StakingInfoResponse, CwStakingError> {
+            let infos = self
+                .stakes
+                .iter()
+                .map(|(token, _)| StakingInfo {
+                    staking_target: StakingTarget::Contract(Addr::unchecked(self.name)),
+                    staking_token: AssetInfo::native(*token),
+                    unbonding_periods: None,
+                    max_claims: None,
+                })
+                .collect();
+            Ok(StakingInfoResponse { infos })
+        }
+
+        fn query_staked(
+            &self,
+            _querier: &QuerierWrapper,
+            _staker: Addr,
+            stakes: Vec<AssetEntry>,
+            _unbonding_period: Option<Duration>,
+        ) -> Result<StakeResponse, CwStakingError> {
+            let amounts = stakes
+                .iter()
+                .map(|entry| {
+                    self.stakes
+                        .iter()
+                        .find(|(token, _)| entry.as_str() == *token)
+                        .map(|(_, amount)| Uint128::new(*amount))
+                        .ok_or_else(|| StdError::not_found(entry.to_string()).into())
+                })
+                .collect::<Result<_, CwStakingError>>()?;
+            Ok(StakeResponse { amounts })
+        }
+
+        fn query_unbonding(
+            &self,
+            _querier: &QuerierWrapper,
+            _staker: Addr,
+        ) -> Result<UnbondingResponse, CwStakingError> {
+            Ok(UnbondingResponse {
+                claims: vec![vec![]; self.stakes.len()],
+            })
+        }
+
+        fn query_rewards(
+            &self,
+            _querier: &QuerierWrapper,
+        ) -> Result<RewardTokensResponse, CwStakingError> {
+            Ok(RewardTokensResponse {
+                tokens: vec![vec![AssetInfo::native("reward")]; self.stakes.len()],
+            })
+        }
+    }
+
+    fn provider(
+        name: &'static str,
+        stakes: Vec<(&'static str, u128)>,
+    ) -> (ProviderName, Box<dyn CwStakingCommand>) {
+        (name.to_owned(), Box::new(FixedStakes { name, stakes }))
+    }
+
+    fn positions(
+        providers: Vec<(ProviderName, Box<dyn CwStakingCommand>)>,
+    ) -> StakingResult<Vec<StakingPosition>> {
+        let deps = mock_dependencies();
+        let env = mock_env_validated(deps.api);
+        providers_positions(
+            deps.as_ref(),
+            &env,
+            &AnsHost {
+                address: Addr::unchecked("ans_host"),
+            },
+            &RegistryContract {
+                address: Addr::unchecked("registry"),
+            },
+            &Addr::unchecked("staker"),
+            providers,
+        )
+    }
+
+    fn position(provider: &str, token: &str, amount: u128) -> StakingPosition {
+        StakingPosition {
+            provider: provider.to_owned(),
+            staking_token: AssetEntry::new(token),
+            staked: vec![StakedAmount {
+                unbonding_period: None,
+                amount: Uint128::new(amount),
+            }],
+            unbonding: vec![],
+            claimable: Uint128::zero(),
+            reward_tokens: vec![AssetInfo::native("reward")],
+        }
+    }
+
+    #[test]
+    fn positions_across_providers() {
+        let positions = positions(vec![
+            provider("alpha", vec![("alpha/lp", 100), ("alpha/empty", 0)]),
+            provider("beta", vec![]),
+            provider("gamma", vec![("gamma/lp", 5)]),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            positions,
+            vec![
+                position("alpha", "alpha/lp", 100),
+                position("gamma", "gamma/lp", 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_positions_without_stakes() {
+        let positions = positions(vec![
+            provider("alpha", vec![("alpha/lp", 0)]),
+            provider("beta", vec![]),
+        ])
+        .unwrap();
+
+        assert!(positions.is_empty());
     }
 }
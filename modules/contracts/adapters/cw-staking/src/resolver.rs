@@ -31,6 +31,23 @@ pub(crate) fn identify_provider(value: &str) -> Result<Box<dyn Identify>, CwStak
     }
 }
 
+/// Names of the providers that are available on the current chain
+pub(crate) fn local_provider_names(env: &Env) -> Vec<String> {
+    [
+        abstract_wyndex_adapter::WYNDEX,
+        abstract_kujira_adapter::staking::BOW,
+        abstract_osmosis_adapter::OSMOSIS,
+        abstract_astrovault_adapter::ASTROVAULT,
+    ]
+    .into_iter()
+    .filter(|name| {
+        identify_provider(name).is_ok_and(|provider| is_available_on(provider, env, None))
+            && resolve_local_provider(name).is_ok()
+    })
+    .map(ToOwned::to_owned)
+    .collect()
+}
+
 /// Given the provider name, return the local provider implementation
 pub(crate) fn resolve_local_provider(
     name: &str,
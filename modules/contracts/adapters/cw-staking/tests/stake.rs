@@ -8,7 +8,8 @@ use abstract_cw_staking::{
     contract::CONTRACT_VERSION, interface::CwStakingAdapter, msg::StakingQueryMsgFns,
 };
 use abstract_staking_standard::msg::{
-    Claim, RewardTokensResponse, StakingInfo, StakingInfoResponse, UnbondingResponse,
+    Claim, PositionsResponse, RewardTokensResponse, StakedAmount, StakingInfo, StakingInfoResponse,
+    StakingPosition, UnbondingResponse,
 };
 use cosmwasm_std::{coin, Uint128};
 use cw_asset::AssetInfoBase;
@@ -279,3 +280,73 @@ fn claim_rewards() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn positions_without_stakes() -> anyhow::Result<()> {
+    let (_, _, staking, account) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    // all the providers of the chain
+    let positions = staking.positions(account_addr.to_string(), None)?;
+    assert_eq!(positions, PositionsResponse { positions: vec![] });
+
+    let positions = staking.positions(account_addr.to_string(), Some(vec![WYNDEX.to_owned()]))?;
+    assert_eq!(positions, PositionsResponse { positions: vec![] });
+    Ok(())
+}
+
+#[test]
+fn positions_of_staker() -> anyhow::Result<()> {
+    let (chain, _, staking, account) = setup_mock()?;
+    let account_addr = account.address()?;
+
+    let dur = Some(cw_utils::Duration::Time(2));
+
+    // stake 100 EUR
+    staking.stake(
+        AnsAsset::new(EUR_USD_LP, 100u128),
+        WYNDEX.into(),
+        dur,
+        &account,
+    )?;
+
+    let positions = staking.positions(account_addr.to_string(), None)?;
+    assert_eq!(
+        positions,
+        PositionsResponse {
+            positions: vec![StakingPosition {
+                provider: WYNDEX_WITHOUT_CHAIN.to_owned(),
+                staking_token: AssetEntry::new(EUR_USD_LP),
+                staked: vec![StakedAmount {
+                    unbonding_period: dur,
+                    amount: Uint128::new(100),
+                }],
+                unbonding: vec![],
+                claimable: Uint128::zero(),
+                reward_tokens: vec![AssetInfoBase::Native(WYND_TOKEN.to_owned())],
+            }],
+        }
+    );
+
+    // unbond 40, which becomes claimable after the unbonding period
+    staking.unstake(
+        AnsAsset::new(EUR_USD_LP, 40u128),
+        WYNDEX.into(),
+        dur,
+        &account,
+    )?;
+    chain.next_block()?;
+
+    let PositionsResponse { positions } = staking.positions(account_addr.to_string(), None)?;
+    assert_eq!(positions.len(), 1);
+    assert_eq!(
+        positions[0].staked,
+        vec![StakedAmount {
+            unbonding_period: dur,
+            amount: Uint128::new(60),
+        }]
+    );
+    assert_eq!(positions[0].unbonding.len(), 1);
+    assert_eq!(positions[0].claimable, Uint128::new(40));
+    Ok(())
+}
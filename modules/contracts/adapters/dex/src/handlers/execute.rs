@@ -148,6 +148,7 @@ fn handle_ibc_request(
                 dex: dex_name.clone(),
                 action: action.clone(),
             })?,
+            callback_id: None,
         })
     };
     let ibc_action_msg = ibc_client.host_action(host_chain, host_action)?;
@@ -12,6 +12,10 @@ pub(crate) fn identify_money_market(value: &str) -> Result<Box<dyn Identify>, Mo
         abstract_kujira_adapter::money_market::GHOST => {
             Ok(Box::<abstract_kujira_adapter::money_market::Ghost>::default())
         }
+        #[cfg(feature = "mockmarket")]
+        mockmarket_bundle::MOCKMARKET => {
+            Ok(Box::<mockmarket_bundle::money_market::MockMarketProtocol>::default())
+        }
         // abstract_mars_adapter::MARS => {
         //     Ok(Box::<abstract_mars_adapter::money_market::Mars>::default())
         // }
@@ -31,6 +35,10 @@ pub(crate) fn resolve_money_market(
         abstract_mars_adapter::MARS => {
             Ok(Box::<abstract_mars_adapter::money_market::Mars>::default())
         }
+        #[cfg(feature = "mockmarket")]
+        mockmarket_bundle::MOCKMARKET => {
+            Ok(Box::<mockmarket_bundle::money_market::MockMarketProtocol>::default())
+        }
         _ => Err(MoneyMarketError::ForeignMoneyMarket(value.to_owned())),
     }
 }
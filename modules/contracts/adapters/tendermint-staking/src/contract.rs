@@ -2,11 +2,12 @@ use std::iter;
 
 use abstract_adapter::sdk::Execution;
 use abstract_adapter::AdapterContract;
-use cosmwasm_std::{DepsMut, Empty, Env, MessageInfo, Response};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response};
 
 use crate::{
     error::TendermintStakeError,
     msg::{TendermintStakingExecuteMsg, TendermintStakingQueryMsg},
+    rebalance::{delegation_status, rebalance, set_delegation_targets},
     staking::*,
     TENDERMINT_STAKING,
 };
@@ -21,7 +22,8 @@ pub type TendermintStakeAdapter = AdapterContract<
 
 pub const STAKING_ADAPTER: TendermintStakeAdapter =
     TendermintStakeAdapter::new(TENDERMINT_STAKING, CONTRACT_VERSION, None)
-        .with_execute(handle_request);
+        .with_execute(handle_request)
+        .with_query(handle_query);
 
 pub type TendermintStakeResult = Result<Response, TendermintStakeError>;
 
@@ -92,6 +94,223 @@ pub fn handle_request(
         TendermintStakingExecuteMsg::WithdrawAllRewards {} => {
             executor.execute(withdraw_all_rewards(&deps.querier, module.target()?)?)
         }
+        TendermintStakingExecuteMsg::SetDelegationTargets { validators } => {
+            return set_delegation_targets(deps, &module, validators);
+        }
+        TendermintStakingExecuteMsg::Rebalance {} => return rebalance(deps, &module),
     }?;
     Ok(Response::new().add_message(msg))
 }
+
+pub fn handle_query(
+    deps: Deps,
+    _env: Env,
+    _module: &TendermintStakeAdapter,
+    msg: TendermintStakingQueryMsg,
+) -> Result<Binary, TendermintStakeError> {
+    match msg {
+        TendermintStakingQueryMsg::DelegationTargets { account_address } => {
+            let account_address = deps.api.addr_validate(&account_address)?;
+            to_json_binary(&delegation_status(deps, &account_address)?).map_err(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abstract_adapter::{
+        abstract_testing::prelude::*,
+        sdk::base::{ExecuteEndpoint, InstantiateEndpoint, QueryEndpoint},
+        std::{
+            account,
+            adapter::{
+                AdapterRequestMsg, BaseInstantiateMsg, ExecuteMsg, InstantiateMsg, QueryMsg,
+            },
+            registry::Account,
+        },
+    };
+    use cosmwasm_std::{
+        coin, from_json,
+        testing::{message_info, mock_dependencies},
+        CosmosMsg, Decimal, FullDelegation, StakingMsg, Uint128, Validator, WasmMsg,
+    };
+
+    use super::*;
+    use crate::msg::{DelegationTargetsResponse, ValidatorDelegation};
+
+    const DENOM: &str = "ustake";
+
+    fn validator(address: &str) -> Validator {
+        Validator::create(
+            address.to_owned(),
+            Decimal::percent(5),
+            Decimal::percent(10),
+            Decimal::percent(1),
+        )
+    }
+
+    /// Account delegates 100 to `val1` and 50 to `val2`, of which only 20 can be redelegated.
+    fn setup() -> (MockDeps, Account) {
+        let mut deps = mock_dependencies();
+        let account = test_account(deps.api);
+        deps.querier = abstract_mock_querier_builder(deps.api)
+            .account(&account, TEST_ACCOUNT_ID)
+            .build();
+        deps.querier.update_staking(
+            DENOM,
+            &[validator("val1"), validator("val2"), validator("val3")],
+            &[
+                FullDelegation::create(
+                    account.addr().clone(),
+                    "val1".to_owned(),
+                    coin(100, DENOM),
+                    coin(100, DENOM),
+                    vec![],
+                ),
+                FullDelegation::create(
+                    account.addr().clone(),
+                    "val2".to_owned(),
+                    coin(50, DENOM),
+                    coin(20, DENOM),
+                    vec![],
+                ),
+            ],
+        );
+
+        let abstr = AbstractMockAddrs::new(deps.api);
+        let env = mock_env_validated(deps.api);
+        STAKING_ADAPTER
+            .instantiate(
+                deps.as_mut(),
+                env,
+                message_info(&abstr.owner, &[]),
+                InstantiateMsg {
+                    base: BaseInstantiateMsg {
+                        registry_address: abstr.registry.to_string(),
+                    },
+                    module: Empty {},
+                },
+            )
+            .unwrap();
+        (deps, account)
+    }
+
+    fn set_delegation_targets(
+        deps: &mut MockDeps,
+        account: &Account,
+        validators: Vec<(String, Decimal)>,
+    ) -> TendermintStakeResult {
+        let env = mock_env_validated(deps.api);
+        STAKING_ADAPTER.execute(
+            deps.as_mut(),
+            env,
+            message_info(account.addr(), &[]),
+            ExecuteMsg::Module(AdapterRequestMsg {
+                account_address: None,
+                request: TendermintStakingExecuteMsg::SetDelegationTargets { validators },
+            }),
+        )
+    }
+
+    /// Messages that the adapter asks the Account to execute.
+    fn account_msgs(res: &Response) -> Vec<CosmosMsg> {
+        let CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) = &res.messages[0].msg else {
+            panic!("expected an Account execution");
+        };
+        let account::ExecuteMsg::Execute { msgs } = from_json(msg).unwrap() else {
+            panic!("expected account msgs");
+        };
+        msgs
+    }
+
+    fn redelegate(source: &str, destination: &str, amount: u128) -> CosmosMsg {
+        CosmosMsg::Staking(StakingMsg::Redelegate {
+            src_validator: source.to_owned(),
+            dst_validator: destination.to_owned(),
+            amount: coin(amount, DENOM),
+        })
+    }
+
+    #[test]
+    fn set_delegation_targets_redelegates_stake() {
+        let (mut deps, account) = setup();
+
+        let res = set_delegation_targets(
+            &mut deps,
+            &account,
+            vec![
+                ("val1".to_owned(), Decimal::percent(50)),
+                ("val3".to_owned(), Decimal::percent(50)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        // Only 20 of the stake on `val2` can be redelegated now
+        assert_eq!(
+            account_msgs(&res),
+            vec![
+                redelegate("val1", "val3", 25),
+                redelegate("val2", "val3", 20)
+            ]
+        );
+    }
+
+    #[test]
+    fn queries_targets_against_delegations() {
+        let (mut deps, account) = setup();
+        set_delegation_targets(
+            &mut deps,
+            &account,
+            vec![
+                ("val1".to_owned(), Decimal::percent(50)),
+                ("val3".to_owned(), Decimal::percent(50)),
+            ],
+        )
+        .unwrap();
+
+        let res = STAKING_ADAPTER
+            .query(
+                deps.as_ref(),
+                mock_env_validated(deps.api),
+                QueryMsg::Module(TendermintStakingQueryMsg::DelegationTargets {
+                    account_address: account.addr().to_string(),
+                }),
+            )
+            .unwrap();
+        let position = |validator: &str, weight: u64, target: u128, actual: u128, free: u128| {
+            ValidatorDelegation {
+                validator: validator.to_owned(),
+                target_weight: Decimal::percent(weight),
+                target_amount: Uint128::new(target),
+                actual_amount: Uint128::new(actual),
+                redelegatable_amount: Uint128::new(free),
+            }
+        };
+        assert_eq!(
+            from_json::<DelegationTargetsResponse>(res).unwrap(),
+            DelegationTargetsResponse {
+                total_delegated: Uint128::new(150),
+                validators: vec![
+                    position("val1", 50, 75, 100, 100),
+                    position("val3", 50, 75, 0, 0),
+                    position("val2", 0, 0, 50, 20),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_validator() {
+        let (mut deps, account) = setup();
+
+        let res = set_delegation_targets(
+            &mut deps,
+            &account,
+            vec![("unknown".to_owned(), Decimal::one())],
+        );
+        assert!(matches!(
+            res,
+            Err(TendermintStakeError::ValidatorNotFound { .. })
+        ));
+    }
+}
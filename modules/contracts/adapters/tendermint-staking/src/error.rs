@@ -17,4 +17,16 @@ pub enum TendermintStakeError {
 
     #[error(transparent)]
     AdapterError(#[from] AdapterError),
+
+    #[error("Delegation target weights must be non-zero and sum up to one")]
+    InvalidDelegationWeights {},
+
+    #[error("Validator {validator} is listed more than once in the delegation targets")]
+    DuplicateValidator { validator: String },
+
+    #[error("Validator {validator} not found")]
+    ValidatorNotFound { validator: String },
+
+    #[error("No delegation targets set for {account}")]
+    NoDelegationTargets { account: String },
 }
@@ -4,7 +4,7 @@
 
 use abstract_adapter::std::adapter;
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Empty, Uint128};
+use cosmwasm_std::{Decimal, Empty, Uint128};
 
 pub type InstantiateMsg = adapter::InstantiateMsg<Empty>;
 pub type ExecuteMsg = adapter::ExecuteMsg<TendermintStakingExecuteMsg>;
@@ -44,9 +44,46 @@ pub enum TendermintStakingExecuteMsg {
     },
     /// Withdraw all the rewards
     WithdrawAllRewards {},
+    /// Set the delegation weights of the Account and redelegate its stake to match them.
+    /// Weights must be non-zero and sum up to one.
+    /// Validators that are not part of the targets will be fully redelegated away from.
+    SetDelegationTargets {
+        /// (Validator address, weight)
+        validators: Vec<(String, Decimal)>,
+    },
+    /// Redelegate the Account's stake to match its delegation targets.
+    /// Useful to finish a rebalance that was blocked by stake that could not be redelegated yet.
+    Rebalance {},
 }
 
 /// Staking queries are available on [`cosmwasm_std::QuerierWrapper`] through [`cosmwasm_std::Deps`].
 #[cosmwasm_schema::cw_serde]
 #[derive(QueryResponses, cw_orch::QueryFns)]
-pub enum TendermintStakingQueryMsg {}
+pub enum TendermintStakingQueryMsg {
+    /// Delegation targets of an Account compared to its actual delegations.
+    /// Returns [`DelegationTargetsResponse`]
+    #[returns(DelegationTargetsResponse)]
+    DelegationTargets { account_address: String },
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct DelegationTargetsResponse {
+    /// Total amount of bonded tokens delegated by the Account
+    pub total_delegated: Uint128,
+    pub validators: Vec<ValidatorDelegation>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ValidatorDelegation {
+    /// Validator address
+    pub validator: String,
+    /// Targeted weight, zero if the validator is not part of the targets
+    pub target_weight: Decimal,
+    /// Amount that should be delegated to this validator to match the target weight
+    pub target_amount: Uint128,
+    /// Amount that is currently delegated to this validator
+    pub actual_amount: Uint128,
+    /// Amount that can be redelegated away from this validator now.
+    /// Stake that was redelegated to the validator is locked until its unbonding period ended.
+    pub redelegatable_amount: Uint128,
+}
@@ -0,0 +1,276 @@
+//! # Rebalance
+//! Redelegates the stake of an Account to match its delegation targets.
+use std::collections::HashSet;
+
+use abstract_adapter::sdk::Execution;
+use cosmwasm_std::{
+    Addr, Coin, CosmosMsg, Decimal, Deps, DepsMut, QuerierWrapper, Response, StakingMsg, Uint128,
+};
+
+use crate::{
+    contract::{TendermintStakeAdapter, TendermintStakeResult},
+    error::TendermintStakeError,
+    msg::{DelegationTargetsResponse, ValidatorDelegation},
+    state::DELEGATION_TARGETS,
+};
+
+/// Amount of stake to move from `source` to `destination`.
+#[derive(Debug, PartialEq)]
+pub struct Redelegation {
+    pub source: String,
+    pub destination: String,
+    pub amount: Uint128,
+}
+
+pub fn set_delegation_targets(
+    deps: DepsMut,
+    module: &TendermintStakeAdapter,
+    validators: Vec<(String, Decimal)>,
+) -> TendermintStakeResult {
+    validate_targets(&deps.querier, &validators)?;
+    DELEGATION_TARGETS.save(deps.storage, module.target()?, &validators)?;
+    rebalance(deps, module)
+}
+
+pub fn rebalance(deps: DepsMut, module: &TendermintStakeAdapter) -> TendermintStakeResult {
+    let account = module.target()?;
+    let status = delegation_status(deps.as_ref(), account)?;
+    let redelegations = plan_redelegations(&status.validators);
+    if redelegations.is_empty() {
+        return Ok(Response::new());
+    }
+
+    let denom = deps.querier.query_bonded_denom()?;
+    let redelegate_msgs: Vec<CosmosMsg> = redelegations
+        .into_iter()
+        .map(|redelegation| {
+            CosmosMsg::Staking(StakingMsg::Redelegate {
+                src_validator: redelegation.source,
+                dst_validator: redelegation.destination,
+                amount: Coin::new(redelegation.amount, denom.clone()),
+            })
+        })
+        .collect();
+
+    let msg = module.executor(deps.as_ref()).execute(redelegate_msgs)?;
+    Ok(Response::new().add_message(msg))
+}
+
+/// Compare the delegation targets of `account` to its actual delegations.
+pub fn delegation_status(
+    deps: Deps,
+    account: &Addr,
+) -> Result<DelegationTargetsResponse, TendermintStakeError> {
+    let targets = DELEGATION_TARGETS
+        .may_load(deps.storage, account)?
+        .ok_or_else(|| TendermintStakeError::NoDelegationTargets {
+            account: account.to_string(),
+        })?;
+
+    let denom = deps.querier.query_bonded_denom()?;
+    let delegations: Vec<_> = deps
+        .querier
+        .query_all_delegations(account)?
+        .into_iter()
+        .filter(|delegation| delegation.amount.denom == denom)
+        .collect();
+    let total_delegated: Uint128 = delegations
+        .iter()
+        .map(|delegation| delegation.amount.amount)
+        .sum();
+
+    let mut validators: Vec<ValidatorDelegation> = targets
+        .iter()
+        .zip(target_amounts(total_delegated, &targets))
+        .map(|((validator, weight), target_amount)| ValidatorDelegation {
+            validator: validator.clone(),
+            target_weight: *weight,
+            target_amount,
+            actual_amount: Uint128::zero(),
+            redelegatable_amount: Uint128::zero(),
+        })
+        .collect();
+    for delegation in delegations {
+        // Stake that was redelegated to the validator can't be moved again until it matured
+        let redelegatable_amount = deps
+            .querier
+            .query_delegation(account, &delegation.validator)?
+            .map(|full_delegation| full_delegation.can_redelegate.amount)
+            .unwrap_or_default();
+        match validators
+            .iter_mut()
+            .find(|v| v.validator == delegation.validator)
+        {
+            Some(validator) => {
+                validator.actual_amount = delegation.amount.amount;
+                validator.redelegatable_amount = redelegatable_amount;
+            }
+            None => validators.push(ValidatorDelegation {
+                validator: delegation.validator,
+                target_weight: Decimal::zero(),
+                target_amount: Uint128::zero(),
+                actual_amount: delegation.amount.amount,
+                redelegatable_amount,
+            }),
+        }
+    }
+
+    Ok(DelegationTargetsResponse {
+        total_delegated,
+        validators,
+    })
+}
+
+fn validate_targets(
+    querier: &QuerierWrapper,
+    targets: &[(String, Decimal)],
+) -> Result<(), TendermintStakeError> {
+    let total_weight = targets
+        .iter()
+        .try_fold(Decimal::zero(), |acc, (_, weight)| acc.checked_add(*weight))
+        .map_err(|_| TendermintStakeError::InvalidDelegationWeights {})?;
+    if targets.iter().any(|(_, weight)| weight.is_zero()) || total_weight != Decimal::one() {
+        return Err(TendermintStakeError::InvalidDelegationWeights {});
+    }
+
+    let mut seen = HashSet::new();
+    for (validator, _) in targets {
+        if !seen.insert(validator) {
+            return Err(TendermintStakeError::DuplicateValidator {
+                validator: validator.clone(),
+            });
+        }
+        if querier.query_validator(validator)?.is_none() {
+            return Err(TendermintStakeError::ValidatorNotFound {
+                validator: validator.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Split `total` over the targets according to their weights.
+/// Rounding dust is assigned to the first target so that the amounts add up to `total`.
+fn target_amounts(total: Uint128, targets: &[(String, Decimal)]) -> Vec<Uint128> {
+    let mut amounts: Vec<Uint128> = targets
+        .iter()
+        .map(|(_, weight)| total.mul_floor(*weight))
+        .collect();
+    let assigned: Uint128 = amounts.iter().copied().sum();
+    if let Some(first) = amounts.first_mut() {
+        *first += total - assigned;
+    }
+    amounts
+}
+
+/// Pair validators with too much stake with validators that have too little.
+/// Only the stake that can currently be redelegated is moved, the rest is left for a later rebalance.
+fn plan_redelegations(validators: &[ValidatorDelegation]) -> Vec<Redelegation> {
+    let mut surpluses: Vec<(&str, Uint128)> = validators
+        .iter()
+        .filter(|v| v.actual_amount > v.target_amount)
+        .map(|v| {
+            let surplus = v.actual_amount - v.target_amount;
+            (v.validator.as_str(), surplus.min(v.redelegatable_amount))
+        })
+        .filter(|(_, surplus)| !surplus.is_zero())
+        .collect();
+    let mut deficits: Vec<(&str, Uint128)> = validators
+        .iter()
+        .filter(|v| v.target_amount > v.actual_amount)
+        .map(|v| (v.validator.as_str(), v.target_amount - v.actual_amount))
+        .collect();
+
+    let mut redelegations = vec![];
+    let (mut source, mut destination) = (0, 0);
+    while source < surpluses.len() && destination < deficits.len() {
+        let amount = surpluses[source].1.min(deficits[destination].1);
+        redelegations.push(Redelegation {
+            source: surpluses[source].0.to_string(),
+            destination: deficits[destination].0.to_string(),
+            amount,
+        });
+        surpluses[source].1 -= amount;
+        deficits[destination].1 -= amount;
+        if surpluses[source].1.is_zero() {
+            source += 1;
+        }
+        if deficits[destination].1.is_zero() {
+            destination += 1;
+        }
+    }
+    redelegations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(validator: &str, target: u128, actual: u128) -> ValidatorDelegation {
+        ValidatorDelegation {
+            validator: validator.to_owned(),
+            target_weight: Decimal::zero(),
+            target_amount: Uint128::new(target),
+            actual_amount: Uint128::new(actual),
+            redelegatable_amount: Uint128::new(actual),
+        }
+    }
+
+    #[test]
+    fn target_amounts_add_up_to_total() {
+        let targets = vec![
+            ("a".to_owned(), Decimal::percent(33)),
+            ("b".to_owned(), Decimal::percent(33)),
+            ("c".to_owned(), Decimal::percent(34)),
+        ];
+        let amounts = target_amounts(Uint128::new(100), &targets);
+        assert_eq!(
+            amounts,
+            vec![Uint128::new(33), Uint128::new(33), Uint128::new(34)]
+        );
+
+        let amounts = target_amounts(Uint128::new(10), &targets);
+        assert_eq!(amounts.iter().copied().sum::<Uint128>(), Uint128::new(10));
+    }
+
+    #[test]
+    fn plans_redelegations_from_surplus_to_deficit() {
+        let validators = vec![
+            position("a", 50, 100),
+            position("b", 30, 0),
+            position("c", 20, 0),
+        ];
+        assert_eq!(
+            plan_redelegations(&validators),
+            vec![
+                Redelegation {
+                    source: "a".to_owned(),
+                    destination: "b".to_owned(),
+                    amount: Uint128::new(30),
+                },
+                Redelegation {
+                    source: "a".to_owned(),
+                    destination: "c".to_owned(),
+                    amount: Uint128::new(20),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn only_moves_redelegatable_stake() {
+        let mut locked = position("a", 0, 100);
+        locked.redelegatable_amount = Uint128::zero();
+        let mut partially_locked = position("b", 50, 100);
+        partially_locked.redelegatable_amount = Uint128::new(20);
+        let validators = vec![locked, partially_locked, position("c", 150, 0)];
+        assert_eq!(
+            plan_redelegations(&validators),
+            vec![Redelegation {
+                source: "b".to_owned(),
+                destination: "c".to_owned(),
+                amount: Uint128::new(20),
+            }]
+        );
+    }
+}
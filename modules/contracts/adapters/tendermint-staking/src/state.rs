@@ -0,0 +1,5 @@
+use cosmwasm_std::{Addr, Decimal};
+use cw_storage_plus::Map;
+
+/// Delegation weights targeted by an Account, as set through `SetDelegationTargets`.
+pub const DELEGATION_TARGETS: Map<&Addr, Vec<(String, Decimal)>> = Map::new("targets");
@@ -1,11 +1,12 @@
 use abstract_app::sdk::{
-    features::{AbstractNameService, AbstractResponse},
-    TransferInterface,
+    features::{AbstractNameService, AbstractResponse, AccountIdentification},
+    IbcInterface, TransferInterface,
 };
-use abstract_app::std::objects::AssetEntry;
+use abstract_app::std::objects::{AssetEntry, TruncatedChainId};
 use chrono::{DateTime, FixedOffset, LocalResult, NaiveTime, TimeZone};
 use cosmwasm_std::{
-    BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, Int64, MessageInfo, StdError, Uint128,
+    to_json_string, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, Int64, MessageInfo, StdError,
+    Uint128,
 };
 use cw_asset::AssetInfoBase;
 use cw_utils::must_pay;
@@ -13,8 +14,8 @@ use cw_utils::must_pay;
 use crate::{
     contract::{CalendarApp, CalendarAppResult},
     error::CalendarError,
-    msg::CalendarExecuteMsg,
-    state::{Meeting, CALENDAR, CONFIG},
+    msg::{CalendarExecuteMsg, ExecuteMsg, IbcHooksMemo, IbcHooksWasmMsg},
+    state::{Meeting, RemoteRequester, CALENDAR, CONFIG},
 };
 
 enum StakeAction {
@@ -34,7 +35,33 @@ pub fn execute_handler(
         CalendarExecuteMsg::RequestMeeting {
             start_time,
             end_time,
-        } => request_meeting(deps, info, module, env, start_time, end_time),
+            remote_requester,
+        } => request_meeting(
+            deps,
+            info,
+            module,
+            env,
+            start_time,
+            end_time,
+            remote_requester,
+        ),
+        CalendarExecuteMsg::RequestRemoteMeeting {
+            host_chain,
+            host_calendar,
+            start_time,
+            end_time,
+            stake,
+        } => request_remote_meeting(
+            deps,
+            info,
+            module,
+            env,
+            host_chain,
+            host_calendar,
+            start_time,
+            end_time,
+            stake,
+        ),
         CalendarExecuteMsg::SlashFullStake {
             day_datetime,
             meeting_index,
@@ -86,9 +113,13 @@ fn request_meeting(
     env: Env,
     meeting_start_time: Int64,
     meeting_end_time: Int64,
+    remote_requester: Option<RemoteRequester>,
 ) -> CalendarAppResult {
     let config = CONFIG.load(deps.storage)?;
     let amount_sent = must_pay(&info, &config.denom)?;
+    if let Some(remote_requester) = &remote_requester {
+        remote_requester.chain.verify()?;
+    }
 
     let timezone: FixedOffset =
         FixedOffset::east_opt(config.utc_offset).ok_or(CalendarError::InvalidUtcOffset {})?;
@@ -107,6 +138,7 @@ fn request_meeting(
         meeting_end_datetime,
         info.sender,
         amount_sent,
+        remote_requester,
     )?;
 
     let meeting_start_timestamp = meeting.start_time;
@@ -156,6 +188,57 @@ fn request_meeting(
         .add_attribute("meeting_end_time", meeting_end_timestamp.to_string()))
 }
 
+#[allow(clippy::too_many_arguments)]
+fn request_remote_meeting(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: CalendarApp,
+    env: Env,
+    host_chain: TruncatedChainId,
+    host_calendar: String,
+    start_time: Int64,
+    end_time: Int64,
+    stake: Coin,
+) -> CalendarAppResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+    host_chain.verify()?;
+
+    let account = module.account(deps.as_ref())?;
+    let request = ExecuteMsg::Module(CalendarExecuteMsg::RequestMeeting {
+        start_time,
+        end_time,
+        remote_requester: Some(RemoteRequester {
+            chain: TruncatedChainId::new(&env),
+            address: account.into_addr().into_string(),
+        }),
+    });
+    let memo = to_json_string(&IbcHooksMemo {
+        wasm: IbcHooksWasmMsg {
+            contract: host_calendar.clone(),
+            msg: request,
+        },
+    })?;
+    let transfer_msg = module.ibc_client(deps.as_ref()).ics20_transfer(
+        host_chain.clone(),
+        vec![stake],
+        Some(memo),
+        Some(host_calendar),
+    )?;
+
+    Ok(module
+        .custom_response(
+            "request_remote_meeting",
+            vec![
+                ("host_chain", host_chain.to_string()),
+                ("meeting_start_time", start_time.to_string()),
+                ("meeting_end_time", end_time.to_string()),
+            ],
+        )
+        .add_message(transfer_msg))
+}
+
 fn handle_stake(
     deps: DepsMut,
     info: MessageInfo,
@@ -193,15 +276,35 @@ fn handle_stake(
 
     meeting.amount_staked = Uint128::zero();
     let bank = module.bank(deps.as_ref());
+    let return_stake_msgs = |amount: Uint128| -> CalendarAppResult<Vec<CosmosMsg>> {
+        let funds = vec![Coin::new(amount, config.denom.clone())];
+        match &meeting.remote_requester {
+            // Remote stakes are sent back over IBC by the Account
+            Some(remote_requester) => {
+                let mut msgs = bank.deposit(funds.clone())?;
+                msgs.push(module.ibc_client(deps.as_ref()).ics20_transfer(
+                    remote_requester.chain.clone(),
+                    funds,
+                    None,
+                    Some(remote_requester.address.clone()),
+                )?);
+                Ok(msgs)
+            }
+            None => Ok(vec![BankMsg::Send {
+                to_address: requester.clone(),
+                amount: funds,
+            }
+            .into()]),
+        }
+    };
 
     let response = match stake_action {
-        StakeAction::Return => module.response("return_stake").add_message(BankMsg::Send {
-            to_address: requester,
-            amount: vec![Coin::new(amount_staked, config.denom)],
-        }),
+        StakeAction::Return => module
+            .response("return_stake")
+            .add_messages(return_stake_msgs(amount_staked)?),
         StakeAction::FullSlash => {
             let account_deposit_msgs: Vec<CosmosMsg> =
-                bank.deposit(vec![Coin::new(amount_staked, config.denom)])?;
+                bank.deposit(vec![Coin::new(amount_staked, config.denom.clone())])?;
             module
                 .response("full_slash")
                 .add_messages(account_deposit_msgs)
@@ -221,10 +324,7 @@ fn handle_stake(
 
             module
                 .response("partial_slash")
-                .add_message(BankMsg::Send {
-                    to_address: requester,
-                    amount: vec![Coin::new(amount_staked - amount_to_slash, config.denom)],
-                })
+                .add_messages(return_stake_msgs(amount_staked - amount_to_slash)?)
                 .add_messages(account_deposit_msgs)
         }
     };
@@ -1,9 +1,13 @@
-use abstract_app::std::objects::AssetEntry;
+use abstract_app::std::objects::{AssetEntry, TruncatedChainId};
 use chrono::NaiveTime;
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Int64, Uint128};
+use cosmwasm_std::{Coin, Int64, Uint128};
 
-use crate::{contract::CalendarApp, error::CalendarError, state::Meeting};
+use crate::{
+    contract::CalendarApp,
+    error::CalendarError,
+    state::{Meeting, RemoteRequester},
+};
 
 // This is used for type safety and re-exporting the contract endpoint structs.
 abstract_app::app_msg_types!(CalendarApp, CalendarExecuteMsg, CalendarQueryMsg);
@@ -59,6 +63,24 @@ pub enum CalendarExecuteMsg {
         start_time: Int64,
         /// The unix timestamp of the end datetime of the meeting.
         end_time: Int64,
+        /// Requester on another chain that the stake is returned to.
+        /// Set when the meeting is booked through an ICS-20 transfer with a memo.
+        remote_requester: Option<RemoteRequester>,
+    },
+    /// Request a meeting on the calendar of another chain, staking funds of this Account.
+    /// The stake is sent with an ICS-20 transfer whose memo books the meeting on arrival.
+    /// Requires the ibc client on this Account and ibc hooks on the host chain. Admin only.
+    RequestRemoteMeeting {
+        /// The chain of the calendar to book a meeting on.
+        host_chain: TruncatedChainId,
+        /// The address of the calendar app on the host chain.
+        host_calendar: String,
+        /// The unix timestamp of the start datetime of the meeting.
+        start_time: Int64,
+        /// The unix timestamp of the end datetime of the meeting.
+        end_time: Int64,
+        /// The stake, denominated in the local representation of the host calendar's denom.
+        stake: Coin,
     },
     /// Fully slashes the stake for a completed meeting. Admin only.
     SlashFullStake {
@@ -113,6 +135,19 @@ pub enum CalendarQueryMsg {
 #[cosmwasm_schema::cw_serde]
 pub struct CalendarMigrateMsg {}
 
+/// Memo of an ICS-20 transfer that executes a contract on arrival through ibc hooks.
+#[cosmwasm_schema::cw_serde]
+pub struct IbcHooksMemo {
+    pub wasm: IbcHooksWasmMsg,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct IbcHooksWasmMsg {
+    /// Contract to execute, must be the receiver of the transfer.
+    pub contract: String,
+    pub msg: ExecuteMsg,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ConfigResponse {
     pub price_per_minute: Uint128,
@@ -1,3 +1,4 @@
+use abstract_app::std::objects::TruncatedChainId;
 use chrono::{DateTime, FixedOffset, NaiveTime, Timelike};
 use cosmwasm_std::{Addr, BlockInfo, Uint128};
 use cw_storage_plus::{Item, Map};
@@ -13,12 +14,24 @@ pub struct Config {
     pub end_time: Time,
 }
 
+/// Requester that booked a meeting from another chain.
+/// Stakes of their meetings are sent back over IBC.
+#[cosmwasm_schema::cw_serde]
+pub struct RemoteRequester {
+    /// Chain of the requester, as registered on the ibc client.
+    pub chain: TruncatedChainId,
+    /// Address of the requester on that chain.
+    pub address: String,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct Meeting {
     pub start_time: i64,
     pub end_time: i64,
     pub requester: Addr,
     pub amount_staked: Uint128,
+    /// Set if the meeting was requested from another chain.
+    pub remote_requester: Option<RemoteRequester>,
 }
 
 impl Meeting {
@@ -29,6 +42,7 @@ impl Meeting {
         meeting_end_datetime: DateTime<FixedOffset>,
         requester: Addr,
         amount_staked: Uint128,
+        remote_requester: Option<RemoteRequester>,
     ) -> Result<Self, CalendarError> {
         let meeting_start_timestamp = meeting_start_datetime.timestamp();
         let meeting_end_timestamp = meeting_end_datetime.timestamp();
@@ -74,6 +88,7 @@ impl Meeting {
             end_time: meeting_end_timestamp,
             requester,
             amount_staked,
+            remote_requester,
         })
     }
 }
@@ -1,4 +1,4 @@
-use abstract_app::std::objects::{namespace::Namespace, AssetEntry};
+use abstract_app::std::objects::{namespace::Namespace, AssetEntry, TruncatedChainId};
 use abstract_client::{AbstractClient, Application, Publisher};
 // Use prelude to get all the necessary imports
 use calendar_app::{
@@ -7,7 +7,7 @@ use calendar_app::{
         CalendarExecuteMsg, CalendarExecuteMsgFns, CalendarInstantiateMsg, CalendarQueryMsgFns,
         ConfigResponse, Time,
     },
-    state::Meeting,
+    state::{Meeting, RemoteRequester},
     CalendarAppInterface,
 };
 use chrono::{DateTime, Days, FixedOffset, NaiveDateTime, NaiveTime, TimeZone, Timelike};
@@ -73,6 +73,7 @@ fn request_meeting(
 
     module.request_meeting(
         meeting_end_datetime.and_utc().timestamp().into(),
+        None,
         meeting_start_datetime.and_utc().timestamp().into(),
         &[funds],
     )?;
@@ -320,6 +321,7 @@ fn request_meeting_at_start_of_day() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender,
             amount_staked: Uint128::from(60u128),
+            remote_requester: None,
         }],
         meetings_response.meetings
     );
@@ -364,6 +366,7 @@ fn request_meeting_at_end_of_day() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender,
             amount_staked: Uint128::from(60u128),
+            remote_requester: None,
         }],
         meetings_response.meetings
     );
@@ -425,12 +428,14 @@ fn request_multiple_meetings_on_same_day() -> anyhow::Result<()> {
                 end_time: meeting_end_datetime1.and_utc().timestamp(),
                 requester: sender1,
                 amount_staked: Uint128::from(60u128),
+                remote_requester: None,
             },
             Meeting {
                 start_time: meeting_start_datetime2.and_utc().timestamp(),
                 end_time: meeting_end_datetime2.and_utc().timestamp(),
                 requester: sender2,
                 amount_staked: Uint128::from(60u128),
+                remote_requester: None,
             }
         ],
         meetings_response.meetings
@@ -503,12 +508,14 @@ fn request_back_to_back_meetings_on_left() -> anyhow::Result<()> {
                 end_time: meeting_end_datetime1.and_utc().timestamp(),
                 requester: sender1,
                 amount_staked: Uint128::from(60u128),
+                remote_requester: None,
             },
             Meeting {
                 start_time: meeting_start_datetime2.and_utc().timestamp(),
                 end_time: meeting_end_datetime2.and_utc().timestamp(),
                 requester: sender2,
                 amount_staked: Uint128::from(60u128),
+                remote_requester: None,
             }
         ],
         meetings_response.meetings
@@ -581,12 +588,14 @@ fn request_back_to_back_meetings_on_right() -> anyhow::Result<()> {
                 end_time: meeting_end_datetime1.and_utc().timestamp(),
                 requester: sender1,
                 amount_staked: Uint128::from(60u128),
+                remote_requester: None,
             },
             Meeting {
                 start_time: meeting_start_datetime2.and_utc().timestamp(),
                 end_time: meeting_end_datetime2.and_utc().timestamp(),
                 requester: sender2,
                 amount_staked: Uint128::from(60u128),
+                remote_requester: None,
             }
         ],
         meetings_response.meetings
@@ -646,6 +655,7 @@ fn request_meetings_on_different_days() -> anyhow::Result<()> {
             end_time: meeting_end_datetime1.and_utc().timestamp(),
             requester: sender1,
             amount_staked: Uint128::from(60u128),
+            remote_requester: None,
         }],
         meetings_response1.meetings
     );
@@ -665,6 +675,7 @@ fn request_meetings_on_different_days() -> anyhow::Result<()> {
             end_time: meeting_end_datetime2.and_utc().timestamp(),
             requester: sender2,
             amount_staked: Uint128::from(60u128),
+            remote_requester: None,
         }],
         meetings_response2.meetings
     );
@@ -1091,6 +1102,7 @@ fn cannot_request_meeting_with_start_and_end_being_on_different_days() -> anyhow
             &abstract_app::std::base::ExecuteMsg::Module(CalendarExecuteMsg::RequestMeeting {
                 start_time: meeting_start_datetime.and_utc().timestamp().into(),
                 end_time: meeting_end_datetime.and_utc().timestamp().into(),
+                remote_requester: None,
             }),
             &[Coin::new(60u128, DENOM)],
         )
@@ -1189,6 +1201,74 @@ fn slash_full_stake() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender,
             amount_staked: Uint128::zero(),
+            remote_requester: None,
+        }],
+        meetings_response.meetings
+    );
+
+    assert_eq!(Uint128::from(60u128), app.account().query_balance(DENOM)?);
+
+    Ok(())
+}
+
+#[test]
+fn slash_full_stake_of_remote_requester() -> anyhow::Result<()> {
+    // Set up the environment and contract
+    let (mut app, client, chain) = setup()?;
+    let block_info: BlockInfo = client.block_info()?;
+    let admin = app.account().owner()?;
+
+    let config: ConfigResponse = app.config()?;
+
+    let timezone: FixedOffset = FixedOffset::east_opt(config.utc_offset).unwrap();
+    let current_datetime = timezone
+        .timestamp_opt(block_info.time.seconds() as i64, 0)
+        .unwrap();
+
+    // Sender of the ibc hooks execution
+    let sender = chain.addr_make("sender");
+    app.set_sender(&sender);
+
+    let meeting_start_datetime: NaiveDateTime = current_datetime
+        .checked_add_days(Days::new(1))
+        .unwrap()
+        .date_naive()
+        .and_time(config.start_time.into());
+    let meeting_end_datetime = meeting_start_datetime
+        .checked_add_signed(chrono::Duration::hours(1))
+        .unwrap();
+    let remote_requester = RemoteRequester {
+        chain: TruncatedChainId::from_string("osmosis".to_owned())?,
+        address: "osmo1requester".to_owned(),
+    };
+
+    app.request_meeting(
+        meeting_end_datetime.and_utc().timestamp().into(),
+        Some(remote_requester.clone()),
+        meeting_start_datetime.and_utc().timestamp().into(),
+        &[Coin::new(60u128, DENOM)],
+    )?;
+
+    client.wait_blocks(100000)?;
+
+    let day_datetime = meeting_start_datetime
+        .date()
+        .and_time(NaiveTime::default())
+        .and_utc()
+        .timestamp();
+
+    app.set_sender(&admin);
+    app.slash_full_stake(day_datetime.into(), 0)?;
+
+    let meetings_response = app.meetings(day_datetime.into())?;
+
+    assert_eq!(
+        vec![Meeting {
+            start_time: meeting_start_datetime.and_utc().timestamp(),
+            end_time: meeting_end_datetime.and_utc().timestamp(),
+            requester: sender,
+            amount_staked: Uint128::zero(),
+            remote_requester: Some(remote_requester),
         }],
         meetings_response.meetings
     );
@@ -1245,6 +1325,7 @@ fn return_stake() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender.clone(),
             amount_staked: Uint128::zero(),
+            remote_requester: None,
         }],
         meetings_response.meetings
     );
@@ -1305,6 +1386,7 @@ fn slash_partial_stake() -> anyhow::Result<()> {
             end_time: meeting_end_datetime.and_utc().timestamp(),
             requester: sender.clone(),
             amount_staked: Uint128::zero(),
+            remote_requester: None,
         }],
         meetings_response.meetings
     );
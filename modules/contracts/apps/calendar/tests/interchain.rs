@@ -0,0 +1,173 @@
+use abstract_app::std::objects::{namespace::Namespace, AssetEntry, TruncatedChainId};
+use abstract_client::{AbstractClient, Application};
+use abstract_interface::Abstract;
+use calendar_app::{
+    msg::{
+        CalendarExecuteMsg, CalendarExecuteMsgFns, CalendarInstantiateMsg, ExecuteMsg,
+        IbcHooksMemo, IbcHooksWasmMsg, Time,
+    },
+    state::RemoteRequester,
+    CalendarAppInterface,
+};
+use cosmwasm_std::{coins, from_json, Int64, Uint128};
+use cw_asset::AssetInfoUnchecked;
+use cw_orch::{anyhow, prelude::*};
+use cw_orch_interchain::prelude::*;
+
+const JUNO: &str = "juno-1";
+const STARGAZE: &str = "stargaze-1";
+const DENOM: &str = "ustake";
+
+type Calendar = Application<MockBech32, CalendarAppInterface<MockBech32>>;
+
+/// Data of an ICS-20 packet, as emitted in the `send_packet` event
+#[derive(cosmwasm_schema::serde::Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+struct Ics20PacketData {
+    amount: String,
+    receiver: String,
+    memo: Option<String>,
+}
+
+/// Set up a calendar on juno and on stargaze, with an ICS-20 channel between the two chains.
+fn setup() -> anyhow::Result<(MockBech32InterchainEnv, Calendar, Calendar)> {
+    let mock_interchain =
+        MockBech32InterchainEnv::new(vec![(JUNO, "juno"), (STARGAZE, "stargaze")]);
+
+    let mut clients = vec![];
+    let mut calendars = vec![];
+    for chain_id in [JUNO, STARGAZE] {
+        let chain = mock_interchain.get_chain(chain_id)?;
+        let client = AbstractClient::builder(chain.clone())
+            .asset(DENOM, AssetInfoUnchecked::native(DENOM))
+            .build()?;
+        let publisher = client
+            .fetch_account(Namespace::new("abstract")?)?
+            .publisher()?;
+        publisher.publish_app::<CalendarAppInterface<MockBech32>>()?;
+
+        let calendar: Calendar = publisher.account().install_app(
+            &CalendarInstantiateMsg {
+                price_per_minute: Uint128::one(),
+                denom: AssetEntry::from(DENOM),
+                utc_offset: 0,
+                start_time: Time { hour: 9, minute: 0 },
+                end_time: Time {
+                    hour: 17,
+                    minute: 0,
+                },
+            },
+            &[],
+        )?;
+        calendar.account().set_ibc_status(true)?;
+
+        clients.push(client);
+        calendars.push(calendar);
+    }
+    clients[0].connect_to(&clients[1], &mock_interchain)?;
+    Abstract::load_from(mock_interchain.get_chain(JUNO)?)?.create_ics20_channel(
+        &Abstract::load_from(mock_interchain.get_chain(STARGAZE)?)?,
+        &mock_interchain,
+    )?;
+
+    let stargaze_calendar = calendars.pop().unwrap();
+    let juno_calendar = calendars.pop().unwrap();
+    Ok((mock_interchain, juno_calendar, stargaze_calendar))
+}
+
+/// Start and end of a one hour meeting at the start of the next day
+fn next_day_meeting(chain: &MockBech32) -> anyhow::Result<(Int64, Int64)> {
+    const DAY: i64 = 24 * 60 * 60;
+    let now = chain.block_info()?.time.seconds() as i64;
+    let start = (now / DAY + 1) * DAY + 9 * 60 * 60;
+    Ok((start.into(), (start + 60 * 60).into()))
+}
+
+#[test]
+fn request_remote_meeting_memo() -> anyhow::Result<()> {
+    let (mock_interchain, mut juno_calendar, stargaze_calendar) = setup()?;
+    let juno = mock_interchain.get_chain(JUNO)?;
+    let account_addr = juno_calendar.account().address()?;
+    juno.add_balance(&account_addr, coins(60, DENOM))?;
+
+    let host_calendar = stargaze_calendar.address()?.to_string();
+    let (start_time, end_time) = next_day_meeting(&juno)?;
+
+    juno_calendar.set_sender(&juno_calendar.account().owner()?);
+    let response = juno_calendar.request_remote_meeting(
+        end_time,
+        host_calendar.clone(),
+        TruncatedChainId::from_chain_id(STARGAZE),
+        Coin::new(60u128, DENOM),
+        start_time,
+    )?;
+
+    // The stake is sent to the host calendar, the memo books the meeting for this Account
+    let packet: Ics20PacketData =
+        from_json(response.event_attr_value("send_packet", "packet_data")?)?;
+    assert_eq!(packet.receiver, host_calendar);
+    assert_eq!(packet.amount, "60");
+    let memo: IbcHooksMemo = from_json(packet.memo.unwrap())?;
+    assert_eq!(
+        memo,
+        IbcHooksMemo {
+            wasm: IbcHooksWasmMsg {
+                contract: host_calendar,
+                msg: ExecuteMsg::Module(CalendarExecuteMsg::RequestMeeting {
+                    start_time,
+                    end_time,
+                    remote_requester: Some(RemoteRequester {
+                        chain: TruncatedChainId::from_chain_id(JUNO),
+                        address: account_addr.to_string(),
+                    }),
+                }),
+            },
+        }
+    );
+    assert!(juno.balance(&account_addr, Some(DENOM.to_owned()))?[0]
+        .amount
+        .is_zero());
+    Ok(())
+}
+
+#[test]
+fn return_stake_to_remote_requester() -> anyhow::Result<()> {
+    let (mock_interchain, _, mut stargaze_calendar) = setup()?;
+    let juno = mock_interchain.get_chain(JUNO)?;
+    let stargaze = mock_interchain.get_chain(STARGAZE)?;
+
+    // Sender of the ibc hooks execution
+    let sender = stargaze.addr_make("sender");
+    stargaze.add_balance(&sender, coins(60, DENOM))?;
+    let requester = juno.addr_make("requester");
+
+    let (start_time, end_time) = next_day_meeting(&stargaze)?;
+    stargaze_calendar.set_sender(&sender);
+    stargaze_calendar.request_meeting(
+        end_time,
+        Some(RemoteRequester {
+            chain: TruncatedChainId::from_chain_id(JUNO),
+            address: requester.to_string(),
+        }),
+        start_time,
+        &[Coin::new(60u128, DENOM)],
+    )?;
+
+    stargaze.wait_blocks(100000)?;
+
+    let day_datetime = start_time.i64() - 9 * 60 * 60;
+    stargaze_calendar.set_sender(&stargaze_calendar.account().owner()?);
+    let response = stargaze_calendar.return_stake(day_datetime.into(), 0)?;
+    mock_interchain.await_and_check_packets(STARGAZE, response)?;
+
+    // The stake is returned over IBC, nothing stays on the host chain
+    let returned = juno.balance(&requester, None)?;
+    assert_eq!(returned.len(), 1);
+    assert_eq!(returned[0].amount, Uint128::new(60));
+    assert!(returned[0].denom.starts_with("ibc/"));
+    assert!(stargaze.balance(&sender, Some(DENOM.to_owned()))?[0]
+        .amount
+        .is_zero());
+    assert!(stargaze_calendar.account().query_balance(DENOM)?.is_zero());
+    Ok(())
+}
@@ -12,6 +12,8 @@ use crate::{
 pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// The id of the app
 pub const APP_ID: &str = "abstract:payment";
+/// Reply id of the swaps of recurring payments
+pub const SWAP_REPLY_ID: u64 = 1;
 
 /// The type of the result returned by your app's entry points.
 pub type AppResult<T = Response> = Result<T, AppError>;
@@ -31,6 +33,7 @@ const APP: PaymentApp = PaymentApp::new(APP_ID, APP_VERSION, None)
     .with_execute(handlers::execute_handler)
     .with_query(handlers::query_handler)
     .with_migrate(handlers::migrate_handler)
+    .with_replies(&[(SWAP_REPLY_ID, handlers::swap_reply_handler)])
     // Specify dependencies
     .with_dependencies(&[DEX_DEPENDENCY]);
 // ANCHOR_END: dependencies
@@ -1,7 +1,7 @@
 use abstract_app::sdk::AbstractSdkError;
 use abstract_app::std::AbstractError;
 use abstract_app::AppError as AbstractAppError;
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Decimal, StdError};
 use cw_asset::AssetError;
 use cw_controllers::AdminError;
 use thiserror::Error;
@@ -34,4 +34,19 @@ pub enum AppError {
 
     #[error("Dex {0} is not registered on Abstract Name Service")]
     DexNotRegistered(String),
+
+    #[error("Asset {0} does not exist on Abstract Name Service")]
+    AssetDoesNotExist(String),
+
+    #[error("Payment interval must be greater than zero")]
+    InvalidPaymentInterval {},
+
+    #[error("Payment end must be after its start")]
+    InvalidPaymentEnd {},
+
+    #[error("Maximum spread {max_spread} of a payment exceeds {limit}")]
+    InvalidMaxSpread { max_spread: Decimal, limit: Decimal },
+
+    #[error("Recurring payment {0} does not exist")]
+    RecurringPaymentDoesNotExist(u64),
 }
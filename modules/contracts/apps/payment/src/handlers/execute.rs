@@ -1,9 +1,9 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use abstract_app::sdk::{
     cw_helpers::{AbstractAttributes, Clearable},
-    features::AbstractNameService,
-    AbstractResponse, TransferInterface,
+    features::{AbstractNameService, AbstractNameServiceClient},
+    AbstractResponse, AccountAction, Execution, TransferInterface,
 };
 use abstract_app::std::{
     ans_host::AssetPairingFilter,
@@ -12,18 +12,24 @@ use abstract_app::std::{
 };
 use abstract_dex_adapter::DexInterface;
 use cosmwasm_std::{
-    Addr, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, Storage, Uint128,
+    Addr, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage,
+    SubMsg, Timestamp, Uint128,
 };
 use cw_asset::{Asset, AssetList};
 
-use crate::contract::{AppResult, PaymentApp};
+use crate::contract::{AppResult, PaymentApp, SWAP_REPLY_ID};
 
-const MAX_SPREAD_PERCENT: u64 = 20;
+pub(crate) const MAX_SPREAD_PERCENT: u64 = 20;
+/// Maximum spread of a recurring payment swap, if not set on the payment
+pub(crate) const DEFAULT_PAYMENT_MAX_SPREAD_PERCENT: u64 = 3;
 
 use crate::{
     error::AppError,
-    msg::AppExecuteMsg,
-    state::{CONFIG, TIPPERS, TIPPER_COUNT, TIP_COUNT},
+    msg::{AppExecuteMsg, RecurringPayment, SettledPayment},
+    state::{
+        PendingSwap, PendingSwaps, CONFIG, NEXT_PAYMENT_ID, PAYMENT_HISTORY, PENDING_SWAPS,
+        RECURRING_PAYMENTS, TIPPERS, TIPPER_COUNT, TIP_COUNT,
+    },
 };
 
 pub fn execute_handler(
@@ -48,6 +54,31 @@ pub fn execute_handler(
             exchanges,
         ),
         AppExecuteMsg::Tip {} => tip(deps, env, info, module, None),
+        AppExecuteMsg::CreateRecurringPayment {
+            recipient,
+            asset,
+            receive_asset,
+            interval,
+            start,
+            end,
+            max_spread,
+        } => create_recurring_payment(
+            deps,
+            env,
+            info,
+            module,
+            recipient,
+            asset,
+            receive_asset,
+            interval,
+            start,
+            end,
+            max_spread,
+        ),
+        AppExecuteMsg::CancelRecurringPayment { id } => {
+            cancel_recurring_payment(deps, env, info, module, id)
+        }
+        AppExecuteMsg::Process {} => process(deps, env, info, module),
     }
 }
 
@@ -223,3 +254,330 @@ fn update_config(
 
     Ok(module.response("update_config"))
 }
+
+/// Create a recurring payment from the Account
+#[allow(clippy::too_many_arguments)]
+fn create_recurring_payment(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    module: PaymentApp,
+    recipient: String,
+    asset: AnsAsset,
+    receive_asset: Option<AssetEntry>,
+    interval: u64,
+    start: Option<Timestamp>,
+    end: Option<Timestamp>,
+    max_spread: Option<Decimal>,
+) -> AppResult {
+    // Only the admin should be able to call this
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &msg_info.sender)?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if interval == 0 {
+        return Err(AppError::InvalidPaymentInterval {});
+    }
+    let start = start.unwrap_or(env.block.time);
+    if end.is_some_and(|end| end < start) {
+        return Err(AppError::InvalidPaymentEnd {});
+    }
+    let max_spread = max_spread.unwrap_or(Decimal::percent(DEFAULT_PAYMENT_MAX_SPREAD_PERCENT));
+    if max_spread > Decimal::percent(MAX_SPREAD_PERCENT) {
+        return Err(AppError::InvalidMaxSpread {
+            max_spread,
+            limit: Decimal::percent(MAX_SPREAD_PERCENT),
+        });
+    }
+    let name_service = module.name_service(deps.as_ref());
+    for entry in std::iter::once(&asset.name).chain(receive_asset.as_ref()) {
+        name_service
+            .query(entry)
+            .map_err(|_| AppError::AssetDoesNotExist(entry.to_string()))?;
+    }
+
+    let id = NEXT_PAYMENT_ID.may_load(deps.storage)?.unwrap_or_default();
+    NEXT_PAYMENT_ID.save(deps.storage, &(id + 1))?;
+    RECURRING_PAYMENTS.save(
+        deps.storage,
+        id,
+        &RecurringPayment {
+            recipient,
+            asset,
+            receive_asset,
+            interval,
+            next_payment: start,
+            end,
+            max_spread,
+        },
+    )?;
+
+    Ok(module.custom_response(
+        "create_recurring_payment",
+        vec![("payment_id", id.to_string())],
+    ))
+}
+
+/// Cancel a recurring payment
+fn cancel_recurring_payment(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    module: PaymentApp,
+    id: u64,
+) -> AppResult {
+    // Only the admin should be able to call this
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &msg_info.sender)?;
+
+    if !RECURRING_PAYMENTS.has(deps.storage, id) {
+        return Err(AppError::RecurringPaymentDoesNotExist(id));
+    }
+    RECURRING_PAYMENTS.remove(deps.storage, id);
+
+    Ok(module.custom_response(
+        "cancel_recurring_payment",
+        vec![("payment_id", id.to_string())],
+    ))
+}
+
+/// Due payment, for the number of periods that are due
+struct DuePayment {
+    id: u64,
+    payment: RecurringPayment,
+    periods: u64,
+    paid: AnsAsset,
+}
+
+/// Settle all the recurring payments that are due.
+/// Payments in the asset that the recipient receives are transferred directly.
+/// Payments that swap the same assets are merged into one swap, so the simulated spread matches the executed swap.
+/// The received amounts are transferred in the swap reply, once they are known.
+/// Payments that can't be funded by the Account, or whose swap exceeds their maximum spread, stay due.
+fn process(deps: DepsMut, env: Env, info: MessageInfo, module: PaymentApp) -> AppResult {
+    // Swaps of permissionless calls could be sandwiched, so only the admin settles payments
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let exchange_strs: HashSet<&str> = config.exchanges.iter().map(AsRef::as_ref).collect();
+    let now = env.block.time;
+
+    let due_payments = RECURRING_PAYMENTS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|res| {
+            res.as_ref()
+                .map_or(true, |(_, payment)| payment.due_periods(now) > 0)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let ans = module.name_service(deps.as_ref());
+    let mut transfers: Vec<AccountAction> = vec![];
+    let mut swap_msgs: Vec<CosmosMsg> = vec![];
+    let mut pending_swaps: Vec<PendingSwap> = vec![];
+    let mut settled: Vec<(u64, SettledPayment)> = vec![];
+    let mut updated: Vec<DuePayment> = vec![];
+    let mut attrs: Vec<(&str, String)> = vec![];
+    // Balances of the Account that aren't spent by the previous payments yet
+    let mut available_funds: BTreeMap<AssetEntry, Uint128> = BTreeMap::new();
+    // Payments to swap, with the exchange to swap on, by offered and received asset
+    let mut swaps: BTreeMap<(AssetEntry, AssetEntry), (DexName, Vec<DuePayment>)> = BTreeMap::new();
+
+    for (id, payment) in due_payments {
+        let periods = payment.due_periods(now);
+        let paid = AnsAsset::new(
+            payment.asset.name.clone(),
+            payment.asset.amount * Uint128::from(periods),
+        );
+
+        let available = match available_funds.get(&paid.name) {
+            Some(available) => *available,
+            None => module.bank(deps.as_ref()).balance(&paid.name)?.amount,
+        };
+        if available < paid.amount {
+            // Keep the payment due until the Account is funded
+            attrs.push(("payment_unfunded", id.to_string()));
+            continue;
+        }
+        available_funds.insert(paid.name.clone(), available - paid.amount);
+
+        let due = DuePayment {
+            id,
+            payment,
+            periods,
+            paid,
+        };
+        match due
+            .payment
+            .receive_asset
+            .clone()
+            .filter(|receive_asset| receive_asset != &due.paid.name)
+        {
+            None => {
+                transfers.push(
+                    module
+                        .bank(deps.as_ref())
+                        .transfer(vec![due.paid.clone()], &due.payment.recipient)?,
+                );
+                settled.push((
+                    id,
+                    SettledPayment {
+                        recipient: due.payment.recipient.clone(),
+                        paid: due.paid.clone(),
+                        received: due.paid.clone(),
+                        settled_at: now,
+                    },
+                ));
+                updated.push(due);
+            }
+            Some(receive_asset) => {
+                let key = (due.paid.name.clone(), receive_asset);
+                if let Some((_, payments)) = swaps.get_mut(&key) {
+                    payments.push(due);
+                    continue;
+                }
+                let Some(dex_name) = find_swap_dex(&ans, &exchange_strs, &key.0, &key.1)? else {
+                    // Keep the payment due until a pool is available
+                    attrs.push(("swap_unavailable", id.to_string()));
+                    continue;
+                };
+                swaps.insert(key, (dex_name, vec![due]));
+            }
+        }
+    }
+
+    for ((offer_asset, receive_asset), (dex_name, payments)) in swaps {
+        let offered: Uint128 = payments.iter().map(|due| due.paid.amount).sum();
+        let offer = AnsAsset::new(offer_asset, offered);
+        // The merged swap respects the maximum spread of every payment
+        let max_spread = payments
+            .iter()
+            .map(|due| due.payment.max_spread)
+            .min()
+            .unwrap_or_default();
+
+        let dex = module.ans_dex(deps.as_ref(), &env, dex_name);
+        let simulation = dex.simulate_swap(offer.clone(), receive_asset.clone())?;
+        let spread = Decimal::checked_from_ratio(
+            simulation.spread_amount,
+            simulation.return_amount + simulation.spread_amount,
+        )
+        .unwrap_or(Decimal::MAX);
+        if spread > max_spread {
+            // Keep the payments due until the pool can absorb the swap
+            for due in payments {
+                attrs.push(("payment_spread_exceeded", due.id.to_string()));
+            }
+            continue;
+        }
+        swap_msgs.push(dex.swap(offer, receive_asset.clone(), Some(max_spread), None)?);
+        for due in payments {
+            pending_swaps.push(PendingSwap {
+                payment_id: due.id,
+                recipient: due.payment.recipient.clone(),
+                paid: due.paid.clone(),
+                receive_asset: receive_asset.clone(),
+                expected_return: simulation
+                    .return_amount
+                    .multiply_ratio(due.paid.amount, offered),
+            });
+            updated.push(due);
+        }
+    }
+
+    for DuePayment {
+        id,
+        mut payment,
+        periods,
+        ..
+    } in updated
+    {
+        attrs.push(("payment", id.to_string()));
+        payment.next_payment = payment
+            .next_payment
+            .plus_seconds(periods * payment.interval);
+        if payment.is_finished() {
+            RECURRING_PAYMENTS.remove(deps.storage, id);
+        } else {
+            RECURRING_PAYMENTS.save(deps.storage, id, &payment)?;
+        }
+    }
+    for (id, settled_payment) in settled {
+        PAYMENT_HISTORY.save(deps.storage, (id, now.seconds()), &settled_payment)?;
+    }
+
+    let mut response = module.response("process").add_abstract_attributes(attrs);
+
+    // The swaps are executed before the transfers so the reply can measure the received amounts.
+    if let Some(last_swap) = swap_msgs.pop() {
+        let baselines = swap_baselines(deps.as_ref(), &module, &pending_swaps)?;
+        PENDING_SWAPS.save(
+            deps.storage,
+            &PendingSwaps {
+                baselines,
+                swaps: pending_swaps,
+            },
+        )?;
+        response = response
+            .add_messages(swap_msgs)
+            .add_submessage(SubMsg::reply_on_success(last_swap, SWAP_REPLY_ID));
+    }
+    if !transfers.is_empty() {
+        response = response.add_message(module.executor(deps.as_ref()).execute(transfers)?);
+    }
+
+    Ok(response)
+}
+
+/// Find a configured exchange that has a pool for the two assets
+fn find_swap_dex(
+    ans: &AbstractNameServiceClient<PaymentApp>,
+    exchanges: &HashSet<&str>,
+    offer_asset: &AssetEntry,
+    ask_asset: &AssetEntry,
+) -> AppResult<Option<DexName>> {
+    let resp: Vec<AssetPairingMapEntry> = ans.pool_list(
+        Some(AssetPairingFilter {
+            asset_pair: Some((ask_asset.clone(), offer_asset.clone())),
+            dex: None,
+        }),
+        None,
+        None,
+    )?;
+
+    Ok(resp
+        .into_iter()
+        .find(|(pair, refs)| !refs.is_empty() && exchanges.contains(&pair.dex()))
+        .map(|(pair, _)| pair.dex().to_owned()))
+}
+
+/// Current balances of the received assets, minus the amounts that are offered in the swaps.
+fn swap_baselines(
+    deps: Deps,
+    module: &PaymentApp,
+    swaps: &[PendingSwap],
+) -> AppResult<Vec<AnsAsset>> {
+    let mut baselines: Vec<AnsAsset> = vec![];
+    for swap in swaps {
+        if baselines
+            .iter()
+            .any(|baseline| baseline.name == swap.receive_asset)
+        {
+            continue;
+        }
+        let balance = module.bank(deps).balance(&swap.receive_asset)?.amount;
+        let offered: Uint128 = swaps
+            .iter()
+            .filter(|s| s.paid.name == swap.receive_asset)
+            .map(|s| s.paid.amount)
+            .sum();
+        baselines.push(AnsAsset::new(
+            swap.receive_asset.clone(),
+            balance.saturating_sub(offered),
+        ));
+    }
+    Ok(baselines)
+}
@@ -4,8 +4,9 @@ pub mod instantiate;
 pub mod migrate;
 pub mod query;
 pub mod receive;
+pub mod reply;
 
 pub use crate::handlers::{
     execute::execute_handler, instantiate::instantiate_handler, migrate::migrate_handler,
-    query::query_handler, receive::receive_handler,
+    query::query_handler, receive::receive_handler, reply::swap_reply_handler,
 };
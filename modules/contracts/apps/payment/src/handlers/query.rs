@@ -5,10 +5,10 @@ use cw_storage_plus::Bound;
 use crate::{
     contract::{AppResult, PaymentApp},
     msg::{
-        AppQueryMsg, ConfigResponse, TipCountResponse, TipperCountResponse, TipperResponse,
-        TippersCountResponse,
+        AppQueryMsg, ConfigResponse, PaymentHistoryResponse, RecurringPaymentsResponse,
+        TipCountResponse, TipperCountResponse, TipperResponse, TippersCountResponse,
     },
-    state::{CONFIG, TIPPERS, TIPPER_COUNT, TIP_COUNT},
+    state::{CONFIG, PAYMENT_HISTORY, RECURRING_PAYMENTS, TIPPERS, TIPPER_COUNT, TIP_COUNT},
 };
 
 const DEFAULT_LIMIT: u32 = 10;
@@ -31,6 +31,14 @@ pub fn query_handler(
             limit,
             at_height,
         } => to_json_binary(&query_tipper(deps, address, start_after, limit, at_height)?),
+        AppQueryMsg::RecurringPayments { start_after, limit } => {
+            to_json_binary(&query_recurring_payments(deps, start_after, limit)?)
+        }
+        AppQueryMsg::PaymentHistory {
+            id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_payment_history(deps, id, start_after, limit)?),
     }
     .map_err(Into::into)
 }
@@ -106,6 +114,45 @@ fn query_list_tippers_count(
     Ok(TippersCountResponse { tippers })
 }
 
+fn query_recurring_payments(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> AppResult<RecurringPaymentsResponse> {
+    let payments = RECURRING_PAYMENTS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .collect::<StdResult<_>>()?;
+
+    Ok(RecurringPaymentsResponse { payments })
+}
+
+fn query_payment_history(
+    deps: Deps,
+    id: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> AppResult<PaymentHistoryResponse> {
+    let payments = PAYMENT_HISTORY
+        .prefix(id)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .map(|item| item.map(|(_, payment)| payment))
+        .collect::<StdResult<_>>()?;
+
+    Ok(PaymentHistoryResponse { payments })
+}
+
 fn tipper_at_height(
     deps: Deps,
     address: Addr,
@@ -0,0 +1,62 @@
+use abstract_app::sdk::{AbstractResponse, AccountAction, Execution, TransferInterface};
+use abstract_app::std::objects::AnsAsset;
+use cosmwasm_std::{DepsMut, Env, Reply, Uint128};
+
+use crate::{
+    contract::{AppResult, PaymentApp},
+    msg::SettledPayment,
+    state::{PendingSwap, PendingSwaps, PAYMENT_HISTORY, PENDING_SWAPS},
+};
+
+/// Transfer the assets received from the swaps of a `Process` call to the recipients.
+pub fn swap_reply_handler(deps: DepsMut, env: Env, module: PaymentApp, _reply: Reply) -> AppResult {
+    let PendingSwaps { baselines, swaps } = PENDING_SWAPS.load(deps.storage)?;
+    PENDING_SWAPS.remove(deps.storage);
+
+    let mut transfers: Vec<AccountAction> = vec![];
+    for baseline in baselines {
+        let balance = module.bank(deps.as_ref()).balance(&baseline.name)?.amount;
+        let mut received = balance.saturating_sub(baseline.amount);
+
+        // Split the received amount proportionally to the expected returns of the swaps.
+        let asset_swaps: Vec<&PendingSwap> = swaps
+            .iter()
+            .filter(|swap| swap.receive_asset == baseline.name)
+            .collect();
+        let mut expected: Uint128 = asset_swaps.iter().map(|swap| swap.expected_return).sum();
+        for swap in asset_swaps {
+            let amount = if expected.is_zero() {
+                received
+            } else {
+                received.multiply_ratio(swap.expected_return, expected)
+            };
+            received -= amount;
+            expected -= swap.expected_return;
+
+            let payment = AnsAsset::new(swap.receive_asset.clone(), amount);
+            if !amount.is_zero() {
+                transfers.push(
+                    module
+                        .bank(deps.as_ref())
+                        .transfer(vec![payment.clone()], &swap.recipient)?,
+                );
+            }
+            PAYMENT_HISTORY.save(
+                deps.storage,
+                (swap.payment_id, env.block.time.seconds()),
+                &SettledPayment {
+                    recipient: swap.recipient.clone(),
+                    paid: swap.paid.clone(),
+                    received: payment,
+                    settled_at: env.block.time,
+                },
+            )?;
+        }
+    }
+
+    let mut response = module.response("settle_swapped_payments");
+    if !transfers.is_empty() {
+        response = response.add_message(module.executor(deps.as_ref()).execute(transfers)?);
+    }
+    Ok(response)
+}
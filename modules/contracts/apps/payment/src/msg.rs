@@ -1,7 +1,7 @@
 use abstract_app::sdk::cw_helpers::Clearable;
 use abstract_app::std::objects::{AnsAsset, AssetEntry, DexName};
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal, Timestamp};
 
 use crate::contract::PaymentApp;
 
@@ -36,6 +36,30 @@ pub enum AppExecuteMsg {
         denom_asset: Option<String>,
         exchanges: Option<Vec<DexName>>,
     },
+    /// Create a payment from the Account to `recipient` that recurs every `interval`.
+    /// Only callable by the admin.
+    CreateRecurringPayment {
+        recipient: String,
+        /// Asset and amount paid every interval
+        asset: AnsAsset,
+        /// Asset the recipient receives, if different from the paid asset.
+        /// The paid asset is swapped on one of the configured exchanges.
+        receive_asset: Option<AssetEntry>,
+        /// Time between two payments, in seconds
+        interval: u64,
+        /// Time of the first payment, defaults to the current block time
+        start: Option<Timestamp>,
+        /// No payments are made after this time
+        end: Option<Timestamp>,
+        /// Maximum spread of the swap to the received asset, defaults to 3%.
+        /// Payments are skipped while the simulated spread of their swap exceeds it.
+        max_spread: Option<Decimal>,
+    },
+    /// Cancel a recurring payment. Only callable by the admin.
+    CancelRecurringPayment { id: u64 },
+    /// Settle all the recurring payments that are due. Only callable by the admin, so the swaps can't be sandwiched.
+    /// Payments that the Account can't fund or that exceed their maximum spread are skipped and stay due.
+    Process {},
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -81,6 +105,20 @@ pub enum AppQueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Returns [`RecurringPaymentsResponse`]
+    #[returns(RecurringPaymentsResponse)]
+    RecurringPayments {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns [`PaymentHistoryResponse`]
+    #[returns(PaymentHistoryResponse)]
+    PaymentHistory {
+        id: u64,
+        /// Settlement time in seconds
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -118,3 +156,60 @@ pub struct TippersCountResponse {
 pub struct TipCountResponse {
     pub count: u32,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct RecurringPayment {
+    pub recipient: Addr,
+    /// Asset and amount paid every interval
+    pub asset: AnsAsset,
+    /// Asset the recipient receives, if different from the paid asset
+    pub receive_asset: Option<AssetEntry>,
+    /// Time between two payments, in seconds
+    pub interval: u64,
+    /// Time of the next payment
+    pub next_payment: Timestamp,
+    /// No payments are made after this time
+    pub end: Option<Timestamp>,
+    /// Maximum spread of the swap to the received asset
+    pub max_spread: Decimal,
+}
+
+impl RecurringPayment {
+    /// Number of intervals that are due at `now`.
+    pub fn due_periods(&self, now: Timestamp) -> u64 {
+        let last = match self.end {
+            Some(end) if end < now => end,
+            _ => now,
+        };
+        if last < self.next_payment {
+            return 0;
+        }
+        (last.seconds() - self.next_payment.seconds()) / self.interval + 1
+    }
+
+    /// Whether all the payments have been made.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.end, Some(end) if self.next_payment > end)
+    }
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct RecurringPaymentsResponse {
+    /// Recurring payments by id, with the time of their next payment
+    pub payments: Vec<(u64, RecurringPayment)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct SettledPayment {
+    pub recipient: Addr,
+    /// Asset and amount paid by the Account
+    pub paid: AnsAsset,
+    /// Asset and amount received by the recipient
+    pub received: AnsAsset,
+    pub settled_at: Timestamp,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PaymentHistoryResponse {
+    pub payments: Vec<SettledPayment>,
+}
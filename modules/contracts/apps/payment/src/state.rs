@@ -1,6 +1,8 @@
-use abstract_app::std::objects::{AssetEntry, DexName};
+use abstract_app::std::objects::{AnsAsset, AssetEntry, DexName};
 use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::{Item, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
+
+use crate::msg::{RecurringPayment, SettledPayment};
 
 pub const CONFIG: Item<Config> = Item::new("cfg");
 // The sender address is used here for querying by tipper
@@ -13,6 +15,12 @@ pub const TIPPER_COUNT: SnapshotMap<&Addr, u32> = SnapshotMap::new(
     "tps-count__chglg",
     Strategy::EveryBlock,
 );
+pub const NEXT_PAYMENT_ID: Item<u64> = Item::new("pmt-id");
+pub const RECURRING_PAYMENTS: Map<u64, RecurringPayment> = Map::new("pmts");
+// Keyed by payment id and settlement time in seconds
+pub const PAYMENT_HISTORY: Map<(u64, u64), SettledPayment> = Map::new("pmt-hist");
+// Swaps of a `Process` call that are settled in the swap reply
+pub const PENDING_SWAPS: Item<PendingSwaps> = Item::new("pmt-swaps");
 
 #[cosmwasm_schema::cw_serde]
 pub struct Config {
@@ -20,3 +28,20 @@ pub struct Config {
     pub denom_asset: String,
     pub exchanges: Vec<DexName>,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct PendingSwaps {
+    /// Balances of the received assets before the swaps, minus the amounts offered in the swaps
+    pub baselines: Vec<AnsAsset>,
+    pub swaps: Vec<PendingSwap>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PendingSwap {
+    pub payment_id: u64,
+    pub recipient: Addr,
+    pub paid: AnsAsset,
+    pub receive_asset: AssetEntry,
+    /// Simulated return of the swap, used to split the received amount between payments
+    pub expected_return: Uint128,
+}
@@ -1,4 +1,5 @@
 use abstract_app::sdk::cw_helpers::Clearable;
+use abstract_app::std::ABSTRACT_EVENT_TYPE;
 use abstract_app::std::{
     ans_host::ExecuteMsgFns,
     objects::{AccountId, AnsAsset, AssetEntry},
@@ -22,8 +23,8 @@ use mockdex_bundle::WynDex;
 use payment_app::{
     contract::{APP_ID, APP_VERSION},
     msg::{
-        AppInstantiateMsg, ConfigResponse, TipCountResponse, TipperCountResponse, TipperResponse,
-        TippersCountResponse,
+        AppExecuteMsg, AppInstantiateMsg, ConfigResponse, ExecuteMsg, TipCountResponse,
+        TipperCountResponse, TipperResponse, TippersCountResponse,
     },
     *,
 };
@@ -516,3 +517,291 @@ fn test_sent_desired_asset() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_recurring_payment() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, _abstr_deployment, app, wyndex) = setup(mock.clone(), None)?;
+    let eur = wyndex.eur_token.to_string();
+    let recipient = mock.addr_make("recipient");
+    mock.set_balance(&account.address()?, coins(1_000, eur.clone()))?;
+
+    let start = mock.block_info()?.time;
+    account.admin_execute_on_module(
+        app.id(),
+        to_json_binary(&ExecuteMsg::Module(AppExecuteMsg::CreateRecurringPayment {
+            recipient: recipient.to_string(),
+            asset: AnsAsset::new(mockdex_bundle::EUR, Uint128::new(100)),
+            receive_asset: None,
+            interval: 100,
+            start: None,
+            end: Some(start.plus_seconds(250)),
+            max_spread: None,
+        }))?,
+        &[],
+    )?;
+
+    // Only the admin can process payments
+    assert!(app.call_as(&mock.addr_make("processor")).process().is_err());
+
+    // First payment is due right away
+    app.process()?;
+    assert_eq!(mock.query_balance(&recipient, &eur)?, Uint128::new(100));
+
+    // Nothing is due in the same block
+    app.process()?;
+    assert_eq!(mock.query_balance(&recipient, &eur)?, Uint128::new(100));
+
+    // Missed payments are caught up, up to the end of the payment
+    mock.wait_seconds(300)?;
+    app.process()?;
+    assert_eq!(mock.query_balance(&recipient, &eur)?, Uint128::new(300));
+    assert_eq!(
+        mock.query_balance(&account.address()?, &eur)?,
+        Uint128::new(700)
+    );
+
+    // Finished payments are removed and kept in the history
+    assert!(app.recurring_payments(None, None)?.payments.is_empty());
+    let history = app.payment_history(0, None, None)?;
+    assert_eq!(
+        history
+            .payments
+            .iter()
+            .map(|payment| payment.received.amount)
+            .collect::<Vec<_>>(),
+        vec![Uint128::new(100), Uint128::new(200)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_recurring_payment_swap() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, _abstr_deployment, app, wyndex) = setup(mock.clone(), None)?;
+    let eur = wyndex.eur_token.to_string();
+    let usd = wyndex.usd_token.to_string();
+    let recipient = mock.addr_make("recipient");
+    mock.set_balance(&account.address()?, coins(1_000, eur.clone()))?;
+
+    account.admin_execute_on_module(
+        app.id(),
+        to_json_binary(&ExecuteMsg::Module(AppExecuteMsg::CreateRecurringPayment {
+            recipient: recipient.to_string(),
+            asset: AnsAsset::new(mockdex_bundle::EUR, Uint128::new(100)),
+            receive_asset: Some(AssetEntry::new(mockdex_bundle::USD)),
+            interval: 100,
+            start: None,
+            end: None,
+            max_spread: None,
+        }))?,
+        &[],
+    )?;
+
+    app.process()?;
+
+    let received = mock.query_balance(&recipient, &usd)?;
+    assert!(!received.is_zero());
+    assert_eq!(
+        mock.query_balance(&account.address()?, &eur)?,
+        Uint128::new(900)
+    );
+    // The Account keeps none of the swapped asset
+    assert!(mock.query_balance(&account.address()?, &usd)?.is_zero());
+
+    let history = app.payment_history(0, None, None)?;
+    assert_eq!(
+        history.payments[0].received,
+        AnsAsset::new(mockdex_bundle::USD, received)
+    );
+
+    // Next payment is still scheduled
+    let payments = app.recurring_payments(None, None)?.payments;
+    assert_eq!(payments.len(), 1);
+    assert_eq!(
+        payments[0].1.next_payment,
+        mock.block_info()?.time.plus_seconds(100)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_recurring_payment_swaps_are_merged() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, _abstr_deployment, app, wyndex) = setup(mock.clone(), None)?;
+    let eur = wyndex.eur_token.to_string();
+    let usd = wyndex.usd_token.to_string();
+    let first_recipient = mock.addr_make("first_recipient");
+    let second_recipient = mock.addr_make("second_recipient");
+    mock.set_balance(&account.address()?, coins(1_000, eur.clone()))?;
+
+    for recipient in [&first_recipient, &second_recipient] {
+        account.admin_execute_on_module(
+            app.id(),
+            to_json_binary(&ExecuteMsg::Module(AppExecuteMsg::CreateRecurringPayment {
+                recipient: recipient.to_string(),
+                asset: AnsAsset::new(mockdex_bundle::EUR, Uint128::new(100)),
+                receive_asset: Some(AssetEntry::new(mockdex_bundle::USD)),
+                interval: 100,
+                start: None,
+                end: None,
+                max_spread: None,
+            }))?,
+            &[],
+        )?;
+    }
+
+    // Both payments are swapped in one swap and split the received amount
+    app.process()?;
+    let received = mock.query_balance(&first_recipient, &usd)?;
+    assert!(!received.is_zero());
+    assert!(
+        mock.query_balance(&second_recipient, &usd)?
+            .abs_diff(received)
+            <= Uint128::one()
+    );
+    assert_eq!(
+        mock.query_balance(&account.address()?, &eur)?,
+        Uint128::new(800)
+    );
+    assert!(mock.query_balance(&account.address()?, &usd)?.is_zero());
+
+    Ok(())
+}
+
+#[test]
+fn test_recurring_payment_unfunded() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, _abstr_deployment, app, wyndex) = setup(mock.clone(), None)?;
+    let eur = wyndex.eur_token.to_string();
+    let first_recipient = mock.addr_make("first_recipient");
+    let second_recipient = mock.addr_make("second_recipient");
+    // Only enough to fund one of the payments
+    mock.set_balance(&account.address()?, coins(150, eur.clone()))?;
+
+    for recipient in [&first_recipient, &second_recipient] {
+        account.admin_execute_on_module(
+            app.id(),
+            to_json_binary(&ExecuteMsg::Module(AppExecuteMsg::CreateRecurringPayment {
+                recipient: recipient.to_string(),
+                asset: AnsAsset::new(mockdex_bundle::EUR, Uint128::new(100)),
+                receive_asset: None,
+                interval: 100,
+                start: None,
+                end: None,
+                max_spread: None,
+            }))?,
+            &[],
+        )?;
+    }
+
+    // The unfunded payment is skipped, without reverting the funded one
+    let response = app.process()?;
+    assert_eq!(
+        response.event_attr_value(ABSTRACT_EVENT_TYPE, "payment_unfunded")?,
+        "1"
+    );
+    assert_eq!(
+        mock.query_balance(&first_recipient, &eur)?,
+        Uint128::new(100)
+    );
+    assert!(mock.query_balance(&second_recipient, &eur)?.is_zero());
+
+    // The skipped payment stays due and is settled once the Account is funded
+    let start = mock.block_info()?.time;
+    let payments = app.recurring_payments(None, None)?.payments;
+    assert_eq!(payments[1].1.next_payment, start);
+
+    mock.add_balance(&account.address()?, coins(50, eur.clone()))?;
+    app.process()?;
+    assert_eq!(
+        mock.query_balance(&second_recipient, &eur)?,
+        Uint128::new(100)
+    );
+    assert!(mock.query_balance(&account.address()?, &eur)?.is_zero());
+
+    Ok(())
+}
+
+#[test]
+fn test_recurring_payment_max_spread() -> anyhow::Result<()> {
+    let mock = MockBech32::new("sender");
+
+    let (account, _abstr_deployment, app, wyndex) = setup(mock.clone(), None)?;
+    let eur = wyndex.eur_token.to_string();
+    let usd = wyndex.usd_token.to_string();
+    let recipient = mock.addr_make("recipient");
+    mock.set_balance(&account.address()?, coins(5_000, eur.clone()))?;
+
+    // The spread can't exceed the spread of tips
+    let err = account
+        .admin_execute_on_module(
+            app.id(),
+            to_json_binary(&ExecuteMsg::Module(AppExecuteMsg::CreateRecurringPayment {
+                recipient: recipient.to_string(),
+                asset: AnsAsset::new(mockdex_bundle::EUR, Uint128::new(100)),
+                receive_asset: Some(AssetEntry::new(mockdex_bundle::USD)),
+                interval: 100,
+                start: None,
+                end: None,
+                max_spread: Some(Decimal::percent(50)),
+            }))?,
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.root().to_string().contains("exceeds"));
+
+    // A swap of 2000 EUR moves the 10k/10k pool far beyond the default spread
+    account.admin_execute_on_module(
+        app.id(),
+        to_json_binary(&ExecuteMsg::Module(AppExecuteMsg::CreateRecurringPayment {
+            recipient: recipient.to_string(),
+            asset: AnsAsset::new(mockdex_bundle::EUR, Uint128::new(2_000)),
+            receive_asset: Some(AssetEntry::new(mockdex_bundle::USD)),
+            interval: 100,
+            start: None,
+            end: None,
+            max_spread: None,
+        }))?,
+        &[],
+    )?;
+    // A small payment in the same call is still settled
+    account.admin_execute_on_module(
+        app.id(),
+        to_json_binary(&ExecuteMsg::Module(AppExecuteMsg::CreateRecurringPayment {
+            recipient: recipient.to_string(),
+            asset: AnsAsset::new(mockdex_bundle::EUR, Uint128::new(100)),
+            receive_asset: None,
+            interval: 100,
+            start: None,
+            end: None,
+            max_spread: None,
+        }))?,
+        &[],
+    )?;
+
+    let response = app.process()?;
+    assert_eq!(
+        response.event_attr_value(ABSTRACT_EVENT_TYPE, "payment_spread_exceeded")?,
+        "0"
+    );
+    assert!(mock.query_balance(&recipient, &usd)?.is_zero());
+    assert_eq!(mock.query_balance(&recipient, &eur)?, Uint128::new(100));
+    assert_eq!(
+        mock.query_balance(&account.address()?, &eur)?,
+        Uint128::new(4_900)
+    );
+
+    // The swapped payment stays due
+    let payments = app.recurring_payments(None, None)?.payments;
+    assert_eq!(payments[0].1.next_payment, mock.block_info()?.time);
+    assert_eq!(payments[0].1.max_spread, Decimal::percent(3));
+
+    Ok(())
+}
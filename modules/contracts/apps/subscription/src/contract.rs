@@ -29,7 +29,8 @@ const SUBSCRIPTION_MODULE: SubscriptionApp =
     SubscriptionApp::new(SUBSCRIPTION_ID, CONTRACT_VERSION, None)
         .with_execute(handlers::execute_handler)
         .with_instantiate(handlers::instantiate_handler)
-        .with_query(handlers::query_handler);
+        .with_query(handlers::query_handler)
+        .with_migrate(handlers::migrate_handler);
 
 // export endpoints
 #[cfg(feature = "export")]
@@ -58,19 +59,35 @@ mod tests {
     use std::str::FromStr;
 
     use cosmwasm_std::{
-        testing::{mock_dependencies, mock_env},
-        to_json_binary, CosmosMsg, Decimal, SubMsg, WasmMsg,
+        testing::{message_info, mock_dependencies, mock_env},
+        to_json_binary, Addr, CosmosMsg, Decimal, StdResult, Storage, SubMsg, Timestamp, WasmMsg,
     };
 
     use super::*;
     use crate::{
         msg::{HookReceiverExecuteMsg, UnsubscribedHookMsg},
         state::{
-            Subscriber, SubscriptionConfig, SubscriptionState, INCOME_TWA, SUBSCRIBERS,
-            SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+            EmissionType, Subscriber, SubscriptionConfig, SubscriptionPlan, SubscriptionPlanBase,
+            SubscriptionState, DEFAULT_PLAN, INCOME_TWA, PLANS, SUBSCRIBERS, SUBSCRIPTION_CONFIG,
+            SUBSCRIPTION_STATE,
         },
     };
 
+    fn default_plan() -> SubscriptionPlan {
+        SubscriptionPlan {
+            settings: SubscriptionPlanBase {
+                payment_asset: cw_asset::AssetInfoBase::Native("token".to_owned()),
+                cost_per_second: Decimal::from_str("0.1").unwrap(),
+                emissions_weight: Decimal::one(),
+            },
+            closed: false,
+        }
+    }
+
+    fn save_default_plan(storage: &mut dyn Storage) {
+        PLANS.save(storage, DEFAULT_PLAN, &default_plan()).unwrap();
+    }
+
     #[test]
     fn unsubscribe_no_hook_msg() {
         let mut deps = mock_dependencies();
@@ -86,13 +103,12 @@ mod tests {
             .save(
                 depsmut.storage,
                 &SubscriptionConfig {
-                    payment_asset: cw_asset::AssetInfoBase::Native("token".to_owned()),
-                    subscription_cost_per_second: Decimal::from_str("0.1").unwrap(),
                     subscription_per_second_emissions: crate::state::EmissionType::None,
                     unsubscribe_hook_addr: None,
                 },
             )
             .unwrap();
+        save_default_plan(depsmut.storage);
         SUBSCRIBERS
             .save(
                 depsmut.storage,
@@ -100,11 +116,19 @@ mod tests {
                 &Subscriber {
                     expiration_timestamp: env.block.time,
                     last_emission_claim_timestamp: env.block.time,
+                    plan: DEFAULT_PLAN.to_owned(),
                 },
             )
             .unwrap();
         SUBSCRIPTION_STATE
-            .save(depsmut.storage, &SubscriptionState { active_subs: 1 })
+            .save(
+                depsmut.storage,
+                &SubscriptionState {
+                    active_subs: 1,
+                    income_per_second: Decimal::from_str("0.1").unwrap(),
+                    total_emissions_weight: Decimal::one(),
+                },
+            )
             .unwrap();
 
         let res = handlers::execute::unsubscribe(depsmut, env, app, vec![bob.to_string()]).unwrap();
@@ -128,13 +152,12 @@ mod tests {
             .save(
                 depsmut.storage,
                 &SubscriptionConfig {
-                    payment_asset: cw_asset::AssetInfoBase::Native("token".to_owned()),
-                    subscription_cost_per_second: Decimal::from_str("0.1").unwrap(),
                     subscription_per_second_emissions: crate::state::EmissionType::None,
                     unsubscribe_hook_addr: Some(alice.clone()),
                 },
             )
             .unwrap();
+        save_default_plan(depsmut.storage);
         SUBSCRIBERS
             .save(
                 depsmut.storage,
@@ -142,11 +165,19 @@ mod tests {
                 &Subscriber {
                     expiration_timestamp: env.block.time,
                     last_emission_claim_timestamp: env.block.time,
+                    plan: DEFAULT_PLAN.to_owned(),
                 },
             )
             .unwrap();
         SUBSCRIPTION_STATE
-            .save(depsmut.storage, &SubscriptionState { active_subs: 1 })
+            .save(
+                depsmut.storage,
+                &SubscriptionState {
+                    active_subs: 1,
+                    income_per_second: Decimal::from_str("0.1").unwrap(),
+                    total_emissions_weight: Decimal::one(),
+                },
+            )
             .unwrap();
 
         let res = handlers::execute::unsubscribe(depsmut, env, app, vec![bob.to_string()]).unwrap();
@@ -161,4 +192,139 @@ mod tests {
         }));
         assert_eq!(res.messages, vec![expected_msg]);
     }
+
+    /// Storage layout of an instance without plans, with `active_subs` subscribers of which `subscriber` expires at `expiration`
+    fn save_single_plan_instance(
+        storage: &mut dyn Storage,
+        active_subs: u32,
+        subscriber: &Addr,
+        expiration: Timestamp,
+    ) {
+        storage.set(
+            b"config",
+            br#"{"payment_asset":{"native":"token"},"subscription_cost_per_second":"0.1","subscription_per_second_emissions":"none","unsubscribe_hook_addr":null}"#,
+        );
+        storage.set(
+            b"state",
+            format!(r#"{{"active_subs":{active_subs}}}"#).as_bytes(),
+        );
+        storage.set(
+            &SUBSCRIBERS.key(subscriber),
+            format!(
+                r#"{{"expiration_timestamp":"{}","last_emission_claim_timestamp":"{}"}}"#,
+                expiration.nanos(),
+                expiration.nanos()
+            )
+            .as_bytes(),
+        );
+    }
+
+    #[test]
+    fn migrate_single_plan_instance() {
+        let mut deps = mock_dependencies();
+        let bob = deps.api.addr_make("bob");
+        let env = mock_env();
+
+        save_single_plan_instance(&mut deps.storage, 2, &bob, env.block.time);
+
+        handlers::migrate_handler(
+            deps.as_mut(),
+            env,
+            SUBSCRIPTION_MODULE,
+            SubscriptionMigrateMsg {},
+        )
+        .unwrap();
+
+        assert_eq!(
+            SUBSCRIPTION_CONFIG.load(&deps.storage).unwrap(),
+            SubscriptionConfig {
+                subscription_per_second_emissions: EmissionType::None,
+                unsubscribe_hook_addr: None,
+            }
+        );
+        assert_eq!(
+            PLANS.load(&deps.storage, DEFAULT_PLAN).unwrap(),
+            default_plan()
+        );
+        assert_eq!(
+            SUBSCRIPTION_STATE.load(&deps.storage).unwrap(),
+            SubscriptionState {
+                active_subs: 2,
+                income_per_second: Decimal::from_str("0.2").unwrap(),
+                total_emissions_weight: Decimal::from_str("2").unwrap(),
+            }
+        );
+        let subscriber = SUBSCRIBERS.load(&deps.storage, &bob).unwrap();
+        assert_eq!(subscriber.plan, DEFAULT_PLAN);
+    }
+
+    #[test]
+    fn migrated_subscribers_move_to_replacement_plan() {
+        let mut deps = mock_dependencies();
+        let bob = deps.api.addr_make("bob");
+        let env = mock_env();
+
+        save_single_plan_instance(
+            &mut deps.storage,
+            1,
+            &bob,
+            env.block.time.plus_seconds(1000),
+        );
+        INCOME_TWA
+            .instantiate(&mut deps.storage, &env, None, 259200u64)
+            .unwrap();
+        handlers::migrate_handler(
+            deps.as_mut(),
+            env.clone(),
+            SUBSCRIPTION_MODULE,
+            SubscriptionMigrateMsg {},
+        )
+        .unwrap();
+
+        // `UpdateSubscriptionConfig` no longer changes the price, a new plan replaces the migrated one
+        handlers::execute::save_plan(
+            &mut deps.storage,
+            &deps.api,
+            "v2",
+            SubscriptionPlanBase {
+                payment_asset: cw_asset::AssetInfoBase::Native("token".to_owned()),
+                cost_per_second: Decimal::from_str("0.2").unwrap(),
+                emissions_weight: Decimal::one(),
+            },
+        )
+        .unwrap();
+        PLANS
+            .update(&mut deps.storage, DEFAULT_PLAN, |plan| {
+                StdResult::Ok(SubscriptionPlan {
+                    closed: true,
+                    ..plan.unwrap()
+                })
+            })
+            .unwrap();
+
+        handlers::execute::change_plan(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&bob, &[]),
+            SUBSCRIPTION_MODULE,
+            "v2".to_owned(),
+        )
+        .unwrap();
+
+        // The remaining time of the migrated subscription is converted at the new price
+        let subscriber = SUBSCRIBERS.load(&deps.storage, &bob).unwrap();
+        assert_eq!(subscriber.plan, "v2");
+        assert_eq!(
+            subscriber.expiration_timestamp,
+            env.block.time.plus_seconds(500)
+        );
+        assert_eq!(
+            SUBSCRIPTION_STATE.load(&deps.storage).unwrap(),
+            SubscriptionState {
+                active_subs: 1,
+                income_per_second: Decimal::from_str("0.2").unwrap(),
+                total_emissions_weight: Decimal::one(),
+            }
+        );
+    }
 }
@@ -65,4 +65,25 @@ pub enum SubscriptionError {
 
     #[error("Income averaging period can't be zero")]
     ZeroAveragePeriod {},
+
+    #[error("Subscription plan {0} not found")]
+    PlanNotFound(String),
+
+    #[error("Subscription plan {0} already exists")]
+    PlanAlreadyExists(String),
+
+    #[error("Subscription plan {0} is closed")]
+    PlanClosed(String),
+
+    #[error("Subscription plan cost can't be zero")]
+    ZeroPlanCost {},
+
+    #[error("Subscription plans must be paid in {0}")]
+    PlanPaymentAssetMismatch(AssetInfo),
+
+    #[error("Already subscribed to plan {0}")]
+    AlreadyOnPlan(String),
+
+    #[error("Subscribed to plan {current}, use the change plan message to switch plans")]
+    PlanChangeRequired { current: String },
 }
@@ -1,15 +1,19 @@
 use abstract_app::sdk::{
     cw_helpers::Clearable, AbstractResponse, AccountAction, Execution, TransferInterface,
 };
-use cosmwasm_std::{Addr, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
-use cw_asset::{Asset, AssetInfoUnchecked};
+use cosmwasm_std::{
+    Addr, Api, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128,
+};
+use cw_asset::Asset;
 
 use crate::{
     contract::{SubscriptionApp, SubscriptionResult},
     msg::{SubscriptionExecuteMsg, UnsubscribedHookMsg},
     state::{
-        EmissionType, Subscriber, SubscriptionConfig, SubscriptionState, EXPIRED_SUBSCRIBERS,
-        INCOME_TWA, SUBSCRIBERS, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+        EmissionType, PlanId, Subscriber, SubscriptionConfig, SubscriptionPlan,
+        SubscriptionPlanBase, SubscriptionPlanSettings, SubscriptionState, DEFAULT_PLAN,
+        EXPIRED_SUBSCRIBERS, INCOME_TWA, PLANS, SUBSCRIBERS, SUBSCRIPTION_CONFIG,
+        SUBSCRIPTION_STATE,
     },
     SubscriptionError,
 };
@@ -24,27 +28,35 @@ pub fn execute_handler(
     msg: SubscriptionExecuteMsg,
 ) -> SubscriptionResult {
     match msg {
-        SubscriptionExecuteMsg::Pay { subscriber_addr } => {
+        SubscriptionExecuteMsg::Pay {
+            subscriber_addr,
+            plan,
+        } => {
             let maybe_received_coin = info.funds.last();
             let subscriber_addr = subscriber_addr
                 .map(|human| deps.api.addr_validate(&human))
                 .transpose()?
                 .unwrap_or(info.sender.clone());
             if let Some(coin) = maybe_received_coin.cloned() {
-                try_pay(module, deps, env, Asset::from(coin), subscriber_addr)
+                try_pay(module, deps, env, Asset::from(coin), subscriber_addr, plan)
             } else {
                 Err(SubscriptionError::NotUsingCW20Hook {})
             }
         }
+        SubscriptionExecuteMsg::ChangePlan { plan } => change_plan(deps, env, info, module, plan),
         SubscriptionExecuteMsg::Unsubscribe { unsubscribe_addrs } => {
             unsubscribe(deps, env, module, unsubscribe_addrs)
         }
         SubscriptionExecuteMsg::ClaimEmissions { addr } => {
             claim_subscriber_emissions(&module, &mut deps, &env, addr)
         }
+        SubscriptionExecuteMsg::AddPlan { plan_id, plan } => {
+            add_plan(deps, env, info, module, plan_id, plan)
+        }
+        SubscriptionExecuteMsg::ClosePlan { plan_id } => {
+            close_plan(deps, env, info, module, plan_id)
+        }
         SubscriptionExecuteMsg::UpdateSubscriptionConfig {
-            payment_asset,
-            subscription_cost_per_second,
             subscription_per_second_emissions,
             unsubscribe_hook_addr,
         } => update_subscription_config(
@@ -52,8 +64,6 @@ pub fn execute_handler(
             env,
             info,
             module,
-            payment_asset,
-            subscription_cost_per_second,
             subscription_per_second_emissions,
             unsubscribe_hook_addr,
         ),
@@ -72,13 +82,27 @@ pub fn try_pay(
     env: Env,
     asset: Asset,
     subscriber_addr: Addr,
+    plan: Option<PlanId>,
 ) -> SubscriptionResult {
     // Load all needed states
-    let config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
     let twa_data = INCOME_TWA.load(deps.storage)?;
     let base_state = module.load_state(deps.storage)?;
+    let maybe_active_sub = SUBSCRIBERS.may_load(deps.storage, &subscriber_addr)?;
+
+    // Active subscribers can only extend their current plan
+    let plan_id = match (&maybe_active_sub, plan) {
+        (Some(active_sub), Some(plan)) if active_sub.plan != plan => {
+            return Err(SubscriptionError::PlanChangeRequired {
+                current: active_sub.plan.clone(),
+            })
+        }
+        (_, Some(plan)) => plan,
+        (Some(active_sub), None) => active_sub.plan.clone(),
+        (None, None) => DEFAULT_PLAN.to_owned(),
+    };
+    let plan = load_open_plan(deps.storage, &plan_id)?;
     // Construct deposit info
-    let deposit_info = config.payment_asset;
+    let deposit_info = plan.payment_asset.clone();
 
     // Assert payment asset and claimed asset infos are the same
     if deposit_info != asset.info {
@@ -86,13 +110,10 @@ pub fn try_pay(
     }
     // Minimum of one period worth to (re)-subscribe.
     // prevents un- and re-subscribing all the time.
-    let required_payment = Uint128::from(twa_data.averaging_period)
-        .checked_mul_ceil(config.subscription_cost_per_second)?;
-    let paid_for_seconds = asset
-        .amount
-        .checked_div_floor(config.subscription_cost_per_second)?
-        .u128() as u64;
-    if let Some(mut active_sub) = SUBSCRIBERS.may_load(deps.storage, &subscriber_addr)? {
+    let required_payment =
+        Uint128::from(twa_data.averaging_period).checked_mul_ceil(plan.cost_per_second)?;
+    let paid_for_seconds = asset.amount.checked_div_floor(plan.cost_per_second)?.u128() as u64;
+    if let Some(mut active_sub) = maybe_active_sub {
         // Subscriber is active, update balance
         active_sub.extend(paid_for_seconds);
         SUBSCRIBERS.save(deps.storage, &subscriber_addr, &active_sub)?;
@@ -104,19 +125,14 @@ pub fn try_pay(
                 deposit_info.to_string(),
             ));
         }
-        let subscriber = Subscriber::new(&env.block, paid_for_seconds);
+        let subscriber = Subscriber::new(&env.block, paid_for_seconds, plan_id);
         let mut subscription_state = SUBSCRIPTION_STATE.load(deps.storage)?;
-        INCOME_TWA.accumulate(
-            &env,
-            deps.storage,
-            Decimal::from_atomics(Uint128::from(subscription_state.active_subs), 0)?
-                * config.subscription_cost_per_second,
-        )?;
+        INCOME_TWA.accumulate(&env, deps.storage, subscription_state.income_per_second)?;
         // Remove from expired list in case it's re-sub
         EXPIRED_SUBSCRIBERS.remove(deps.storage, &subscriber_addr);
 
         SUBSCRIBERS.save(deps.storage, &subscriber_addr, &subscriber)?;
-        subscription_state.active_subs += 1;
+        subscription_state.add_subscriber(&plan);
         SUBSCRIPTION_STATE.save(deps.storage, &subscription_state)?;
     }
 
@@ -129,6 +145,75 @@ pub fn try_pay(
         ))
 }
 
+/// Move the subscription of the sender to `new_plan_id`.
+/// The value of the remaining subscription time is credited towards the new plan.
+pub fn change_plan(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: SubscriptionApp,
+    new_plan_id: PlanId,
+) -> SubscriptionResult {
+    let subscriber_addr = info.sender;
+    let mut subscriber = SUBSCRIBERS.load(deps.storage, &subscriber_addr)?;
+    if subscriber.plan == new_plan_id {
+        return Err(SubscriptionError::AlreadyOnPlan(new_plan_id));
+    }
+    let current_plan = PLANS.load(deps.storage, &subscriber.plan)?.settings;
+    let new_plan = load_open_plan(deps.storage, &new_plan_id)?;
+    // Credit can only be converted between plans that are paid with the same asset
+    if current_plan.payment_asset != new_plan.payment_asset {
+        return Err(SubscriptionError::WrongToken(current_plan.payment_asset));
+    }
+
+    let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
+    let mut subscription_state = SUBSCRIPTION_STATE.load(deps.storage)?;
+
+    // Settle the emissions of the current plan before the weight changes
+    let maybe_claim_msg = match claim_emissions_msg(
+        &module,
+        deps.as_ref(),
+        &env,
+        &mut subscriber,
+        &subscriber_addr,
+        subscription_config.subscription_per_second_emissions,
+        current_plan.emissions_weight,
+        &subscription_state,
+    ) {
+        Ok(maybe_msg) => maybe_msg,
+        // If just claimed or not enabled - no claims
+        Err(SubscriptionError::EmissionsAlreadyClaimed {})
+        | Err(SubscriptionError::SubscriberEmissionsNotEnabled {}) => None,
+        Err(error) => {
+            return Err(error);
+        }
+    };
+
+    let credit = Uint128::from(subscriber.remaining_seconds(&env.block))
+        .mul_floor(current_plan.cost_per_second);
+    let credited_seconds = credit.checked_div_floor(new_plan.cost_per_second)?.u128() as u64;
+    subscriber.expiration_timestamp = env.block.time.plus_seconds(credited_seconds);
+    subscriber.plan = new_plan_id;
+
+    INCOME_TWA.accumulate(&env, deps.storage, subscription_state.income_per_second)?;
+    subscription_state.remove_subscriber(&current_plan)?;
+    subscription_state.add_subscriber(&new_plan);
+    SUBSCRIPTION_STATE.save(deps.storage, &subscription_state)?;
+    SUBSCRIBERS.save(deps.storage, &subscriber_addr, &subscriber)?;
+
+    let mut response = module.custom_response(
+        "change_plan",
+        vec![
+            ("plan", subscriber.plan),
+            ("expiration", subscriber.expiration_timestamp.to_string()),
+        ],
+    );
+    if let Some(action) = maybe_claim_msg {
+        response = response.add_message(module.executor(deps.as_ref()).execute(vec![action])?);
+    }
+    Ok(response)
+}
+
 pub fn unsubscribe(
     deps: DepsMut,
     env: Env,
@@ -148,16 +233,12 @@ pub fn unsubscribe(
     let mut claim_actions: Vec<AccountAction> = vec![];
 
     // update income
-    INCOME_TWA.accumulate(
-        &env,
-        deps.storage,
-        Decimal::from_atomics(Uint128::from(subscription_state.active_subs), 0)?
-            * subscription_config.subscription_cost_per_second,
-    )?;
+    INCOME_TWA.accumulate(&env, deps.storage, subscription_state.income_per_second)?;
 
     for addr in unsubscribe_addrs.into_iter() {
         let mut subscriber = SUBSCRIBERS.load(deps.storage, &addr)?;
         if subscriber.is_expired(&env.block) {
+            let plan = PLANS.load(deps.storage, &subscriber.plan)?.settings;
             let maybe_claim_msg = match claim_emissions_msg(
                 &module,
                 deps.as_ref(),
@@ -167,6 +248,7 @@ pub fn unsubscribe(
                 subscription_config
                     .subscription_per_second_emissions
                     .clone(),
+                plan.emissions_weight,
                 &subscription_state,
             ) {
                 Ok(maybe_msg) => maybe_msg,
@@ -178,7 +260,7 @@ pub fn unsubscribe(
                 }
             };
 
-            subscription_state.active_subs -= 1;
+            subscription_state.remove_subscriber(&plan)?;
             SUBSCRIBERS.remove(deps.storage, &addr);
             EXPIRED_SUBSCRIBERS.save(deps.storage, &addr, &subscriber)?;
             canceled_subs.push(addr.into_string());
@@ -213,6 +295,7 @@ pub fn unsubscribe(
 }
 
 // Claim emissions
+#[allow(clippy::too_many_arguments)]
 pub fn claim_emissions_msg(
     module: &SubscriptionApp,
     deps: Deps,
@@ -220,6 +303,7 @@ pub fn claim_emissions_msg(
     subscriber: &mut Subscriber,
     subscriber_addr: &Addr,
     subscription_per_second_emissions: EmissionType<Addr>,
+    emissions_weight: Decimal,
     subscription_state: &SubscriptionState,
 ) -> SubscriptionResult<Option<AccountAction>> {
     if subscriber.last_emission_claim_timestamp >= env.block.time {
@@ -237,13 +321,24 @@ pub fn claim_emissions_msg(
             return Err(SubscriptionError::SubscriberEmissionsNotEnabled {});
         }
         crate::state::EmissionType::SecondShared(shared_emissions, token) => {
-            // active_sub can't be 0 as we already loaded one from storage
-            let amount = Uint128::from(seconds_passed).mul_floor(shared_emissions)
-                / Uint128::from(subscription_state.active_subs);
+            // The shared emissions are split by the weights of the plans of the active subscribers
+            let amount = if emissions_weight.is_zero() {
+                Uint128::zero()
+            } else {
+                // total weight can't be 0 as it includes the weight of this subscriber
+                Uint128::from(seconds_passed)
+                    .mul_floor(shared_emissions)
+                    .multiply_ratio(
+                        emissions_weight.atomics(),
+                        subscription_state.total_emissions_weight.atomics(),
+                    )
+            };
             Asset::new(token, amount)
         }
         crate::state::EmissionType::SecondPerUser(per_user_emissions, token) => {
-            let amount = Uint128::from(seconds_passed).mul_floor(per_user_emissions);
+            let amount = Uint128::from(seconds_passed)
+                .mul_floor(per_user_emissions)
+                .mul_floor(emissions_weight);
             Asset::new(token, amount)
         }
     };
@@ -270,6 +365,7 @@ pub fn claim_subscriber_emissions(
     let subscription_state = SUBSCRIPTION_STATE.load(deps.storage)?;
     let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
     let mut subscriber = SUBSCRIBERS.load(deps.storage, &subscriber_addr)?;
+    let plan = PLANS.load(deps.storage, &subscriber.plan)?.settings;
 
     let maybe_action = claim_emissions_msg(
         module,
@@ -278,6 +374,7 @@ pub fn claim_subscriber_emissions(
         &mut subscriber,
         &subscriber_addr,
         subscription_config.subscription_per_second_emissions,
+        plan.emissions_weight,
         &subscription_state,
     )?;
 
@@ -290,31 +387,102 @@ pub fn claim_subscriber_emissions(
 }
 
 // Only Admin can execute it
-#[allow(clippy::too_many_arguments)]
-pub fn update_subscription_config(
+pub fn add_plan(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     module: SubscriptionApp,
-    payment_asset: Option<AssetInfoUnchecked>,
-    subscription_cost_per_second: Option<Decimal>,
-    subscription_per_second_emissions: Option<EmissionType<String>>,
-    unsubscribe_hook_addr: Option<Clearable<String>>,
+    plan_id: PlanId,
+    plan: SubscriptionPlanBase<String>,
 ) -> SubscriptionResult {
     module
         .admin
         .assert_admin(deps.as_ref(), &env, &info.sender)?;
 
-    let mut config: SubscriptionConfig = SUBSCRIPTION_CONFIG.load(deps.storage)?;
+    if PLANS.has(deps.storage, &plan_id) {
+        return Err(SubscriptionError::PlanAlreadyExists(plan_id));
+    }
+    save_plan(deps.storage, deps.api, &plan_id, plan)?;
 
-    if let Some(subscription_cost_per_second) = subscription_cost_per_second {
-        // validate address format
-        config.subscription_cost_per_second = subscription_cost_per_second;
+    Ok(module.custom_response("add_plan", vec![("plan", plan_id)]))
+}
+
+// Only Admin can execute it
+pub fn close_plan(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: SubscriptionApp,
+    plan_id: PlanId,
+) -> SubscriptionResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let mut plan = PLANS
+        .may_load(deps.storage, &plan_id)?
+        .ok_or_else(|| SubscriptionError::PlanNotFound(plan_id.clone()))?;
+    plan.closed = true;
+    PLANS.save(deps.storage, &plan_id, &plan)?;
+
+    Ok(module.custom_response("close_plan", vec![("plan", plan_id)]))
+}
+
+/// Validate and store a new plan
+pub(crate) fn save_plan(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    plan_id: &str,
+    plan: SubscriptionPlanBase<String>,
+) -> SubscriptionResult<()> {
+    if plan.cost_per_second.is_zero() {
+        return Err(SubscriptionError::ZeroPlanCost {});
     }
+    let settings = plan.check(api)?;
+    // Income of all plans is tracked together, so they have to be paid in the same asset
+    if let Some(default_plan) = PLANS.may_load(storage, DEFAULT_PLAN)? {
+        if default_plan.settings.payment_asset != settings.payment_asset {
+            return Err(SubscriptionError::PlanPaymentAssetMismatch(
+                default_plan.settings.payment_asset,
+            ));
+        }
+    }
+    let plan = SubscriptionPlan {
+        settings,
+        closed: false,
+    };
+    PLANS.save(storage, plan_id, &plan)?;
+    Ok(())
+}
 
-    if let Some(payment_asset) = payment_asset {
-        config.payment_asset = payment_asset.check(deps.api, None)?;
+/// Load the settings of a plan that accepts payments
+fn load_open_plan(
+    storage: &dyn Storage,
+    plan_id: &str,
+) -> SubscriptionResult<SubscriptionPlanSettings> {
+    let plan = PLANS
+        .may_load(storage, plan_id)?
+        .ok_or_else(|| SubscriptionError::PlanNotFound(plan_id.to_owned()))?;
+    if plan.closed {
+        return Err(SubscriptionError::PlanClosed(plan_id.to_owned()));
     }
+    Ok(plan.settings)
+}
+
+// Only Admin can execute it
+pub fn update_subscription_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: SubscriptionApp,
+    subscription_per_second_emissions: Option<EmissionType<String>>,
+    unsubscribe_hook_addr: Option<Clearable<String>>,
+) -> SubscriptionResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let mut config: SubscriptionConfig = SUBSCRIPTION_CONFIG.load(deps.storage)?;
 
     if let Some(subscription_per_second_emissions) = subscription_per_second_emissions {
         config.subscription_per_second_emissions =
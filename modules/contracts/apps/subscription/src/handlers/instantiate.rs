@@ -1,10 +1,12 @@
-use cosmwasm_std::{ensure, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{ensure, Decimal, DepsMut, Env, MessageInfo, Response};
 
 use crate::{
     contract::{SubscriptionApp, SubscriptionResult},
+    handlers::execute::save_plan,
     msg::SubscriptionInstantiateMsg,
     state::{
-        SubscriptionConfig, SubscriptionState, INCOME_TWA, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+        SubscriptionConfig, SubscriptionPlanBase, SubscriptionState, DEFAULT_PLAN, INCOME_TWA,
+        SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
     },
     SubscriptionError,
 };
@@ -17,8 +19,6 @@ pub fn instantiate_handler(
     msg: SubscriptionInstantiateMsg,
 ) -> SubscriptionResult {
     let subscription_config: SubscriptionConfig = SubscriptionConfig {
-        payment_asset: msg.payment_asset.check(deps.api, None)?,
-        subscription_cost_per_second: msg.subscription_cost_per_second,
         subscription_per_second_emissions: msg.subscription_per_second_emissions.check(deps.api)?,
         unsubscribe_hook_addr: msg
             .unsubscribe_hook_addr
@@ -26,9 +26,23 @@ pub fn instantiate_handler(
             .transpose()?,
     };
 
-    let subscription_state: SubscriptionState = SubscriptionState { active_subs: 0 };
+    let subscription_state: SubscriptionState = SubscriptionState {
+        active_subs: 0,
+        income_per_second: Decimal::zero(),
+        total_emissions_weight: Decimal::zero(),
+    };
     SUBSCRIPTION_CONFIG.save(deps.storage, &subscription_config)?;
     SUBSCRIPTION_STATE.save(deps.storage, &subscription_state)?;
+    save_plan(
+        deps.storage,
+        deps.api,
+        DEFAULT_PLAN,
+        SubscriptionPlanBase {
+            payment_asset: msg.payment_asset,
+            cost_per_second: msg.subscription_cost_per_second,
+            emissions_weight: Decimal::one(),
+        },
+    )?;
 
     ensure!(
         !msg.income_averaging_period.is_zero(),
@@ -0,0 +1,70 @@
+use abstract_app::sdk::AbstractResponse;
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, Uint128};
+use cw_asset::AssetInfo;
+use cw_storage_plus::Item;
+
+use crate::{
+    contract::{SubscriptionApp, SubscriptionResult},
+    msg::SubscriptionMigrateMsg,
+    state::{
+        EmissionType, SubscriptionConfig, SubscriptionPlan, SubscriptionPlanBase, DEFAULT_PLAN,
+        PLANS, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+    },
+};
+
+/// Config of instances that were created before subscription plans were introduced.
+#[cosmwasm_schema::cw_serde]
+struct LegacySubscriptionConfig {
+    payment_asset: AssetInfo,
+    subscription_cost_per_second: Decimal,
+    subscription_per_second_emissions: EmissionType<Addr>,
+    unsubscribe_hook_addr: Option<Addr>,
+}
+
+const LEGACY_SUBSCRIPTION_CONFIG: Item<LegacySubscriptionConfig> = Item::new("config");
+
+/// Handle the app migrate msg
+/// The top-level Abstract app does version checking and dispatches to this handler
+pub fn migrate_handler(
+    deps: DepsMut,
+    _env: Env,
+    module: SubscriptionApp,
+    _msg: SubscriptionMigrateMsg,
+) -> SubscriptionResult {
+    if PLANS.is_empty(deps.storage) {
+        migrate_to_plans(deps)?;
+    }
+    Ok(module.response("migrate"))
+}
+
+/// Move the pricing of a single-plan instance to the [default plan](DEFAULT_PLAN).
+/// Existing subscribers are on the default plan.
+fn migrate_to_plans(deps: DepsMut) -> SubscriptionResult<()> {
+    let legacy_config = LEGACY_SUBSCRIPTION_CONFIG.load(deps.storage)?;
+    SUBSCRIPTION_CONFIG.save(
+        deps.storage,
+        &SubscriptionConfig {
+            subscription_per_second_emissions: legacy_config.subscription_per_second_emissions,
+            unsubscribe_hook_addr: legacy_config.unsubscribe_hook_addr,
+        },
+    )?;
+    PLANS.save(
+        deps.storage,
+        DEFAULT_PLAN,
+        &SubscriptionPlan {
+            settings: SubscriptionPlanBase {
+                payment_asset: legacy_config.payment_asset,
+                cost_per_second: legacy_config.subscription_cost_per_second,
+                emissions_weight: Decimal::one(),
+            },
+            closed: false,
+        },
+    )?;
+
+    let mut state = SUBSCRIPTION_STATE.load(deps.storage)?;
+    let active_subs = Decimal::from_atomics(Uint128::from(state.active_subs), 0)?;
+    state.income_per_second = active_subs * legacy_config.subscription_cost_per_second;
+    state.total_emissions_weight = active_subs;
+    SUBSCRIPTION_STATE.save(deps.storage, &state)?;
+    Ok(())
+}
@@ -6,11 +6,12 @@ use cw_storage_plus::Bound;
 use crate::{
     contract::{SubscriptionApp, SubscriptionResult},
     msg::{
-        StateResponse, SubscriberResponse, SubscribersResponse, SubscriptionFeeResponse,
-        SubscriptionQueryMsg,
+        PlansResponse, StateResponse, SubscriberResponse, SubscribersResponse,
+        SubscriptionFeeResponse, SubscriptionQueryMsg,
     },
     state::{
-        EXPIRED_SUBSCRIBERS, INCOME_TWA, SUBSCRIBERS, SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
+        PlanId, DEFAULT_PLAN, EXPIRED_SUBSCRIBERS, INCOME_TWA, PLANS, SUBSCRIBERS,
+        SUBSCRIPTION_CONFIG, SUBSCRIPTION_STATE,
     },
 };
 
@@ -28,18 +29,27 @@ pub fn query_handler(
                 subscription: subscription_state,
             })
         }
-        SubscriptionQueryMsg::Fee {} => {
-            let config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
+        SubscriptionQueryMsg::Fee { plan } => {
+            let plan = PLANS
+                .load(deps.storage, plan.as_deref().unwrap_or(DEFAULT_PLAN))?
+                .settings;
             let twa_data = INCOME_TWA.load(deps.storage)?;
-            let minimal_cost = Uint128::from(twa_data.averaging_period)
-                .mul_floor(config.subscription_cost_per_second);
+            let minimal_cost =
+                Uint128::from(twa_data.averaging_period).mul_floor(plan.cost_per_second);
             to_json_binary(&SubscriptionFeeResponse {
                 fee: Asset {
-                    info: config.payment_asset,
+                    info: plan.payment_asset,
                     amount: minimal_cost,
                 },
             })
         }
+        SubscriptionQueryMsg::Plan { plan_id } => {
+            let plan = PLANS.load(deps.storage, &plan_id)?;
+            to_json_binary(&plan)
+        }
+        SubscriptionQueryMsg::Plans { start_after, limit } => {
+            to_json_binary(&query_plans(deps, start_after, limit)?)
+        }
         SubscriptionQueryMsg::Config {} => {
             let subscription_config = SUBSCRIPTION_CONFIG.load(deps.storage)?;
             to_json_binary(&subscription_config)
@@ -54,6 +64,20 @@ pub fn query_handler(
     .map_err(Into::into)
 }
 
+fn query_plans(
+    deps: Deps,
+    start_after: Option<PlanId>,
+    limit: Option<u64>,
+) -> SubscriptionResult<PlansResponse> {
+    let min = start_after.as_deref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let plans = PLANS
+        .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<_>>()?;
+    Ok(PlansResponse { plans })
+}
+
 fn query_subscriber(deps: Deps, addr: String) -> SubscriptionResult<SubscriberResponse> {
     let addr = deps.api.addr_validate(&addr)?;
     let subscription_state = if let Some(sub) = SUBSCRIBERS.may_load(deps.storage, &addr)? {
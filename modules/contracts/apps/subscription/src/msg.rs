@@ -33,7 +33,10 @@ use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::{to_json_binary, Addr, Binary, CosmosMsg, Decimal, StdResult, Uint64, WasmMsg};
 use cw_asset::{Asset, AssetInfoUnchecked};
 
-use super::state::{EmissionType, Subscriber, SubscriptionConfig, SubscriptionState};
+use super::state::{
+    EmissionType, PlanId, Subscriber, SubscriptionConfig, SubscriptionPlan, SubscriptionPlanBase,
+    SubscriptionState,
+};
 use crate::contract::SubscriptionApp;
 
 abstract_app::app_msg_types!(
@@ -49,9 +52,9 @@ pub struct SubscriptionMigrateMsg {}
 /// Subscription instantiation message
 #[cosmwasm_schema::cw_serde]
 pub struct SubscriptionInstantiateMsg {
-    /// Asset for payment
+    /// Asset for payment of the [default plan](crate::state::DEFAULT_PLAN)
     pub payment_asset: AssetInfoUnchecked,
-    /// Cost of the subscription on a per-second basis.
+    /// Cost of the [default plan](crate::state::DEFAULT_PLAN) on a per-second basis.
     pub subscription_cost_per_second: Decimal,
     /// Subscription emissions per second
     pub subscription_per_second_emissions: EmissionType<String>,
@@ -71,6 +74,15 @@ pub enum SubscriptionExecuteMsg {
         /// Address of new subscriber
         /// defaults to the sender
         subscriber_addr: Option<String>,
+        /// Plan to subscribe to
+        /// defaults to the current plan of the subscriber or the [default plan](crate::state::DEFAULT_PLAN)
+        plan: Option<PlanId>,
+    },
+    /// Move the sender's subscription to another plan.
+    /// The remaining subscription time is converted at the price ratio of the plans.
+    ChangePlan {
+        /// Plan to move to
+        plan: PlanId,
     },
     /// Unsubscribe inactive accounts
     Unsubscribe {
@@ -82,12 +94,21 @@ pub enum SubscriptionExecuteMsg {
         /// Address of subscriber
         addr: String,
     },
+    /// Add a new subscription plan
+    /// The plan must be paid in the payment asset of the [default plan](crate::state::DEFAULT_PLAN)
+    AddPlan {
+        /// Identifier of the plan
+        plan_id: PlanId,
+        /// Plan settings
+        plan: SubscriptionPlanBase<String>,
+    },
+    /// Close a subscription plan for new payments
+    ClosePlan {
+        /// Identifier of the plan
+        plan_id: PlanId,
+    },
     /// Update config of subscription
     UpdateSubscriptionConfig {
-        /// New asset for payment
-        payment_asset: Option<AssetInfoUnchecked>,
-        /// new subscription_cost_per_second
-        subscription_cost_per_second: Option<Decimal>,
         /// Subscription emissions per second
         subscription_per_second_emissions: Option<EmissionType<String>>,
         /// New unsubscribe_hook_addr
@@ -132,7 +153,27 @@ pub enum SubscriptionQueryMsg {
     /// Get minimum of one month's worth to (re)-subscribe.
     /// Returns [`SubscriptionFeeResponse`]
     #[returns(SubscriptionFeeResponse)]
-    Fee {},
+    Fee {
+        /// Plan to subscribe to
+        /// defaults to the [default plan](crate::state::DEFAULT_PLAN)
+        plan: Option<PlanId>,
+    },
+    /// Get a subscription plan
+    /// Returns [`SubscriptionPlan`]
+    #[returns(SubscriptionPlan)]
+    Plan {
+        /// Identifier of the plan
+        plan_id: PlanId,
+    },
+    /// Get list of subscription plans
+    /// Returns [`PlansResponse`]
+    #[returns(PlansResponse)]
+    Plans {
+        /// Start after plan id
+        start_after: Option<PlanId>,
+        /// Limit
+        limit: Option<u64>,
+    },
     /// Get state of the subscriber
     /// Returns [`SubscriberResponse`]
     #[returns(SubscriberResponse)]
@@ -161,6 +202,8 @@ pub enum DepositHookMsg {
         /// Subscriber Addr
         /// defaults to the sender
         subscriber_addr: Option<String>,
+        /// Plan to subscribe to
+        plan: Option<PlanId>,
     },
 }
 
@@ -178,6 +221,13 @@ pub struct SubscriptionFeeResponse {
     pub fee: Asset,
 }
 
+/// Query response for [`SubscriptionQueryMsg::Plans`]
+#[cosmwasm_schema::cw_serde]
+pub struct PlansResponse {
+    /// list of plans
+    pub plans: Vec<(PlanId, SubscriptionPlan)>,
+}
+
 /// Query response for [`SubscriptionQueryMsg::Subscriber`]
 #[cosmwasm_schema::cw_serde]
 pub struct SubscriberResponse {
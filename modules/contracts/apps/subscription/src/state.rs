@@ -1,7 +1,7 @@
 use abstract_app::std::{objects::time_weighted_average::TimeWeightedAverage, AbstractResult};
-use cosmwasm_std::{Addr, Api, BlockInfo, Decimal, Timestamp};
+use cosmwasm_std::{Addr, Api, BlockInfo, Decimal, StdResult, Timestamp};
 use cw_address_like::AddressLike;
-use cw_asset::{AssetInfo, AssetInfoBase};
+use cw_asset::AssetInfoBase;
 use cw_storage_plus::{Item, Map};
 
 /// Setting for protocol token emissions
@@ -35,13 +35,53 @@ impl EmissionType<String> {
     }
 }
 
+/// Identifier of a subscription plan
+pub type PlanId = String;
+
+/// Plan created from the instantiate message.
+/// Subscribers without an explicit plan are subscribed to this plan.
+pub const DEFAULT_PLAN: &str = "default";
+
+fn default_plan() -> PlanId {
+    DEFAULT_PLAN.to_owned()
+}
+
+/// Pricing and emissions settings of a subscription plan
+#[cosmwasm_schema::cw_serde]
+pub struct SubscriptionPlanBase<T: AddressLike> {
+    /// Asset that's accepted as payment, the same for all plans
+    pub payment_asset: AssetInfoBase<T>,
+    /// Cost of the subscription on a per-second basis.
+    pub cost_per_second: Decimal,
+    /// Multiplier on the emissions of the subscribers of this plan
+    pub emissions_weight: Decimal,
+}
+
+/// Checked plan settings
+pub type SubscriptionPlanSettings = SubscriptionPlanBase<Addr>;
+
+impl SubscriptionPlanBase<String> {
+    pub fn check(self, api: &dyn Api) -> AbstractResult<SubscriptionPlanSettings> {
+        Ok(SubscriptionPlanBase {
+            payment_asset: self.payment_asset.check(api, None)?,
+            cost_per_second: self.cost_per_second,
+            emissions_weight: self.emissions_weight,
+        })
+    }
+}
+
+/// Stored subscription plan
+#[cosmwasm_schema::cw_serde]
+pub struct SubscriptionPlan {
+    /// Plan settings
+    pub settings: SubscriptionPlanSettings,
+    /// Closed plans don't accept new payments, current subscribers keep their subscription until it expires
+    pub closed: bool,
+}
+
 /// Config for subscriber functionality
 #[cosmwasm_schema::cw_serde]
 pub struct SubscriptionConfig {
-    /// Asset that's accepted as payment
-    pub payment_asset: AssetInfo,
-    /// Cost of the subscription on a per-second basis.
-    pub subscription_cost_per_second: Decimal,
     /// Subscription emissions per second
     pub subscription_per_second_emissions: EmissionType<Addr>,
     /// Unsubscription hook addr
@@ -55,6 +95,31 @@ pub struct SubscriptionConfig {
 pub struct SubscriptionState {
     /// amount of active subscribers
     pub active_subs: u32,
+    /// Sum of the per-second cost of the plans of the active subscribers, in the shared payment asset
+    #[serde(default)]
+    pub income_per_second: Decimal,
+    /// Sum of the emissions weights of the plans of the active subscribers
+    #[serde(default)]
+    pub total_emissions_weight: Decimal,
+}
+
+impl SubscriptionState {
+    /// Add a subscriber of `plan` to the state
+    pub fn add_subscriber(&mut self, plan: &SubscriptionPlanSettings) {
+        self.active_subs += 1;
+        self.income_per_second += plan.cost_per_second;
+        self.total_emissions_weight += plan.emissions_weight;
+    }
+
+    /// Remove a subscriber of `plan` from the state
+    pub fn remove_subscriber(&mut self, plan: &SubscriptionPlanSettings) -> StdResult<()> {
+        self.active_subs -= 1;
+        self.income_per_second = self.income_per_second.checked_sub(plan.cost_per_second)?;
+        self.total_emissions_weight = self
+            .total_emissions_weight
+            .checked_sub(plan.emissions_weight)?;
+        Ok(())
+    }
 }
 
 /// Stored info for each subscriber.
@@ -64,13 +129,17 @@ pub struct Subscriber {
     pub expiration_timestamp: Timestamp,
     /// last time emissions were claimed
     pub last_emission_claim_timestamp: Timestamp,
+    /// Plan of the subscriber
+    #[serde(default = "default_plan")]
+    pub plan: PlanId,
 }
 
 impl Subscriber {
-    pub fn new(block: &BlockInfo, paid_for_seconds: u64) -> Self {
+    pub fn new(block: &BlockInfo, paid_for_seconds: u64, plan: PlanId) -> Self {
         Self {
             expiration_timestamp: block.time.plus_seconds(paid_for_seconds),
             last_emission_claim_timestamp: block.time,
+            plan,
         }
     }
 
@@ -81,6 +150,13 @@ impl Subscriber {
     pub fn is_expired(&self, block: &BlockInfo) -> bool {
         block.time >= self.expiration_timestamp
     }
+
+    /// Seconds left until the subscription expires
+    pub fn remaining_seconds(&self, block: &BlockInfo) -> u64 {
+        self.expiration_timestamp
+            .seconds()
+            .saturating_sub(block.time.seconds())
+    }
 }
 
 /// Average number of subscribers
@@ -88,5 +164,6 @@ pub const SUBSCRIPTION_CONFIG: Item<SubscriptionConfig> = Item::new("config");
 pub const SUBSCRIPTION_STATE: Item<SubscriptionState> = Item::new("state");
 pub const SUBSCRIBERS: Map<&Addr, Subscriber> = Map::new("subs");
 pub const EXPIRED_SUBSCRIBERS: Map<&Addr, Subscriber> = Map::new("unsubs");
+pub const PLANS: Map<&str, SubscriptionPlan> = Map::new("plans");
 
 pub const INCOME_TWA: TimeWeightedAverage = TimeWeightedAverage::new("twa");
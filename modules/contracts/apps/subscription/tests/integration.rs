@@ -7,7 +7,10 @@ use abstract_client::{builder::cw20_builder, AbstractClient, Application, Enviro
 use abstract_subscription::{
     contract::interface::SubscriptionInterface,
     msg::{SubscriptionExecuteMsgFns, SubscriptionInstantiateMsg, SubscriptionQueryMsgFns},
-    state::{EmissionType, Subscriber, SubscriptionConfig},
+    state::{
+        EmissionType, Subscriber, SubscriptionConfig, SubscriptionPlan, SubscriptionPlanBase,
+        DEFAULT_PLAN,
+    },
     SubscriptionError,
 };
 
@@ -150,8 +153,6 @@ fn successful_install() -> anyhow::Result<()> {
     assert_eq!(
         config,
         SubscriptionConfig {
-            payment_asset,
-            subscription_cost_per_second: Decimal::from_str("0.000037")?,
             subscription_per_second_emissions: EmissionType::SecondShared(
                 Decimal::from_str("0.00005")?,
                 AssetInfoBase::Cw20(addr)
@@ -159,6 +160,18 @@ fn successful_install() -> anyhow::Result<()> {
             unsubscribe_hook_addr: None
         }
     );
+    let plan = subscription_app.plan(DEFAULT_PLAN.to_owned())?;
+    assert_eq!(
+        plan,
+        SubscriptionPlan {
+            settings: SubscriptionPlanBase {
+                payment_asset,
+                cost_per_second: Decimal::from_str("0.000037")?,
+                emissions_weight: Decimal::one(),
+            },
+            closed: false,
+        }
+    );
 
     let Cw20Subscription {
         client: _,
@@ -170,12 +183,12 @@ fn successful_install() -> anyhow::Result<()> {
     assert_eq!(
         config,
         SubscriptionConfig {
-            payment_asset,
-            subscription_cost_per_second: Decimal::from_str("0.000037")?,
             subscription_per_second_emissions: EmissionType::None,
             unsubscribe_hook_addr: None
         }
     );
+    let fee = subscription_app.fee(None)?;
+    assert_eq!(fee.fee.info, payment_asset);
     Ok(())
 }
 
@@ -209,10 +222,10 @@ fn subscribe() -> anyhow::Result<()> {
     // 2 people subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     let twa = query_twa(&client.environment(), subscription_addr.clone());
     // No income yet
     assert_eq!(twa.cumulative_value, 0);
@@ -223,7 +236,7 @@ fn subscribe() -> anyhow::Result<()> {
     // Third user subscribes
     subscription_app
         .call_as(&subscriber3)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     // refresh twa
     subscription_app.refresh_twa()?;
     // It should contain income of previous 2 subscribers
@@ -250,7 +263,7 @@ fn subscribe() -> anyhow::Result<()> {
     // Fourth user subscribes
     subscription_app
         .call_as(&subscriber4)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     // two subscribers were subbed for two periods
     let first_two_subs =
         Uint128::from(INCOME_AVERAGING_PERIOD * Uint64::new(2)).mul_floor(two_subs_per_second);
@@ -290,10 +303,10 @@ fn claim_emissions_week_shared() -> anyhow::Result<()> {
     // 2 users subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -348,12 +361,12 @@ fn claim_emissions_none() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscription_app.account().owner()?)
-        .update_subscription_config(None, None, Some(EmissionType::None), None)?;
+        .update_subscription_config(Some(EmissionType::None), None)?;
 
     // 1 user subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -389,8 +402,6 @@ fn claim_emissions_week_per_user() -> anyhow::Result<()> {
     subscription_app
         .call_as(&subscription_app.account().owner()?)
         .update_subscription_config(
-            None,
-            None,
             Some(EmissionType::SecondPerUser(
                 Decimal::from_str("0.00005")?,
                 AssetInfoBase::Cw20(emission_cw20.addr_str()?),
@@ -401,10 +412,10 @@ fn claim_emissions_week_per_user() -> anyhow::Result<()> {
     // 2 users subscribe
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -474,7 +485,7 @@ fn claim_emissions_errors() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     client.wait_seconds(WEEK_IN_SECONDS)?;
 
@@ -507,7 +518,7 @@ fn unsubscribe() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &sub_amount)?;
+        .pay(None, None, &sub_amount)?;
 
     let subscriber = subscription_app.subscriber(subscriber1.to_string())?;
     let current_time = client.block_info()?.time;
@@ -577,10 +588,10 @@ fn unsubscribe_part_of_list() -> anyhow::Result<()> {
 
     subscription_app
         .call_as(&subscriber1)
-        .pay(None, &coins(2200, DENOM))?;
+        .pay(None, None, &coins(2200, DENOM))?;
     subscription_app
         .call_as(&subscriber2)
-        .pay(None, &coins(220, DENOM))?;
+        .pay(None, None, &coins(220, DENOM))?;
     // 1 out of 10 weeks wait
     client.wait_seconds(WEEK_IN_SECONDS)?;
     // Un-sub on not-expired users should error
@@ -606,6 +617,127 @@ fn unsubscribe_part_of_list() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn subscription_plans() -> anyhow::Result<()> {
+    let mock = MockBech32::new("mock");
+    let subscriber1 = mock.addr_make("subscriber1");
+    let subscriber2 = mock.addr_make("subscriber2");
+
+    let sub_amount = coins(1000, DENOM);
+    let NativeSubscription {
+        client,
+        subscription_app,
+        payment_asset: _,
+        emission_cw20,
+    } = setup_native(
+        &mock,
+        [
+            (&subscriber1, sub_amount.as_slice()),
+            (&subscriber2, sub_amount.as_slice()),
+        ],
+    )?;
+
+    // Premium plan costs twice as much and earns twice the emissions
+    let premium = "premium".to_owned();
+    subscription_app.add_plan(
+        SubscriptionPlanBase {
+            payment_asset: AssetInfoUnchecked::native(DENOM),
+            cost_per_second: Decimal::from_str("0.000074")?,
+            emissions_weight: Decimal::from_str("2")?,
+        },
+        premium.clone(),
+    )?;
+    let plans = subscription_app.plans(None, None)?;
+    assert_eq!(plans.plans.len(), 2);
+
+    // Plans can't be paid in a different asset than the default plan
+    let err = subscription_app
+        .add_plan(
+            SubscriptionPlanBase {
+                payment_asset: AssetInfoUnchecked::native("other"),
+                cost_per_second: Decimal::from_str("0.000074")?,
+                emissions_weight: Decimal::one(),
+            },
+            "other".to_owned(),
+        )
+        .unwrap_err();
+    let err: SubscriptionError = err.downcast().unwrap();
+    assert_eq!(
+        err,
+        SubscriptionError::PlanPaymentAssetMismatch(AssetInfo::native(DENOM))
+    );
+
+    subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &coins(500, DENOM))?;
+    subscription_app
+        .call_as(&subscriber2)
+        .pay(Some(premium.clone()), None, &sub_amount)?;
+    let subscriber = subscription_app.subscriber(subscriber2.to_string())?;
+    assert_eq!(subscriber.subscriber_details.unwrap().plan, premium);
+
+    // Active subscribers can't switch plans by paying
+    let err = subscription_app
+        .call_as(&subscriber1)
+        .pay(Some(premium.clone()), None, &coins(500, DENOM))
+        .unwrap_err();
+    let err: SubscriptionError = err.downcast().unwrap();
+    assert_eq!(
+        err,
+        SubscriptionError::PlanChangeRequired {
+            current: DEFAULT_PLAN.to_owned()
+        }
+    );
+
+    // Shared emissions are split by plan weight
+    client.wait_seconds(WEEK_IN_SECONDS)?;
+    subscription_app.claim_emissions(subscriber1.to_string())?;
+    subscription_app.claim_emissions(subscriber2.to_string())?;
+    let total_amount = Uint128::from(WEEK_IN_SECONDS).mul_floor(Decimal::from_str("0.00005")?);
+    let balance1 = emission_cw20.balance(subscriber1.to_string())?;
+    assert_eq!(balance1.balance, total_amount.multiply_ratio(1u128, 3u128));
+    let balance2 = emission_cw20.balance(subscriber2.to_string())?;
+    assert_eq!(balance2.balance, total_amount.multiply_ratio(2u128, 3u128));
+
+    // Upgrading halves the remaining subscription time
+    let details = subscription_app
+        .subscriber(subscriber1.to_string())?
+        .subscriber_details
+        .unwrap();
+    let now = client.block_info()?.time;
+    let remaining = details.expiration_timestamp.seconds() - now.seconds();
+    subscription_app
+        .call_as(&subscriber1)
+        .change_plan(premium.clone())?;
+    let details = subscription_app
+        .subscriber(subscriber1.to_string())?
+        .subscriber_details
+        .unwrap();
+    assert_eq!(details.plan, premium);
+    let credited = details.expiration_timestamp.seconds() - now.seconds();
+    assert!(credited.abs_diff(remaining / 2) <= 1);
+
+    // Closed plans don't accept new payments
+    subscription_app.close_plan(premium.clone())?;
+    let err = subscription_app
+        .call_as(&subscriber1)
+        .pay(None, None, &coins(500, DENOM))
+        .unwrap_err();
+    let err: SubscriptionError = err.downcast().unwrap();
+    assert_eq!(err, SubscriptionError::PlanClosed(premium));
+
+    // But subscribers can move back to an open plan
+    subscription_app
+        .call_as(&subscriber1)
+        .change_plan(DEFAULT_PLAN.to_owned())?;
+    let details = subscription_app
+        .subscriber(subscriber1.to_string())?
+        .subscriber_details
+        .unwrap();
+    assert_eq!(details.plan, DEFAULT_PLAN);
+    Ok(())
+}
+
 // Helper to raw_query twa
 fn query_twa(chain: &MockBech32, subscription_addr: Addr) -> TimeWeightedAverageData {
     let app = chain.app.borrow();